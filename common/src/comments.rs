@@ -31,6 +31,68 @@ impl Comments {
     pub fn leading_comments(&self, pos: BytePos) -> Option<ReadGuard<BytePos, Vec<Comment>>> {
         self.leading.get(&pos)
     }
+
+    /// Removes all comments and returns them.
+    pub fn take_all(&self) -> Comments {
+        Comments {
+            leading: self.leading.clear(),
+            trailing: self.trailing.clear(),
+        }
+    }
+
+    /// Moves comments of `other` into `self`.
+    ///
+    /// `f` returns the new position of comments at a position, or `None` to
+    /// drop them. Comments are not moved to positions which already have
+    /// comments.
+    pub fn extend_with<F>(&self, other: Comments, mut f: F)
+    where
+        F: FnMut(BytePos, Vec<Comment>) -> Option<(BytePos, Vec<Comment>)>,
+    {
+        fn extend_in<F>(map: &CommentMap, other: CommentMap, f: &mut F)
+        where
+            F: FnMut(BytePos, Vec<Comment>) -> Option<(BytePos, Vec<Comment>)>,
+        {
+            for (pos, cmts) in other {
+                if let Some((pos, cmts)) = f(pos, cmts) {
+                    if !map.contains_key(&pos) {
+                        map.insert(pos, cmts);
+                    }
+                }
+            }
+        }
+
+        extend_in(&self.leading, other.leading, &mut f);
+        extend_in(&self.trailing, other.trailing, &mut f);
+    }
+
+    /// Returns comments for which `f` returns true.
+    pub fn filter<F>(&self, f: F) -> Comments
+    where
+        F: Fn(&Comment) -> bool,
+    {
+        fn filter_in<F>(map: &CommentMap, f: &F) -> CommentMap
+        where
+            F: Fn(&Comment) -> bool,
+        {
+            map.clone()
+                .into_iter()
+                .filter_map(|(pos, mut cmts)| {
+                    cmts.retain(|c| f(c));
+                    if cmts.is_empty() {
+                        None
+                    } else {
+                        Some((pos, cmts))
+                    }
+                })
+                .collect()
+        }
+
+        Comments {
+            leading: filter_in(&self.leading, &f),
+            trailing: filter_in(&self.trailing, &f),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
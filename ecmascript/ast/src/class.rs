@@ -103,6 +103,10 @@ macro_rules! property {
 
             #[serde(default)]
             pub definite: bool,
+
+            /// `accessor x = 1;` from the 2019-11 decorators proposal.
+            #[serde(default)]
+            pub is_accessor: bool,
         }
     };
 }
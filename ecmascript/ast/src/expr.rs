@@ -143,6 +143,20 @@ pub enum Expr {
 
     #[tag("TsOptionalChainingExpression")]
     TsOptChain(TsOptChain),
+
+    /// `?` in `f(?, x)`.
+    ///
+    /// Also used as the argument of `await` in `x |> await` (F# pipelines).
+    #[tag("ArgumentPlaceholder")]
+    ArgPlaceholder(ArgPlaceholder),
+
+    /// `do { ... }`
+    #[tag("DoExpression")]
+    Do(DoExpr),
+
+    /// `throw e` used as an expression.
+    #[tag("ThrowExpression")]
+    Throw(ThrowExpr),
 }
 
 #[ast_node("ThisExpression")]
@@ -376,6 +390,27 @@ pub struct MetaPropExpr {
     pub prop: Ident,
 }
 
+#[ast_node("ArgumentPlaceholder")]
+#[derive(Copy)]
+pub struct ArgPlaceholder {
+    pub span: Span,
+}
+
+#[ast_node("DoExpression")]
+pub struct DoExpr {
+    pub span: Span,
+
+    pub body: BlockStmt,
+}
+
+#[ast_node("ThrowExpression")]
+pub struct ThrowExpr {
+    pub span: Span,
+
+    #[serde(rename = "argument")]
+    pub arg: Box<Expr>,
+}
+
 #[ast_node("AwaitExpression")]
 pub struct AwaitExpr {
     pub span: Span,
@@ -531,6 +566,7 @@ impl From<f64> for Expr {
         Expr::Lit(Lit::Num(Number {
             span: DUMMY_SP,
             value,
+            raw: None,
         }))
     }
 }
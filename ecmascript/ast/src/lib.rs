@@ -22,10 +22,11 @@ pub use self::{
     },
     decl::{ClassDecl, Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator},
     expr::{
-        ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BlockStmtOrExpr, CallExpr, ClassExpr,
-        CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, MemberExpr, MetaPropExpr, NewExpr,
-        ObjectLit, ParenExpr, PatOrExpr, PropOrSpread, SeqExpr, SpreadElement, Super, TaggedTpl,
-        ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
+        ArgPlaceholder, ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BlockStmtOrExpr,
+        CallExpr, ClassExpr, CondExpr, DoExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr,
+        MemberExpr, MetaPropExpr, NewExpr, ObjectLit, ParenExpr, PatOrExpr, PropOrSpread,
+        SeqExpr, SpreadElement, Super, TaggedTpl, ThisExpr, ThrowExpr, Tpl, TplElement,
+        UnaryExpr, UpdateExpr, YieldExpr,
     },
     function::{Function, PatOrTsParamProp},
     ident::{Ident, IdentExt, PrivateName},
@@ -50,8 +51,9 @@ pub use self::{
     prop::{AssignProp, GetterProp, KeyValueProp, MethodProp, Prop, PropName, SetterProp},
     stmt::{
         BlockStmt, BreakStmt, CatchClause, ContinueStmt, DebuggerStmt, DoWhileStmt, EmptyStmt,
-        ForInStmt, ForOfStmt, ForStmt, IfStmt, LabeledStmt, ReturnStmt, Stmt, SwitchCase,
-        SwitchStmt, ThrowStmt, TryStmt, VarDeclOrExpr, VarDeclOrPat, WhileStmt, WithStmt,
+        ForInStmt, ForOfStmt, ForStmt, IfStmt, LabeledStmt, LazyBody, ReturnStmt, Stmt,
+        SwitchCase, SwitchStmt, ThrowStmt, TryStmt, VarDeclOrExpr, VarDeclOrPat, WhileStmt,
+        WithStmt,
     },
     typescript::{
         Accessibility, TruePlusMinus, TsArrayType, TsAsExpr, TsCallSignatureDecl,
@@ -67,7 +69,7 @@ pub use self::{
         TsType, TsTypeAliasDecl, TsTypeAnn, TsTypeAssertion, TsTypeCastExpr, TsTypeElement,
         TsTypeLit, TsTypeOperator, TsTypeOperatorOp, TsTypeParam, TsTypeParamDecl,
         TsTypeParamInstantiation, TsTypePredicate, TsTypeQuery, TsTypeRef,
-        TsUnionOrIntersectionType, TsUnionType,
+        TsUnionOrIntersectionType, TsUnionType, Variance,
     },
 };
 
@@ -33,6 +33,12 @@ pub struct Str {
     /// This includes line escape.
     #[serde(default)]
     pub has_escape: bool,
+
+    /// Source text of the literal, including quotes.
+    ///
+    /// `None` if the literal is not from the source code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<JsWord>,
 }
 impl Str {
     #[inline]
@@ -67,11 +73,22 @@ pub struct Regex {
 
 pub type RegexFlags = Str;
 
+/// Numeric literal.
+///
+/// This is not `Copy` since `raw` was added, which is a breaking change. The
+/// raw text can't be looked up from `span` instead, because compat passes
+/// (e.g. `numeric_separator`) need it and they don't have a `SourceMap`. Use
+/// `.clone()` or match with `ref` where a copy was made before.
 #[ast_node("NumericLiteral")]
-#[derive(Copy)]
 pub struct Number {
     pub span: Span,
     pub value: f64,
+
+    /// Source text of the literal, e.g. `0xFF` or `1e3`.
+    ///
+    /// `None` if the literal is not from the source code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<JsWord>,
 }
 
 impl Display for Number {
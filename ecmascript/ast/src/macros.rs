@@ -97,6 +97,9 @@ macro_rules! op {
     ("&&") => {
         $crate::BinaryOp::LogicalAnd
     };
+    ("|>") => {
+        $crate::BinaryOp::Pipeline
+    };
     ("in") => {
         $crate::BinaryOp::In
     };
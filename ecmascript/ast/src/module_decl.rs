@@ -62,6 +62,10 @@ pub struct ImportDecl {
 
     #[serde(rename = "source")]
     pub src: Str,
+
+    /// Flow: `import type { Foo } from 'mod'`
+    #[serde(default)]
+    pub type_only: bool,
 }
 
 /// `export * from 'mod'`
@@ -83,6 +87,10 @@ pub struct NamedExport {
 
     #[serde(rename = "source")]
     pub src: Option<Str>,
+
+    /// Flow: `export type { Foo }`
+    #[serde(default)]
+    pub type_only: bool,
 }
 
 #[ast_node("ExportDefaultDeclaration")]
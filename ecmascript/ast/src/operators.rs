@@ -85,6 +85,10 @@ pub enum BinaryOp {
     /// `**`
     #[kind(precedence = "11")]
     Exp,
+
+    /// `|>`
+    #[kind(precedence = "0")]
+    Pipeline,
 }
 
 #[derive(StringEnum, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
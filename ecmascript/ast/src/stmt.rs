@@ -69,6 +69,10 @@ pub enum Stmt {
     #[tag("ForOfStatement")]
     ForOf(ForOfStmt),
 
+    /// Unparsed function body. Only created by the parser in lazy mode.
+    #[tag("LazyBody")]
+    Lazy(LazyBody),
+
     #[tag("ClassDeclaration")]
     #[tag("FunctionDeclaration")]
     #[tag("VariableDeclaration")]
@@ -95,6 +99,39 @@ pub struct DebuggerStmt {
     pub span: Span,
 }
 
+/// A function body which is skipped by the parser.
+///
+/// This is the only statement of the body's [BlockStmt], and it can be parsed
+/// on demand with `Parser::parse_lazy_body`.
+#[ast_node("LazyBody")]
+#[derive(Copy)]
+pub struct LazyBody {
+    /// Span including the braces.
+    pub span: Span,
+
+    #[serde(default, rename = "async")]
+    pub is_async: bool,
+
+    #[serde(default, rename = "generator")]
+    pub is_generator: bool,
+
+    /// True if the body is in strict mode code.
+    #[serde(default)]
+    pub strict: bool,
+
+    /// True if the body is in module code.
+    #[serde(default)]
+    pub in_module: bool,
+
+    /// True if the body is in a method, where `super` is allowed.
+    #[serde(default)]
+    pub in_method: bool,
+
+    /// True if the body is in an initializer of a class property.
+    #[serde(default)]
+    pub in_class_prop: bool,
+}
+
 #[ast_node("WithStatement")]
 pub struct WithStmt {
     pub span: Span,
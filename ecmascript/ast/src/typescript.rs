@@ -35,6 +35,10 @@ pub struct TsTypeParam {
     pub span: Span,
     pub name: Ident,
 
+    /// Flow: `+T` or `-T`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variance: Option<Variance>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub constraint: Option<Box<TsType>>,
 
@@ -168,6 +172,9 @@ pub struct TsConstructSignatureDecl {
 pub struct TsPropertySignature {
     pub span: Span,
     pub readonly: bool,
+    /// Flow: `+a: T` or `-a: T`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variance: Option<Variance>,
     pub key: Box<Expr>,
     pub computed: bool,
     pub optional: bool,
@@ -209,6 +216,9 @@ pub struct TsIndexSignature {
     pub type_ann: Option<TsTypeAnn>,
 
     pub readonly: bool,
+    /// Flow: `+[k: K]: V` or `-[k: K]: V`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variance: Option<Variance>,
     pub span: Span,
 }
 
@@ -665,6 +675,9 @@ pub struct TsTypeAliasDecl {
     pub type_params: Option<TsTypeParamDecl>,
     #[serde(rename = "typeAnnotation")]
     pub type_ann: Box<TsType>,
+    /// Flow: supertype of an `opaque type`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub super_type: Option<Box<TsType>>,
 }
 
 #[ast_node("TsEnumDeclaration")]
@@ -822,6 +835,18 @@ pub enum Accessibility {
     Private,
 }
 
+/// Flow variance annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "fold", derive(Fold))]
+pub enum Variance {
+    /// `+`
+    #[serde(rename = "plus")]
+    Covariant,
+    /// `-`
+    #[serde(rename = "minus")]
+    Contravariant,
+}
+
 #[ast_node("TsConstAssertion")]
 pub struct TsConstAssertion {
     pub span: Span,
@@ -108,24 +108,25 @@ fn emit_colors(b: &mut Bencher) {
             .unwrap();
 
         b.iter(|| {
-            let buf = vec![];
+            let mut buf = String::with_capacity(SOURCE.len());
             let mut src_map_builder = SourceMapBuilder::new(None);
             {
-                let handlers = box MyHandlers;
                 let mut emitter = Emitter {
                     cfg: swc_ecma_codegen::Config {
                         ..Default::default()
                     },
                     comments: None,
                     cm: cm.clone(),
-                    wr: box swc_ecma_codegen::text_writer::JsWriter::new(
+                    wr: swc_ecma_codegen::text_writer::JsWriter::new(
                         cm.clone(),
                         "\n",
-                        buf,
+                        &mut buf,
                         Some(&mut src_map_builder),
                     ),
-                    handlers,
+                    handlers: None,
                     pos_of_leading_comments: Default::default(),
+                    in_ambient: false,
+                    unchanged: Default::default(),
                 };
 
                 emitter.emit_module(&module)
@@ -134,7 +135,3 @@ fn emit_colors(b: &mut Bencher) {
         Ok(())
     });
 }
-
-struct MyHandlers;
-
-impl swc_ecma_codegen::Handlers for MyHandlers {}
@@ -70,8 +70,23 @@ fn (&mut self, node: Node) -> Result;
                     {
                         const _FOO: () = {
                             impl ::Node for NodeType {
-                                fn emit_with(&self, e: &mut ::Emitter) -> Result {
-                                    e.mtd_name(self)
+                                fn emit_with<Wr: ::WriteJs>(
+                                    &self,
+                                    e: &mut ::Emitter<Wr>,
+                                ) -> Result {
+                                    let kind = stringify!(NodeType);
+                                    let span = ::swc_common::Spanned::span(self);
+
+                                    if let Some(ref mut h) = e.handlers {
+                                        h.on_enter_node(kind, span, &mut e.wr);
+                                    }
+                                    if !e.reuse_source_text(kind, self)? {
+                                        e.mtd_name(self)?;
+                                    }
+                                    if let Some(ref mut h) = e.handlers {
+                                        h.on_exit_node(kind, span, &mut e.wr);
+                                    }
+                                    Ok(())
                                 }
                             }
                             ()
@@ -16,6 +16,10 @@ macro_rules! write_comments {
                     }
                     $e.wr.write_comment(cmt.span, "//")?;
                     $e.wr.write_comment(cmt.span, &cmt.text)?;
+                    // Line comments must be terminated even if minified.
+                    if $e.cfg.minify {
+                        $e.wr.write_line()?;
+                    }
                 }
                 CommentKind::Block => {
                     if $prefix_space {
@@ -33,7 +37,7 @@ macro_rules! write_comments {
     }};
 }
 
-impl<'a> Emitter<'a> {
+impl<'a, W: WriteJs> Emitter<'a, W> {
     pub(super) fn emit_trailing_comments_of_pos(
         &mut self,
         pos: BytePos,
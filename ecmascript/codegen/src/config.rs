@@ -1,4 +1,131 @@
-#[derive(Debug, Default, Clone, Copy)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Config {
+    #[serde(default)]
     pub minify: bool,
+
+    /// Number of spaces used for a level of indentation.
+    #[serde(default = "default_indent_width")]
+    pub indent_width: usize,
+
+    /// Indent with tabs instead of spaces.
+    #[serde(default)]
+    pub use_tabs: bool,
+
+    #[serde(default)]
+    pub quotes: Quotes,
+
+    #[serde(default)]
+    pub semicolons: Semicolons,
+
+    #[serde(default)]
+    pub trailing_comma: TrailingComma,
+
+    /// Print spaces between braces of import / export specifiers and object
+    /// patterns, like `{ a, b }`.
+    #[serde(default = "default_bracket_spacing")]
+    pub bracket_spacing: bool,
+
+    /// If set, argument lists and array literals which don't fit in this
+    /// width are printed one element per line.
+    #[serde(default)]
+    pub line_width: Option<usize>,
+
+    /// Escape non-ascii characters of strings, template literals,
+    /// identifiers and regular expressions.
+    #[serde(default)]
+    pub ascii_only: bool,
+
+    /// Copy the source text of nodes which are not modified, to keep the
+    /// original formatting and comments.
+    #[serde(default)]
+    pub preserve_unchanged: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            minify: false,
+            indent_width: default_indent_width(),
+            use_tabs: false,
+            quotes: Default::default(),
+            semicolons: Default::default(),
+            trailing_comma: Default::default(),
+            bracket_spacing: default_bracket_spacing(),
+            line_width: None,
+            ascii_only: false,
+            preserve_unchanged: false,
+        }
+    }
+}
+
+impl Config {
+    /// String used for a level of indentation.
+    pub fn indent_str(&self) -> String {
+        if self.use_tabs {
+            "\t".into()
+        } else {
+            " ".repeat(self.indent_width)
+        }
+    }
+}
+
+fn default_indent_width() -> usize {
+    4
+}
+
+fn default_bracket_spacing() -> bool {
+    true
+}
+
+/// Quotes of string literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Quotes {
+    /// Keep quotes of the original source code.
+    Preserve,
+    Single,
+    Double,
+}
+
+impl Default for Quotes {
+    fn default() -> Self {
+        Quotes::Preserve
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Semicolons {
+    Always,
+    /// Omit semicolons at the end of lines unless the next line starts with a
+    /// token which would continue the statement.
+    AsNeeded,
+}
+
+impl Default for Semicolons {
+    fn default() -> Self {
+        Semicolons::Always
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TrailingComma {
+    /// Keep trailing commas of the original source code.
+    Preserve,
+    None,
+    /// Add trailing commas to multi-line object literals, array literals and
+    /// import / export specifiers.
+    Es5,
+    /// Same as `Es5`, but argument lists are also included.
+    All,
+}
+
+impl Default for TrailingComma {
+    fn default() -> Self {
+        TrailingComma::Preserve
+    }
 }
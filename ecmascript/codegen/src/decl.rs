@@ -1,9 +1,9 @@
-use super::{list::ListFormat, Emitter, Result};
+use super::{list::ListFormat, text_writer::WriteJs, Emitter, Result};
 use swc_common::Spanned;
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::emitter;
 
-impl<'a> Emitter<'a> {
+impl<'a, W: WriteJs> Emitter<'a, W> {
     #[emitter]
     pub fn emit_decl(&mut self, node: &Decl) -> Result {
         match *node {
@@ -28,6 +28,11 @@ impl<'a> Emitter<'a> {
         for dec in &node.class.decorators {
             emit!(dec);
         }
+        self.emit_declare(node.declare)?;
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
         keyword!("class");
         space!();
         emit!(node.ident);
@@ -39,6 +44,7 @@ impl<'a> Emitter<'a> {
     pub fn emit_fn_decl(&mut self, node: &FnDecl) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
+        self.emit_declare(node.declare)?;
         if node.function.is_async {
             keyword!("async");
             space!();
@@ -59,6 +65,7 @@ impl<'a> Emitter<'a> {
     pub fn emit_var_decl(&mut self, node: &VarDecl) -> Result {
         self.emit_leading_comments_of_pos(node.span.lo())?;
 
+        self.emit_declare(node.declare)?;
         keyword!(node.kind.as_str());
         space!();
 
@@ -73,7 +80,16 @@ impl<'a> Emitter<'a> {
     pub fn emit_var_declator(&mut self, node: &VarDeclarator) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        emit!(node.name);
+        match node.name {
+            // `let a!: number;`
+            Pat::Ident(ref i) if node.definite => {
+                self.emit_leading_comments_of_pos(i.span.lo())?;
+                self.write_ident_sym(i)?;
+                punct!("!");
+                opt!(i.type_ann);
+            }
+            _ => emit!(node.name),
+        }
 
         if let Some(ref init) = node.init {
             formatting_space!();
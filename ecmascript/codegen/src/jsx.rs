@@ -1,10 +1,10 @@
-use super::{Emitter, Result};
-use crate::list::ListFormat;
+use super::{text_writer::WriteJs, Emitter, Result};
+use crate::{list::ListFormat, util::escape_non_ascii_jsx_text};
 use swc_common::Spanned;
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::emitter;
 
-impl<'a> Emitter<'a> {
+impl<'a, W: WriteJs> Emitter<'a, W> {
     #[emitter]
     pub fn emit_jsx_element(&mut self, node: &JSXElement) -> Result {
         emit!(node.opening);
@@ -22,6 +22,7 @@ impl<'a> Emitter<'a> {
     pub fn emit_jsx_opening_element(&mut self, node: &JSXOpeningElement) -> Result {
         punct!("<");
         emit!(node.name);
+        opt!(node.type_args);
 
         self.emit_list(
             node.span(),
@@ -146,7 +147,12 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     pub fn emit_jsx_text(&mut self, node: &JSXText) -> Result {
-        self.emit_js_word(node.span(), &node.value)?;
+        if self.cfg.ascii_only {
+            self.wr
+                .write_str_lit(node.span, &escape_non_ascii_jsx_text(&node.value))?;
+        } else {
+            self.emit_js_word(node.span(), &node.value)?;
+        }
     }
 
     #[emitter]
@@ -9,18 +9,25 @@ extern crate swc_ecma_codegen_macros;
 #[macro_use]
 extern crate swc_common;
 extern crate hashbrown;
+extern crate serde;
 extern crate swc_ecma_ast;
 #[cfg(test)]
 extern crate testing;
 
-pub use self::config::Config;
+pub use self::{
+    config::{Config, Quotes, Semicolons, TrailingComma},
+    reuse::Unchanged,
+};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
-    util::{SourceMapperExt, SpanExt, StartsWithAlphaNum},
+    util::{
+        escape_non_ascii, is_legacy_octal, parse_num_lit, unescape_str_lit, SourceMapperExt,
+        SpanExt, StartsWithAlphaNum,
+    },
 };
 use hashbrown::HashSet;
-use std::{io, sync::Arc};
+use std::{io, mem, sync::Arc};
 use swc_atoms::JsWord;
 use swc_common::{comments::Comments, BytePos, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -34,6 +41,7 @@ mod decl;
 mod expr;
 mod jsx;
 pub mod list;
+mod reuse;
 mod stmt;
 #[cfg(test)]
 mod tests;
@@ -43,35 +51,53 @@ pub mod util;
 
 pub type Result = io::Result<()>;
 
+/// Hooks called while emitting nodes.
+///
+/// `kind` is the name of the ast type of the node, like `"BinExpr"`. The
+/// current output position is available from `wr`, which can also be used to
+/// write annotations.
 pub trait Handlers {
-    // fn on_before_emit_token(&mut self, _node: &Any) {}
-    // fn on_after_emit_token(&mut self, _node: &Any) {}
+    /// Called before emitting a node.
+    fn on_enter_node(&mut self, _kind: &'static str, _span: Span, _wr: &mut dyn WriteJs) {}
+
+    /// Called after emitting a node.
+    fn on_exit_node(&mut self, _kind: &'static str, _span: Span, _wr: &mut dyn WriteJs) {}
 }
 
 pub trait Node: Spanned {
-    fn emit_with(&self, e: &mut Emitter) -> Result;
+    fn emit_with<W: WriteJs>(&self, e: &mut Emitter<W>) -> Result;
 }
 impl<N: Node> Node for Box<N> {
-    fn emit_with(&self, e: &mut Emitter) -> Result {
+    fn emit_with<W: WriteJs>(&self, e: &mut Emitter<W>) -> Result {
         (**self).emit_with(e)
     }
 }
 impl<'a, N: Node> Node for &'a N {
-    fn emit_with(&self, e: &mut Emitter) -> Result {
+    fn emit_with<W: WriteJs>(&self, e: &mut Emitter<W>) -> Result {
         (**self).emit_with(e)
     }
 }
 
-pub struct Emitter<'a> {
+/// Writes code to `wr`.
+///
+/// `W` defaults to a boxed trait object, but a concrete writer avoids dynamic
+/// dispatch for each token.
+pub struct Emitter<'a, W: WriteJs = Box<dyn 'a + WriteJs>> {
     pub cfg: config::Config,
     pub cm: Arc<SourceMap>,
     pub comments: Option<&'a Comments>,
-    pub wr: Box<(dyn 'a + WriteJs)>,
-    pub handlers: Box<(dyn 'a + Handlers)>,
+    pub wr: W,
+    /// Hooks called for each node. Nodes are emitted without calling any
+    /// hook if this is `None`.
+    pub handlers: Option<Box<dyn 'a + Handlers>>,
     pub pos_of_leading_comments: HashSet<BytePos>,
+    /// True while emitting the body of a `declare` namespace or module.
+    pub in_ambient: bool,
+    /// Used if `cfg.preserve_unchanged` is true.
+    pub unchanged: Unchanged,
 }
 
-impl<'a> Emitter<'a> {
+impl<'a, W: WriteJs> Emitter<'a, W> {
     pub fn emit_stmts(&mut self, stmts: &[Stmt]) -> Result {
         let span = if stmts.is_empty() {
             DUMMY_SP
@@ -169,7 +195,14 @@ impl<'a> Emitter<'a> {
             DefaultDecl::Fn(ref n) => emit!(n),
             DefaultDecl::TsInterfaceDecl(ref n) => emit!(n),
         }
-        semi!();
+        match node.decl {
+            // A function without body is already terminated.
+            DefaultDecl::Fn(FnExpr {
+                function: Function { body: None, .. },
+                ..
+            }) => {}
+            _ => semi!(),
+        }
     }
 
     #[emitter]
@@ -325,7 +358,7 @@ impl<'a> Emitter<'a> {
             Lit::Num(ref n) => emit!(n),
             Lit::Regex(ref n) => {
                 punct!("/");
-                self.emit_js_word(n.exp.span, &n.exp.value)?;
+                self.write_lit_text(n.exp.span, &n.exp.value)?;
                 punct!("/");
                 if let Some(ref flags) = n.flags {
                     self.emit_js_word(flags.span, &flags.value)?;
@@ -341,6 +374,24 @@ impl<'a> Emitter<'a> {
         Ok(())
     }
 
+    /// Writes the text of a string literal, a template literal, a regular
+    /// expression or a lazy body.
+    fn write_lit_text(&mut self, span: Span, s: &str) -> Result {
+        if self.cfg.ascii_only {
+            self.wr.write_str_lit(span, &escape_non_ascii(s, false))
+        } else {
+            self.wr.write_str_lit(span, s)
+        }
+    }
+
+    fn write_ident_sym(&mut self, ident: &Ident) -> Result {
+        if self.cfg.ascii_only {
+            self.wr.write_symbol(ident.span, &escape_non_ascii(&ident.sym, true))
+        } else {
+            self.wr.write_symbol(ident.span, &ident.sym)
+        }
+    }
+
     #[emitter]
     pub fn emit_str_lit(&mut self, node: &Str) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
@@ -349,6 +400,11 @@ impl<'a> Emitter<'a> {
         //     self.wr.write_str_lit(node.span, &s)?;
         //     return Ok(());
         // }
+        if let Some(raw) = self.raw_of_str_lit(node) {
+            self.write_lit_text(node.span, raw)?;
+            return Ok(());
+        }
+
         let value = node
             .value
             .replace("\\", "\\\\")
@@ -371,20 +427,24 @@ impl<'a> Emitter<'a> {
             .replace("\0", "\\0");
         // let value = node.value.replace("\n", "\\n");
 
-        if !node.value.contains("'") {
-            punct!("'");
-            self.wr.write_str_lit(node.span, &value)?;
-            punct!("'");
+        let (quote, alternative) = match self.cfg.quotes {
+            Quotes::Double => ("\"", "'"),
+            Quotes::Preserve | Quotes::Single => ("'", "\""),
+        };
+
+        if !node.value.contains(quote) {
+            punct!(quote);
+            self.write_lit_text(node.span, &value)?;
+            punct!(quote);
         } else {
-            if !node.value.contains("\"") {
-                punct!("\"");
-                self.wr.write_str_lit(node.span, &value)?;
-                punct!("\"");
+            if !node.value.contains(alternative) {
+                punct!(alternative);
+                self.write_lit_text(node.span, &value)?;
+                punct!(alternative);
             } else {
-                punct!("'");
-                self.wr
-                    .write_str_lit(node.span, &value.replace("'", "\\'"))?;
-                punct!("'");
+                punct!(quote);
+                self.write_lit_text(node.span, &value.replace(quote, &format!("\\{}", quote)))?;
+                punct!(quote);
             }
         }
     }
@@ -393,6 +453,11 @@ impl<'a> Emitter<'a> {
     pub fn emit_num_lit(&mut self, num: &Number) -> Result {
         self.emit_leading_comments_of_pos(num.span().lo())?;
 
+        if let Some(raw) = self.raw_of_num_lit(num) {
+            self.wr.write_str_lit(num.span, raw)?;
+            return Ok(());
+        }
+
         // Handle infinity
         if num.value.is_infinite() {
             if num.value.is_sign_negative() {
@@ -404,6 +469,42 @@ impl<'a> Emitter<'a> {
         }
     }
 
+    /// Returns the source text of `node` if it can be emitted as-is.
+    ///
+    /// Transforms may modify `value` without resetting `raw`, so `raw` is used
+    /// only if it still represents `value`. It's also not used if it's not
+    /// quoted as configured.
+    fn raw_of_str_lit<'n>(&self, node: &'n Str) -> Option<&'n str> {
+        if self.cfg.minify {
+            return None;
+        }
+        let raw = node.raw.as_ref()?;
+        let quote_matches = match self.cfg.quotes {
+            Quotes::Preserve => true,
+            Quotes::Single => raw.starts_with('\''),
+            Quotes::Double => raw.starts_with('"'),
+        };
+        if !quote_matches {
+            return None;
+        }
+        match unescape_str_lit(raw) {
+            Some(ref v) if *v == *node.value => Some(&**raw),
+            _ => None,
+        }
+    }
+
+    /// Same as `raw_of_str_lit`, but for numeric literals.
+    fn raw_of_num_lit<'n>(&self, num: &'n Number) -> Option<&'n str> {
+        if self.cfg.minify {
+            return None;
+        }
+        let raw = num.raw.as_ref()?;
+        match parse_num_lit(raw) {
+            Some(v) if v == num.value => Some(&**raw),
+            _ => None,
+        }
+    }
+
     // pub fn emit_object_binding_pat(&mut self, node: &ObjectPat) -> Result {
     //     self.wr.write_punct("{")?;
     //     self.emit_list(
@@ -480,6 +581,9 @@ impl<'a> Emitter<'a> {
             Expr::TsConstAssertion(ref n) => emit!(n),
             Expr::TsTypeCast(ref n) => emit!(n),
             Expr::TsOptChain(ref n) => emit!(n),
+            Expr::ArgPlaceholder(ref n) => emit!(n),
+            Expr::Do(ref n) => emit!(n),
+            Expr::Throw(ref n) => emit!(n),
         }
     }
 
@@ -488,6 +592,7 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
         emit!(node.callee);
+        opt!(node.type_args);
 
         punct!("(");
         self.emit_expr_or_spreads(node.span(), &node.args, ListFormat::CallExpressionArguments)?;
@@ -501,6 +606,7 @@ impl<'a> Emitter<'a> {
         keyword!("new");
         space!();
         emit!(node.callee);
+        opt!(node.type_args);
 
         if let Some(ref args) = node.args {
             punct!("(");
@@ -540,7 +646,14 @@ impl<'a> Emitter<'a> {
         match *expr {
             ExprOrSuper::Expr(ref expr) => {
                 match **expr {
-                    Expr::Lit(Lit::Num(Number { span, value })) => {
+                    Expr::Lit(Lit::Num(ref num)) => {
+                        if let Some(raw) = self.raw_of_num_lit(num) {
+                            // `1.0.toString()` and `0xF.toString()` are valid as-is.
+                            return raw.bytes().all(|b| b.is_ascii_digit() || b == b'_')
+                                && !is_legacy_octal(raw);
+                        }
+
+                        let Number { span, value, .. } = *num;
                         if value.fract() == 0.0 {
                             return true;
                         }
@@ -573,9 +686,11 @@ impl<'a> Emitter<'a> {
         if node.is_generator {
             punct!("*")
         }
+        opt!(node.type_params);
         punct!("(");
         self.emit_list(node.span, Some(&node.params), ListFormat::CommaListElements)?;
         punct!(")");
+        opt!(node.return_type);
 
         punct!("=>");
         emit!(node.body);
@@ -686,11 +801,25 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     pub fn emit_class_trailing(&mut self, node: &Class) -> Result {
+        opt!(node.type_params);
+
         if node.super_class.is_some() {
             space!();
             keyword!("extends");
             space!();
             emit!(node.super_class);
+            opt!(node.super_type_params);
+        }
+
+        if !node.implements.is_empty() {
+            space!();
+            keyword!("implements");
+            space!();
+            self.emit_list(
+                node.span,
+                Some(&node.implements),
+                ListFormat::HeritageClauseTypes,
+            )?;
         }
 
         punct!("{");
@@ -706,7 +835,10 @@ impl<'a> Emitter<'a> {
             ClassMember::Method(ref n) => emit!(n),
             ClassMember::PrivateMethod(ref n) => emit!(n),
             ClassMember::PrivateProp(ref n) => emit!(n),
-            ClassMember::TsIndexSignature(ref n) => emit!(n),
+            ClassMember::TsIndexSignature(ref n) => {
+                emit!(n);
+                semi!();
+            }
         }
     }
 
@@ -714,10 +846,18 @@ impl<'a> Emitter<'a> {
     pub fn emit_private_method(&mut self, n: &PrivateMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
+        for dec in &n.function.decorators {
+            emit!(dec);
+        }
+        self.emit_accessibility(n.accessibility)?;
         if n.is_static {
             keyword!("static");
             space!();
         }
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -743,6 +883,9 @@ impl<'a> Emitter<'a> {
                 emit!(n.key);
             }
         }
+        if n.is_optional {
+            punct!("?");
+        }
 
         self.emit_fn_trailing(&n.function)?;
     }
@@ -751,10 +894,18 @@ impl<'a> Emitter<'a> {
     pub fn emit_class_method(&mut self, n: &ClassMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
+        for dec in &n.function.decorators {
+            emit!(dec);
+        }
+        self.emit_accessibility(n.accessibility)?;
         if n.is_static {
             keyword!("static");
             space!();
         }
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -780,6 +931,9 @@ impl<'a> Emitter<'a> {
                 emit!(n.key);
             }
         }
+        if n.is_optional {
+            punct!("?");
+        }
 
         self.emit_fn_trailing(&n.function)?;
     }
@@ -788,26 +942,112 @@ impl<'a> Emitter<'a> {
     pub fn emit_private_prop(&mut self, n: &PrivateProp) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_private_prop")
+        for dec in &n.decorators {
+            emit!(dec);
+        }
+        self.emit_accessibility(n.accessibility)?;
+        if n.is_static {
+            keyword!("static");
+            space!();
+        }
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+        if n.is_accessor {
+            keyword!("accessor");
+            space!();
+        }
+
+        emit!(n.key);
+        if n.is_optional {
+            punct!("?");
+        }
+        if n.definite {
+            punct!("!");
+        }
+        opt!(n.type_ann);
+
+        if let Some(ref value) = n.value {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+            emit!(value);
+        }
+        semi!();
     }
 
     #[emitter]
     pub fn emit_class_prop(&mut self, node: &ClassProp) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        unimplemented!("emit_class_prop")
+        for dec in &node.decorators {
+            emit!(dec);
+        }
+        self.emit_accessibility(node.accessibility)?;
+        if node.is_static {
+            keyword!("static");
+            space!();
+        }
+        if node.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+        if node.readonly {
+            keyword!("readonly");
+            space!();
+        }
+        if node.is_accessor {
+            keyword!("accessor");
+            space!();
+        }
+
+        if node.computed {
+            punct!("[");
+            emit!(node.key);
+            punct!("]");
+        } else {
+            emit!(node.key);
+        }
+        if node.is_optional {
+            punct!("?");
+        }
+        if node.definite {
+            punct!("!");
+        }
+        opt!(node.type_ann);
+
+        if let Some(ref value) = node.value {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+            emit!(value);
+        }
+        semi!();
     }
 
     #[emitter]
     pub fn emit_class_constructor(&mut self, n: &Constructor) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
+        self.emit_accessibility(n.accessibility)?;
         keyword!("constructor");
+        if n.is_optional {
+            punct!("?");
+        }
         punct!("(");
         self.emit_list(n.span(), Some(&n.params), ListFormat::Parameters)?;
         punct!(")");
 
-        emit!(n.body);
+        match n.body {
+            Some(ref body) => emit!(body),
+            // Overload signature
+            None => semi!(),
+        }
     }
 
     #[emitter]
@@ -863,12 +1103,20 @@ impl<'a> Emitter<'a> {
     /// prints `(b){}` from `function a(b){}`
     #[emitter]
     pub fn emit_fn_trailing(&mut self, node: &Function) -> Result {
+        opt!(node.type_params);
         punct!("(");
         self.emit_list(node.span, Some(&node.params), ListFormat::CommaListElements)?;
         punct!(")");
+        opt!(node.return_type);
 
-        formatting_space!();
-        emit!(node.body);
+        match node.body {
+            Some(ref body) => {
+                formatting_space!();
+                emit!(body);
+            }
+            // Overload signature or a declaration
+            None => semi!(),
+        }
     }
 
     #[emitter]
@@ -939,7 +1187,7 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     pub fn emit_quasi(&mut self, node: &TplElement) -> Result {
-        self.wr.write_str_lit(node.span, &node.raw.value)?;
+        self.write_lit_text(node.span, &node.raw.value)?;
         return Ok(());
     }
 
@@ -1008,6 +1256,7 @@ impl<'a> Emitter<'a> {
         nodes: &[ExprOrSpread],
         format: ListFormat,
     ) -> Result {
+        let format = self.wrap_list(parent_node, nodes, format)?;
         self.emit_list(parent_node, Some(nodes), format)
     }
 
@@ -1028,21 +1277,53 @@ impl<'a> Emitter<'a> {
 
         keyword!("await");
 
+        // `x |> await`
+        if let Expr::ArgPlaceholder(..) = *node.arg {
+            return Ok(());
+        }
+
         space!();
 
         emit!(&node.arg);
     }
 
+    #[emitter]
+    pub fn emit_arg_placeholder(&mut self, node: &ArgPlaceholder) -> Result {
+        self.emit_leading_comments_of_pos(node.span().lo())?;
+
+        punct!("?");
+    }
+
+    #[emitter]
+    pub fn emit_do_expr(&mut self, node: &DoExpr) -> Result {
+        self.emit_leading_comments_of_pos(node.span().lo())?;
+
+        keyword!("do");
+        formatting_space!();
+        emit!(node.body);
+    }
+
+    #[emitter]
+    pub fn emit_throw_expr(&mut self, node: &ThrowExpr) -> Result {
+        self.emit_leading_comments_of_pos(node.span().lo())?;
+
+        keyword!("throw");
+        space!();
+        emit!(node.arg);
+    }
+
     #[emitter]
     pub fn emit_array_lit(&mut self, node: &ArrayLit) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        punct!("[");
-        self.emit_list(
+        let format = self.wrap_list(
             node.span(),
-            Some(&node.elems),
+            &node.elems,
             ListFormat::ArrayLiteralExpressionElements,
         )?;
+
+        punct!("[");
+        self.emit_list(node.span(), Some(&node.elems), format)?;
         punct!("]");
     }
 
@@ -1176,13 +1457,18 @@ impl<'a> Emitter<'a> {
             unimplemented!()
         } else {
             // TODO: span
-            self.wr.write_symbol(ident.span, &ident.sym)?
+            self.write_ident_sym(ident)?
 
             // self.wr
             //     .write(get_text_of_node(&self.cm, &ident, /* includeTrivia */
             // false).as_bytes())?;
         }
 
+        if ident.optional {
+            punct!("?");
+        }
+        opt!(ident.type_ann);
+
         // Call emitList directly since it could be an array of
         // TypeParameterDeclarations _or_ type arguments
 
@@ -1204,6 +1490,83 @@ impl<'a> Emitter<'a> {
         )
     }
 
+    /// Copies the source text of `node` if `preserve_unchanged` is enabled and
+    /// `node` is not modified.
+    ///
+    /// A node is considered as unmodified if its span and the spans of its
+    /// descendants are in the source order, and the tokens between them are
+    /// the same as the source text. See [Unchanged].
+    ///
+    /// Source text containing non-ascii characters is not copied if
+    /// `ascii_only` is enabled.
+    fn reuse_source_text<N: Node>(&mut self, kind: &str, node: &N) -> io::Result<bool> {
+        let span = node.span();
+        if !self.cfg.preserve_unchanged || span.is_dummy() {
+            return Ok(false);
+        }
+        match kind {
+            // These emit comments after the node.
+            "Stmt" | "ModuleItem" => return Ok(false),
+            _ => {}
+        }
+        if !self.unchanged.covers(span) {
+            self.unchanged = Unchanged::analyze(self.cm.clone(), &self.cfg, self.in_ambient, node)?;
+        }
+        if !self.unchanged.is_unchanged(span) {
+            return Ok(false);
+        }
+        let src = match self.cm.span_to_snippet(span) {
+            Ok(src) => src,
+            Err(..) => return Ok(false),
+        };
+        if self.cfg.ascii_only && !src.is_ascii() {
+            return Ok(false);
+        }
+
+        self.emit_leading_comments_of_pos(span.lo())?;
+        self.wr.write_lit(span, &src)?;
+        Ok(true)
+    }
+
+    /// Returns a multi-line variant of `format` if `children` does not fit in
+    /// the configured line width.
+    fn wrap_list<N: Node>(
+        &mut self,
+        parent_node: Span,
+        children: &[N],
+        format: ListFormat,
+    ) -> io::Result<ListFormat> {
+        let line_width = match self.cfg.line_width {
+            Some(line_width) if !self.cfg.minify && !children.is_empty() => line_width,
+            _ => return Ok(format),
+        };
+
+        // Print the list on a single line until it is known whether it fits. 2 for
+        // brackets.
+        let mut e = Emitter {
+            cfg: Config {
+                line_width: None,
+                trailing_comma: TrailingComma::None,
+                ..self.cfg
+            },
+            cm: self.cm.clone(),
+            comments: self.comments,
+            wr: text_writer::Fits::new(self.wr.column() + 2, line_width),
+            handlers: None,
+            pos_of_leading_comments: Default::default(),
+            in_ambient: self.in_ambient,
+            unchanged: mem::replace(&mut self.unchanged, Default::default()),
+        };
+        let res = e.emit_list(parent_node, Some(children), format - ListFormat::LinesMask);
+        self.unchanged = e.unchanged;
+
+        if e.wr.result(res)? {
+            return Ok(format);
+        }
+
+        Ok((format - ListFormat::LinesMask) | ListFormat::MultiLine | ListFormat::Indented)
+    }
+
     pub fn emit_list5<N: Node>(
         &mut self,
         parent_node: Span,
@@ -1216,6 +1579,12 @@ impl<'a> Emitter<'a> {
             return Ok(());
         }
 
+        let format = if self.cfg.bracket_spacing {
+            format
+        } else {
+            format - ListFormat::SpaceBetweenBraces
+        };
+
         let is_empty = children.is_none() || start > children.unwrap().len() || count == 0;
         if is_empty && format.contains(ListFormat::OptionalIfEmpty) {
             // self.handlers.onBeforeEmitNodeArray(children)
@@ -1352,22 +1721,44 @@ impl<'a> Emitter<'a> {
                 previous_sibling = Some(child.span());
             }
 
+            let closing_line = self
+                .cm
+                .should_write_closing_line_terminator(parent_node, children, format);
+
             // Write a trailing comma, if requested.
-            let has_trailing_comma = format.contains(ListFormat::AllowTrailingComma) && {
-                match self.cm.span_to_snippet(parent_node) {
-                    Ok(snippet) => {
-                        if snippet.len() < 3 {
-                            false
-                        } else {
-                            snippet[..snippet.len() - 1].trim().ends_with(",")
+            let has_trailing_comma = match self.cfg.trailing_comma {
+                TrailingComma::Preserve => {
+                    format.contains(ListFormat::AllowTrailingComma) && {
+                        match self.cm.span_to_snippet(parent_node) {
+                            Ok(snippet) => {
+                                if snippet.len() < 3 {
+                                    false
+                                } else {
+                                    snippet[..snippet.len() - 1].trim().ends_with(",")
+                                }
+                            }
+                            _ => false,
                         }
                     }
-                    _ => false,
+                }
+                TrailingComma::None => false,
+                TrailingComma::Es5 | TrailingComma::All => {
+                    // Parenthesized lists which can be multi-line are argument lists.
+                    let allowed = if self.cfg.trailing_comma == TrailingComma::All {
+                        ListFormat::AllowTrailingComma
+                            | ListFormat::Braces
+                            | ListFormat::Parenthesis
+                    } else {
+                        ListFormat::AllowTrailingComma | ListFormat::Braces
+                    };
+                    !self.cfg.minify && closing_line && format.intersects(allowed)
                 }
             };
             if format.contains(ListFormat::CommaDelimited) && has_trailing_comma {
                 self.wr.write_punct(",")?;
-                formatting_space!(self);
+                if !closing_line {
+                    formatting_space!(self);
+                }
             }
 
             {
@@ -1404,10 +1795,7 @@ impl<'a> Emitter<'a> {
             }
 
             // Write the closing line terminator or closing whitespace.
-            if self
-                .cm
-                .should_write_closing_line_terminator(parent_node, children, format)
-            {
+            if closing_line {
                 if !self.cfg.minify {
                     self.wr.write_line()?;
                 }
@@ -1436,7 +1824,7 @@ impl<'a> Emitter<'a> {
 }
 
 /// Patterns
-impl<'a> Emitter<'a> {
+impl<'a, W: WriteJs> Emitter<'a, W> {
     #[emitter]
     pub fn emit_pat(&mut self, node: &Pat) -> Result {
         match *node {
@@ -1455,6 +1843,7 @@ impl<'a> Emitter<'a> {
 
         punct!("...");
         emit!(node.arg);
+        opt!(node.type_ann);
     }
 
     #[emitter]
@@ -1492,6 +1881,7 @@ impl<'a> Emitter<'a> {
             ListFormat::ArrayBindingPatternElements,
         )?;
         punct!("]");
+        opt!(node.type_ann);
     }
 
     #[emitter]
@@ -1499,6 +1889,7 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
         emit!(node.left);
+        opt!(node.type_ann);
         formatting_space!();
         punct!("=");
         formatting_space!();
@@ -1516,6 +1907,7 @@ impl<'a> Emitter<'a> {
             ListFormat::ObjectBindingPatternElements,
         )?;
         punct!("}");
+        opt!(node.type_ann);
     }
 
     #[emitter]
@@ -1561,7 +1953,7 @@ impl<'a> Emitter<'a> {
 }
 
 /// Statements
-impl<'a> Emitter<'a> {
+impl<'a, W: WriteJs> Emitter<'a, W> {
     #[emitter]
     pub fn emit_stmt(&mut self, node: &Stmt) -> Result {
         match *node {
@@ -1575,6 +1967,7 @@ impl<'a> Emitter<'a> {
             }
             Stmt::Empty(ref e) => emit!(e),
             Stmt::Debugger(ref e) => emit!(e),
+            Stmt::Lazy(ref e) => emit!(e),
             Stmt::With(ref e) => emit!(e),
             Stmt::Return(ref e) => emit!(e),
             Stmt::Labeled(ref e) => emit!(e),
@@ -1615,7 +2008,8 @@ impl<'a> Emitter<'a> {
     pub fn emit_empty_stmt(&mut self, node: &EmptyStmt) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        punct!(";");
+        // This is not a terminator, so it can't be omitted.
+        self.wr.write_punct(";")?;
     }
 
     #[emitter]
@@ -1626,6 +2020,21 @@ impl<'a> Emitter<'a> {
         semi!();
     }
 
+    #[emitter]
+    pub fn emit_lazy_body(&mut self, node: &LazyBody) -> Result {
+        // The body is printed as-is, without the braces of the block.
+        if let Ok(src) = self.cm.span_to_snippet(node.span) {
+            let src = if src.len() >= 2 {
+                src[1..src.len() - 1].trim()
+            } else {
+                ""
+            };
+            if !src.is_empty() {
+                self.write_lit_text(node.span, src)?;
+            }
+        }
+    }
+
     #[emitter]
     pub fn emit_with_stmt(&mut self, node: &WithStmt) -> Result {
         keyword!("with");
@@ -1911,7 +2320,7 @@ impl<'a> Emitter<'a> {
     }
 }
 
-impl<'a> Emitter<'a> {
+impl<'a, W: WriteJs> Emitter<'a, W> {
     fn write_delim(&mut self, f: ListFormat) -> Result {
         match f & ListFormat::DelimitersMask {
             ListFormat::None => {}
@@ -2017,7 +2426,7 @@ impl<N> Node for Option<N>
 where
     N: Node,
 {
-    fn emit_with(&self, e: &mut Emitter) -> Result {
+    fn emit_with<W: WriteJs>(&self, e: &mut Emitter<W>) -> Result {
         match *self {
             Some(ref n) => n.emit_with(e),
             None => Ok(()),
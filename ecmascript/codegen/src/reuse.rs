@@ -0,0 +1,252 @@
+use super::{
+    text_writer::{JsWriter, WriteJs},
+    Config, Emitter, Handlers, Node, Quotes, Semicolons, TrailingComma,
+};
+use hashbrown::HashMap;
+use std::{cell::RefCell, fmt, io, rc::Rc, sync::Arc};
+use swc_common::{BytePos, SourceMap, Span};
+
+/// Nodes which can be printed by copying their source text.
+///
+/// This is computed once for the outermost node printed with
+/// `preserve_unchanged`, by printing it and comparing the output of each node
+/// with its source text.
+#[derive(Default)]
+pub struct Unchanged {
+    root: Option<Span>,
+    nodes: HashMap<(BytePos, BytePos), bool>,
+}
+
+impl Unchanged {
+    /// Returns true if `span` is in the node analyzed last.
+    pub(crate) fn covers(&self, span: Span) -> bool {
+        match self.root {
+            Some(root) => root.contains(span),
+            None => false,
+        }
+    }
+
+    pub(crate) fn is_unchanged(&self, span: Span) -> bool {
+        self.nodes
+            .get(&(span.lo(), span.hi()))
+            .cloned()
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn analyze<N: Node>(
+        cm: Arc<SourceMap>,
+        cfg: &Config,
+        in_ambient: bool,
+        node: &N,
+    ) -> io::Result<Self> {
+        let root = node.span();
+        let mut unchanged = Unchanged {
+            root: Some(root),
+            nodes: Default::default(),
+        };
+        let src = match cm.span_to_snippet(root) {
+            Ok(src) => src,
+            Err(..) => return Ok(unchanged),
+        };
+
+        let out = Output::default();
+        {
+            let mut e = Emitter {
+                cfg: Config {
+                    minify: false,
+                    quotes: Quotes::Preserve,
+                    semicolons: Semicolons::Always,
+                    trailing_comma: TrailingComma::Preserve,
+                    line_width: None,
+                    // Compared with the source text. Non-ascii source text is
+                    // not copied if `ascii_only` is enabled.
+                    ascii_only: false,
+                    preserve_unchanged: false,
+                    ..*cfg
+                },
+                cm: cm.clone(),
+                comments: None,
+                wr: JsWriter::new(cm.clone(), "\n", out.clone(), None),
+                handlers: Some(Box::new(Measure {
+                    root,
+                    src: &src,
+                    out: out.clone(),
+                    frames: vec![],
+                    nodes: &mut unchanged.nodes,
+                })),
+                pos_of_leading_comments: Default::default(),
+                in_ambient,
+                unchanged: Default::default(),
+            };
+            node.emit_with(&mut e)?;
+        }
+
+        Ok(unchanged)
+    }
+}
+
+#[derive(Clone, Default)]
+struct Output(Rc<RefCell<String>>);
+
+impl fmt::Write for Output {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.borrow_mut().push_str(s);
+        Ok(())
+    }
+}
+
+/// A node being printed.
+struct Frame {
+    span: Span,
+    /// Start of the output of the node.
+    start: usize,
+    changed: bool,
+    /// Spans and output ranges of the child nodes.
+    children: Vec<(Span, usize, usize)>,
+}
+
+/// Marks a node as changed if its span is not in the order of the source, a
+/// child is changed, or the tokens between its children differ from the
+/// source.
+///
+/// Each byte of the output is compared once, so this is linear in the size
+/// of the output.
+struct Measure<'a> {
+    root: Span,
+    src: &'a str,
+    out: Output,
+    frames: Vec<Frame>,
+    nodes: &'a mut HashMap<(BytePos, BytePos), bool>,
+}
+
+impl<'a> Measure<'a> {
+    fn src(&self, lo: BytePos, hi: BytePos) -> &'a str {
+        let base = self.root.lo().0;
+        &self.src[(lo.0 - base) as usize..(hi.0 - base) as usize]
+    }
+
+    fn matches_source(&self, frame: &Frame, end: usize) -> bool {
+        let span = frame.span;
+        if span.is_dummy() || !self.root.contains(span) {
+            return false;
+        }
+        let out = self.out.0.borrow();
+
+        if frame.children.is_empty() {
+            // Whitespaces are significant in identifiers, literals and
+            // template elements.
+            let src = self.src(span.lo(), span.hi());
+            let printed = &out[frame.start..end];
+            return if src.starts_with(char::is_whitespace) {
+                printed == src
+            } else {
+                printed.trim_start() == src
+            };
+        }
+
+        let (mut lo, mut start) = (span.lo(), frame.start);
+        for &(child, child_start, child_end) in &frame.children {
+            if child.lo() < lo || child.hi() > span.hi() {
+                return false;
+            }
+            if !same_tokens(self.src(lo, child.lo()), &out[start..child_start]) {
+                return false;
+            }
+            lo = child.hi();
+            start = child_end;
+        }
+        same_tokens(self.src(lo, span.hi()), &out[start..end])
+    }
+}
+
+impl<'a> Handlers for Measure<'a> {
+    fn on_enter_node(&mut self, _: &'static str, span: Span, _: &mut dyn WriteJs) {
+        let start = self.out.0.borrow().len();
+        self.frames.push(Frame {
+            span,
+            start,
+            changed: false,
+            children: vec![],
+        });
+    }
+
+    fn on_exit_node(&mut self, _: &'static str, span: Span, _: &mut dyn WriteJs) {
+        let frame = match self.frames.pop() {
+            Some(frame) => frame,
+            None => return,
+        };
+        let end = self.out.0.borrow().len();
+
+        let changed = frame.changed || !self.matches_source(&frame, end);
+        if !span.is_dummy() {
+            *self.nodes.entry((span.lo(), span.hi())).or_insert(true) &= !changed;
+        }
+        if let Some(parent) = self.frames.last_mut() {
+            parent.changed |= changed;
+            parent.children.push((span, frame.start, end));
+        }
+    }
+}
+
+/// Compares tokens of `a` and `b`, ignoring whitespaces and comments.
+fn same_tokens(a: &str, b: &str) -> bool {
+    Tokens(a).eq(Tokens(b))
+}
+
+/// Splits code into words, string literals and punctuators.
+///
+/// Words are not merged across whitespaces, so `a b` and `ab` differ.
+struct Tokens<'a>(&'a str);
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let s = skip_trivia(self.0);
+        let c = s.chars().next()?;
+
+        let len = if is_word_char(c) {
+            s.find(|c| !is_word_char(c)).unwrap_or(s.len())
+        } else if c == '\'' || c == '"' {
+            let mut escaped = false;
+            s.char_indices()
+                .skip(1)
+                .find(|&(_, ch)| {
+                    let end = !escaped && ch == c;
+                    escaped = !escaped && ch == '\\';
+                    end
+                })
+                .map(|(i, _)| i + 1)
+                .unwrap_or(s.len())
+        } else {
+            c.len_utf8()
+        };
+
+        let (token, rest) = s.split_at(len);
+        self.0 = rest;
+        Some(token)
+    }
+}
+
+fn skip_trivia(mut s: &str) -> &str {
+    loop {
+        s = s.trim_start();
+        if s.starts_with("//") {
+            s = match s.find(|c| c == '\n' || c == '\r') {
+                Some(i) => &s[i..],
+                None => "",
+            };
+        } else if s.starts_with("/*") {
+            s = match s[2..].find("*/") {
+                Some(i) => &s[i + 4..],
+                None => "",
+            };
+        } else {
+            return s;
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$' || c == '\\' || !c.is_ascii()
+}
@@ -1,7 +1,8 @@
 extern crate swc_ecma_parser;
-use self::swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
+use self::swc_ecma_parser::{EsConfig, Parser, Session, SourceFileInput, Syntax, TsConfig};
 use super::*;
 use crate::config::Config;
+use sourcemap::SourceMapBuilder;
 use std::{
     fmt::{self, Debug, Display, Formatter},
     io::Write,
@@ -9,9 +10,6 @@ use std::{
 };
 use swc_common::{comments::Comments, FileName, SourceMap};
 
-struct Noop;
-impl Handlers for Noop {}
-
 struct Builder {
     cfg: Config,
     cm: Arc<SourceMap>,
@@ -19,17 +17,19 @@ struct Builder {
 }
 
 impl Builder {
-    pub fn with<F, Ret>(self, src: &str, s: &mut Vec<u8>, op: F) -> Ret
+    pub fn with<F, Ret>(self, src: &str, s: &mut String, op: F) -> Ret
     where
-        F: FnOnce(&mut Emitter) -> Ret,
+        F: FnOnce(&mut Emitter<text_writer::JsWriter<&mut String>>) -> Ret,
     {
         let mut e = Emitter {
             cfg: self.cfg,
             cm: self.cm.clone(),
-            wr: Box::new(text_writer::JsWriter::new(self.cm.clone(), "\n", s, None)),
+            wr: text_writer::JsWriter::new(self.cm.clone(), "\n", s, None).with_config(&self.cfg),
             comments: Some(&self.comments),
-            handlers: Box::new(Noop),
+            handlers: None,
             pos_of_leading_comments: Default::default(),
+            in_ambient: false,
+            unchanged: Default::default(),
         };
 
         let ret = op(&mut e);
@@ -39,17 +39,24 @@ impl Builder {
 
     pub fn text<F>(self, src: &str, op: F) -> String
     where
-        F: FnOnce(&mut Emitter),
+        F: FnOnce(&mut Emitter<text_writer::JsWriter<&mut String>>),
     {
-        let mut buf = vec![];
+        let mut buf = String::new();
 
         self.with(src, &mut buf, op);
 
-        String::from_utf8(buf).unwrap()
+        buf
     }
 }
 
-fn parse_then_emit(from: &str, cfg: Config) -> String {
+fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax) -> String {
+    parse_fold_then_emit(from, cfg, syntax, |m| m)
+}
+
+fn parse_fold_then_emit<F>(from: &str, cfg: Config, syntax: Syntax, op: F) -> String
+where
+    F: FnOnce(Module) -> Module,
+{
     ::testing::run_test(false, |cm, handler| {
         let src = cm.new_source_file(FileName::Real("custom.js".into()), from.to_string());
         println!(
@@ -61,7 +68,7 @@ fn parse_then_emit(from: &str, cfg: Config) -> String {
         let res = {
             let mut parser = Parser::new(
                 Session { handler: &handler },
-                Syntax::default(),
+                syntax,
                 SourceFileInput::from(&*src),
                 Some(&comments),
             );
@@ -76,26 +83,81 @@ fn parse_then_emit(from: &str, cfg: Config) -> String {
             cm: cm.clone(),
             comments,
         }
-        .text(from, |e| e.emit_module(&res).unwrap());
+        .text(from, |e| e.emit_module(&op(res)).unwrap());
         Ok(out)
     })
     .unwrap()
 }
 
 pub(crate) fn assert_min(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: true });
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
 }
 
 pub(crate) fn assert_pretty(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: false });
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: false,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(&out.trim()), DebugUsingDisplay(to),);
 }
 
+pub(crate) fn assert_min_typescript(from: &str, to: &str) {
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        typescript(),
+    );
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
+}
+
+pub(crate) fn assert_pretty_typescript(from: &str, to: &str) {
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: false,
+            ..Default::default()
+        },
+        typescript(),
+    );
+
+    assert_eq!(DebugUsingDisplay(&out.trim()), DebugUsingDisplay(to),);
+}
+
+fn typescript() -> Syntax {
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    })
+}
+
+fn assert_with_config(from: &str, cfg: Config, to: &str) {
+    let out = parse_then_emit(from, cfg, Syntax::default());
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
+}
+
+/// Literal statements are printed from their values, like ones created by
+/// transforms.
 fn test_from_to(from: &str, to: &str) {
-    let out = parse_then_emit(from, Default::default());
+    let out = parse_fold_then_emit(from, Default::default(), Syntax::default(), drop_raw);
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to.trim()),);
 }
@@ -172,6 +234,445 @@ fn no_octal_escape() {
     );
 }
 
+#[test]
+fn raw_literals() {
+    assert_pretty(
+        r#""foo";
+'b\x61r';
+0xFF;
+1e3;
+1.0.toString();
+1..toString();
+10..toString;
+({ 0o7: "a" });"#,
+        r#""foo";
+'b\x61r';
+0xFF;
+1e3;
+1.0.toString();
+1..toString();
+10..toString;
+({
+    0o7: "a"
+});"#,
+    );
+}
+
+#[test]
+fn raw_escapes() {
+    assert_pretty(
+        r#"'\x00a';
+"\u{1F600}";
+'\
+';"#,
+        r#"'\x00a';
+"\u{1F600}";
+'\
+';"#,
+    );
+}
+
+#[test]
+fn indent_with_tabs() {
+    assert_with_config(
+        "if (a) { b; }",
+        Config {
+            use_tabs: true,
+            ..Default::default()
+        },
+        "if (a) {\n\tb;\n}",
+    );
+}
+
+#[test]
+fn quotes() {
+    assert_with_config(
+        r#"'a'; "b"; 'c"d';"#,
+        Config {
+            quotes: Quotes::Double,
+            ..Default::default()
+        },
+        r#""a";
+"b";
+'c"d';"#,
+    );
+}
+
+#[test]
+fn semicolons_as_needed() {
+    assert_with_config(
+        "a;\n(b);\nif (a) ;\nc;",
+        Config {
+            semicolons: Semicolons::AsNeeded,
+            ..Default::default()
+        },
+        "a;\n(b)\nif (a) ;\nc",
+    );
+}
+
+#[test]
+fn bracket_spacing_and_trailing_comma() {
+    assert_with_config(
+        "import { a, b } from 'c'; ({ a: 1 });",
+        Config {
+            bracket_spacing: false,
+            trailing_comma: TrailingComma::Es5,
+            ..Default::default()
+        },
+        "import {a, b} from 'c';\n({\n    a: 1,\n});",
+    );
+}
+
+#[test]
+fn line_width() {
+    let cfg = Config {
+        line_width: Some(20),
+        trailing_comma: TrailingComma::All,
+        ..Default::default()
+    };
+
+    assert_with_config("foo(a, b);", cfg, "foo(a, b);");
+    assert_with_config(
+        "foo(aaaaaaaaaa, bbbbbbbbbb);",
+        cfg,
+        "foo(\n    aaaaaaaaaa,\n    bbbbbbbbbb,\n);",
+    );
+    assert_with_config(
+        "x = [aaaaaaaaaa, bbbbbbbbbb];",
+        cfg,
+        "x = [\n    aaaaaaaaaa,\n    bbbbbbbbbb,\n];",
+    );
+    assert_with_config(
+        "foo(/* long comment */ a);",
+        cfg,
+        "foo(\n    /* long comment */\n    a,\n);",
+    );
+}
+
+#[test]
+fn ascii_only() {
+    assert_with_config(
+        "var caf\u{e9} = '\u{e9}\u{1f600}', b = `\u{fc}${x}`;\n/\u{e4}/;",
+        Config {
+            ascii_only: true,
+            ..Default::default()
+        },
+        r"var caf\u00E9 = '\u00E9\uD83D\uDE00', b = `\u00FC${x}`;
+/\u00E4/;",
+    );
+
+    assert_with_config(
+        "x = '\u{e9}';\ny = [ 1 ];",
+        Config {
+            ascii_only: true,
+            preserve_unchanged: true,
+            ..Default::default()
+        },
+        "x = '\\u00E9';\ny = [ 1 ];",
+    );
+
+    assert_eq!(escape_non_ascii("a\\\u{2028}b\\\u{e9}", false), r"ab\u00E9");
+    assert_eq!(escape_non_ascii("\\\\\u{e9}", false), r"\\\u00E9");
+    assert_eq!(escape_non_ascii("\u{102a7}", true), r"\u{102A7}");
+}
+
+#[test]
+fn ascii_only_jsx_text() {
+    let out = parse_then_emit(
+        "<a>caf\u{e9} \u{1f600}</a>;",
+        Config {
+            ascii_only: true,
+            ..Default::default()
+        },
+        Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    );
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay("<a>caf&#xE9; &#x1F600;</a>;")
+    );
+}
+
+#[test]
+fn ascii_only_lazy_body() {
+    ::testing::run_test(false, |cm, handler| {
+        let src = cm.new_source_file(
+            FileName::Real("input.js".into()),
+            "function f() { return '\u{e9}'; }".into(),
+        );
+        let module = Parser::new(
+            Session { handler: &handler },
+            Syntax::default(),
+            SourceFileInput::from(&*src),
+            None,
+        )
+        .with_lazy_bodies(true)
+        .parse_module()
+        .map_err(|mut e| {
+            e.emit();
+            ()
+        })?;
+
+        let out = Builder {
+            cfg: Config {
+                ascii_only: true,
+                ..Default::default()
+            },
+            cm: cm.clone(),
+            comments: Default::default(),
+        }
+        .text("", |e| e.emit_module(&module).unwrap());
+        assert!(out.contains(r"return '\u00E9';"), "{}", out);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn io_writer() {
+    ::testing::run_test(false, |cm, handler| {
+        let src = cm.new_source_file(FileName::Anon, "foo( 1,2 );".into());
+        let module = Parser::new(
+            Session { handler: &handler },
+            Syntax::default(),
+            SourceFileInput::from(&*src),
+            None,
+        )
+        .parse_module()
+        .map_err(|mut e| {
+            e.emit();
+            ()
+        })?;
+
+        let mut buf = vec![];
+        {
+            let mut e = Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                wr: text_writer::JsWriter::new(
+                    cm.clone(),
+                    "\n",
+                    text_writer::IoWriter::new(&mut buf),
+                    None,
+                ),
+                comments: None,
+                handlers: None,
+                pos_of_leading_comments: Default::default(),
+                in_ambient: false,
+                unchanged: Default::default(),
+            };
+            e.emit_module(&module).unwrap();
+        }
+
+        let out = String::from_utf8(buf).unwrap();
+        assert_eq!(
+            DebugUsingDisplay(out.trim()),
+            DebugUsingDisplay("foo(1, 2);")
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn source_map_names() {
+    ::testing::run_test(false, |cm, handler| {
+        let src = cm.new_source_file(FileName::Real("input.js".into()), "var foo = bar;".into());
+        let mut module = Parser::new(
+            Session { handler: &handler },
+            Syntax::default(),
+            SourceFileInput::from(&*src),
+            None,
+        )
+        .parse_module()
+        .map_err(|mut e| {
+            e.emit();
+            ()
+        })?;
+
+        // Rename `foo` like `hygiene()` does.
+        match module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(ref mut var))) => match var.decls[0].name {
+                Pat::Ident(ref mut i) => i.sym = "foo1".into(),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+
+        let mut buf = String::new();
+        let mut srcmap = SourceMapBuilder::new(None);
+        {
+            let mut e = Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                wr: text_writer::JsWriter::new(cm.clone(), "\n", &mut buf, Some(&mut srcmap)),
+                comments: None,
+                handlers: None,
+                pos_of_leading_comments: Default::default(),
+                in_ambient: false,
+                unchanged: Default::default(),
+            };
+            e.emit_module(&module).unwrap();
+        }
+        let srcmap = srcmap.into_sourcemap();
+
+        let names: Vec<_> = srcmap.tokens().filter_map(|t| t.get_name()).collect();
+        assert_eq!(names, vec!["foo", "bar"]);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn handlers() {
+    struct Recorder<'a>(&'a mut Vec<(&'static str, usize, usize)>);
+    impl<'a> Handlers for Recorder<'a> {
+        fn on_enter_node(&mut self, kind: &'static str, _: Span, wr: &mut dyn WriteJs) {
+            self.0.push((kind, wr.line(), wr.column()));
+        }
+    }
+
+    ::testing::run_test(false, |cm, handler| {
+        let src = cm.new_source_file(FileName::Real("input.js".into()), "a;\nb + c;".into());
+        let module = Parser::new(
+            Session { handler: &handler },
+            Syntax::default(),
+            SourceFileInput::from(&*src),
+            None,
+        )
+        .parse_module()
+        .map_err(|mut e| {
+            e.emit();
+            ()
+        })?;
+
+        let mut buf = String::new();
+        let mut nodes = vec![];
+        {
+            let mut e = Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                wr: text_writer::JsWriter::new(cm.clone(), "\n", &mut buf, None),
+                comments: None,
+                handlers: Some(Box::new(Recorder(&mut nodes))),
+                pos_of_leading_comments: Default::default(),
+                in_ambient: false,
+                unchanged: Default::default(),
+            };
+            e.emit_module(&module).unwrap();
+        }
+
+        let idents: Vec<_> = nodes.iter().filter(|n| n.0 == "Ident").cloned().collect();
+        assert_eq!(
+            idents,
+            vec![("Ident", 0, 0), ("Ident", 1, 0), ("Ident", 1, 4)]
+        );
+        assert!(nodes.contains(&("BinExpr", 1, 0)));
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn preserve_unchanged() {
+    let cfg = Config {
+        preserve_unchanged: true,
+        ..Default::default()
+    };
+
+    assert_with_config(
+        "if (a)   {\n  b( 1,2 ) ; // c\n}",
+        cfg,
+        "if (a)   {\n  b( 1,2 ) ; // c\n}",
+    );
+
+    ::testing::run_test(false, |cm, handler| {
+        let src = cm.new_source_file(
+            FileName::Real("input.js".into()),
+            "var  foo = 1;\nbar( 1,2 );".into(),
+        );
+        let mut module = Parser::new(
+            Session { handler: &handler },
+            Syntax::default(),
+            SourceFileInput::from(&*src),
+            None,
+        )
+        .parse_module()
+        .map_err(|mut e| {
+            e.emit();
+            ()
+        })?;
+
+        match module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(ref mut var))) => match var.decls[0].name {
+                Pat::Ident(ref mut i) => i.sym = "foo1".into(),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+
+        let out = Builder {
+            cfg,
+            cm: cm.clone(),
+            comments: Default::default(),
+        }
+        .text("", |e| e.emit_module(&module).unwrap());
+        assert_eq!(
+            DebugUsingDisplay(out.trim()),
+            DebugUsingDisplay("var foo1 = 1;\nbar( 1,2 );")
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn preserve_unchanged_tokens() {
+    let cfg = Config {
+        preserve_unchanged: true,
+        ..Default::default()
+    };
+
+    assert_with_config("a  -  -b;", cfg, "a  -  -b;");
+    assert_with_config("x = 'a  b' + /c  d/g;", cfg, "x = 'a  b' + /c  d/g;");
+
+    // Fields without spans.
+    let out = parse_fold_then_emit("let  a = [ 1 ];", cfg, Syntax::default(), |mut m| {
+        match m.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(ref mut var))) => var.kind = VarDeclKind::Var,
+            _ => unreachable!(),
+        }
+        m
+    });
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay("var a = [ 1 ];")
+    );
+
+    // Removed nodes.
+    let out = parse_fold_then_emit("foo( 1,  2 );", cfg, Syntax::default(), |mut m| {
+        match m.body[0] {
+            ModuleItem::Stmt(Stmt::Expr(ref mut e)) => match **e {
+                Expr::Call(ref mut call) => {
+                    call.args.pop();
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+        m
+    });
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay("foo(1);"));
+}
+
 #[derive(Debug, Clone)]
 struct Buf(Arc<RwLock<Vec<u8>>>);
 impl Write for Buf {
@@ -184,6 +685,21 @@ impl Write for Buf {
     }
 }
 
+/// Drops the source text of literals used as statements.
+fn drop_raw(mut m: Module) -> Module {
+    for item in &mut m.body {
+        if let ModuleItem::Stmt(Stmt::Expr(ref mut e)) = *item {
+            match **e {
+                Expr::Lit(Lit::Str(ref mut s)) => s.raw = None,
+                Expr::Lit(Lit::Num(ref mut n)) => n.raw = None,
+                _ => {}
+            }
+        }
+    }
+
+    m
+}
+
 #[derive(PartialEq, Eq)]
 struct DebugUsingDisplay<'a>(&'a str);
 
@@ -1,11 +1,15 @@
 use super::{Result, WriteJs};
+use crate::config::{Config, Semicolons};
 use sourcemap::SourceMapBuilder;
 use std::{
-    io::{self, Write},
+    fmt::{self, Write},
+    io::{self, ErrorKind},
     sync::Arc,
 };
 use swc_common::{FileName, SourceMap, Span};
 
+/// Writes code to a `fmt::Write`, like `String`. Use [IoWriter] to write to
+/// an `io::Write`.
 ///
 /// -----
 ///
@@ -15,6 +19,7 @@ use swc_common::{FileName, SourceMap, Span};
 pub struct JsWriter<'a, W: Write> {
     cm: Arc<SourceMap>,
     indent: usize,
+    indent_str: String,
     line_start: bool,
     line_count: usize,
     line_pos: usize,
@@ -22,6 +27,11 @@ pub struct JsWriter<'a, W: Write> {
     srcmap: Option<&'a mut SourceMapBuilder>,
     wr: W,
     written_bytes: usize,
+    omit_semi: bool,
+    /// A semicolon which is written only if required by the next token.
+    pending_semi: bool,
+    /// A line terminator which is delayed because of `pending_semi`.
+    pending_line: bool,
 }
 
 impl<'a, W: Write> JsWriter<'a, W> {
@@ -34,6 +44,7 @@ impl<'a, W: Write> JsWriter<'a, W> {
         JsWriter {
             cm,
             indent: Default::default(),
+            indent_str: "    ".into(),
             line_start: true,
             line_count: 0,
             line_pos: Default::default(),
@@ -41,32 +52,90 @@ impl<'a, W: Write> JsWriter<'a, W> {
             srcmap,
             wr,
             written_bytes: 0,
+            omit_semi: false,
+            pending_semi: false,
+            pending_line: false,
         }
     }
 
-    fn write_indent_string(&mut self) -> io::Result<usize> {
-        const INDENT: &[u8] = b"    ";
+    /// Applies indentation and semicolon options of `cfg`.
+    pub fn with_config(mut self, cfg: &Config) -> Self {
+        self.indent_str = cfg.indent_str();
+        self.omit_semi = cfg.semicolons == Semicolons::AsNeeded;
+        self
+    }
 
-        let mut cnt = 0;
+    fn write_indent_string(&mut self) -> io::Result<usize> {
         for _ in 0..self.indent {
-            cnt += self.raw_write(INDENT)?;
+            write_str(&mut self.wr, &self.indent_str)?;
         }
-
-        Ok(cnt)
-    }
-
-    fn raw_write(&mut self, data: &[u8]) -> io::Result<usize> {
-        let written = self.wr.write(data)?;
+        let written = self.indent * self.indent_str.len();
         self.written_bytes += written;
         self.line_pos += written;
         Ok(written)
     }
 
+    fn raw_write_line(&mut self) -> io::Result<()> {
+        if !self.line_start {
+            self.raw_write(self.new_line)?;
+            self.line_count += 1;
+            self.line_pos = 0;
+            self.line_start = true;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the pending semicolon and line terminator, if any.
+    ///
+    /// The semicolon is dropped if a line terminator follows it, unless
+    /// `next` starts with a token which would continue the statement.
+    fn commit_pending_semi(&mut self, next: &str) -> io::Result<()> {
+        if !self.pending_semi {
+            return Ok(());
+        }
+        self.pending_semi = false;
+
+        if self.pending_line {
+            self.pending_line = false;
+
+            let continues = match next.chars().next() {
+                Some(c) => "([`+-/*<".contains(c),
+                None => false,
+            };
+            if continues {
+                self.write(None, ";")?;
+            }
+            self.raw_write_line()
+        } else {
+            self.write(None, ";")?;
+            Ok(())
+        }
+    }
+
+    fn raw_write(&mut self, data: &str) -> io::Result<usize> {
+        write_str(&mut self.wr, data)?;
+        self.written_bytes += data.len();
+        self.line_pos += data.len();
+        Ok(data.len())
+    }
+
     fn write(&mut self, span: Option<Span>, data: &str) -> io::Result<usize> {
+        self.write_with_name(span, data, None)
+    }
+
+    /// `name` is recorded as the original name of the mapping at the start
+    /// of `data`.
+    fn write_with_name(
+        &mut self,
+        span: Option<Span>,
+        data: &str,
+        name: Option<&str>,
+    ) -> io::Result<usize> {
         let mut cnt = 0;
 
         macro_rules! srcmap {
-            ($byte_pos:expr) => {{
+            ($byte_pos:expr, $name:expr) => {{
                 match self.srcmap {
                     Some(ref mut srcmap) => {
                         let loc = self.cm.lookup_char_pos($byte_pos);
@@ -81,7 +150,7 @@ impl<'a, W: Write> JsWriter<'a, W> {
                             (loc.line - 1) as _,
                             loc.col.0 as _,
                             src.as_ref().map(|s| &**s),
-                            None,
+                            $name,
                         );
                     }
                     _ => {}
@@ -90,9 +159,11 @@ impl<'a, W: Write> JsWriter<'a, W> {
         }
 
         if data.len() > 0 {
+            self.commit_pending_semi(data)?;
+
             if let Some(span) = span {
                 if !span.is_dummy() {
-                    srcmap!(span.lo())
+                    srcmap!(span.lo(), name)
                 }
             }
 
@@ -100,11 +171,17 @@ impl<'a, W: Write> JsWriter<'a, W> {
                 cnt += self.write_indent_string()?;
                 self.line_start = false;
             }
-            cnt += self.raw_write(data.as_bytes())?;
+            cnt += self.raw_write(data)?;
+
+            // `data` may span multiple lines, e.g. template literals.
+            if let Some((lines, last_line_start)) = count_lines(data) {
+                self.line_count += lines;
+                self.line_pos = data.len() - last_line_start;
+            }
 
             if let Some(span) = span {
                 if !span.is_dummy() {
-                    srcmap!(span.hi())
+                    srcmap!(span.hi(), None)
                 }
             }
         }
@@ -114,6 +191,14 @@ impl<'a, W: Write> JsWriter<'a, W> {
 }
 
 impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
+    fn column(&self) -> usize {
+        self.line_pos
+    }
+
+    fn line(&self) -> usize {
+        self.line_count
+    }
+
     fn increase_indent(&mut self) -> Result {
         self.indent += 1;
         Ok(())
@@ -124,7 +209,12 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_semi(&mut self) -> Result {
-        self.write(None, ";")?;
+        if self.omit_semi {
+            self.commit_pending_semi(";")?;
+            self.pending_semi = true;
+        } else {
+            self.write(None, ";")?;
+        }
         Ok(())
     }
     fn write_space(&mut self) -> Result {
@@ -153,28 +243,15 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_line(&mut self) -> Result {
-        if !self.line_start {
-            self.raw_write(self.new_line.as_bytes())?;
-            self.line_count += 1;
-            self.line_pos = 0;
-            self.line_start = true;
+        if self.pending_semi {
+            self.pending_line = true;
+            return Ok(());
         }
-
-        Ok(())
+        self.raw_write_line()
     }
 
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
-        if !s.is_empty() {
-            self.write(Some(span), s)?;
-
-            let line_start_of_s = compute_line_starts(s);
-            if line_start_of_s.len() > 1 {
-                self.line_count = self.line_count + line_start_of_s.len() - 1;
-                self.line_pos =
-                    self.written_bytes - s.len() + line_start_of_s.last().cloned().unwrap_or(0);
-            }
-        }
-
+        self.write(Some(span), s)?;
         Ok(())
     }
 
@@ -189,7 +266,17 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_symbol(&mut self, span: Span, s: &str) -> Result {
-        self.write(Some(span), s)?;
+        // Renamed identifiers are mapped to their name in the source code.
+        let name = if self.srcmap.is_some() && !span.is_dummy() {
+            self.cm
+                .span_to_snippet(span)
+                .ok()
+                .filter(|name| is_ident_name(name))
+        } else {
+            None
+        };
+
+        self.write_with_name(Some(span), s, Some(name.as_ref().map(|s| &**s).unwrap_or(s)))?;
         Ok(())
     }
 
@@ -199,10 +286,58 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 }
 
-fn compute_line_starts(s: &str) -> Vec<usize> {
-    let mut res = vec![];
+fn is_ident_name(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' || c == '$' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
 
-    let mut line_start = 0;
+/// Adapts an `io::Write`, like `File` or `Vec<u8>`, to `fmt::Write` for
+/// [JsWriter].
+pub struct IoWriter<W: io::Write> {
+    wr: W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> IoWriter<W> {
+    pub fn new(wr: W) -> Self {
+        IoWriter { wr, error: None }
+    }
+
+    /// Returns the underlying writer, or the first error returned by it.
+    pub fn into_inner(self) -> io::Result<W> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.wr),
+        }
+    }
+}
+
+impl<W: io::Write> Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.error.is_some() {
+            return Err(fmt::Error);
+        }
+
+        self.wr.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+fn write_str<W: Write>(wr: &mut W, s: &str) -> io::Result<()> {
+    wr.write_str(s).map_err(|_| io::Error::new(ErrorKind::Other, "failed to write code"))
+}
+
+/// Returns the number of line terminators in `s` and the byte offset of the
+/// start of the last line, or `None` if `s` is a single line.
+fn count_lines(s: &str) -> Option<(usize, usize)> {
+    let mut lines = 0;
+    let mut last_line_start = 0;
 
     let mut chars = s.char_indices().peekable();
 
@@ -211,26 +346,21 @@ fn compute_line_starts(s: &str) -> Vec<usize> {
             '\r' => {
                 if let Some(&(_, '\n')) = chars.peek() {
                     let _ = chars.next();
+                    last_line_start = pos + 2;
+                } else {
+                    last_line_start = pos + 1;
                 }
             }
-
-            '\n' => {
-                res.push(line_start);
-                line_start = pos;
-                break;
-            }
-
-            _ => {
-                // if c > MAX_ASCII_CHAR && is_line_break(c) {
-                //     res.push(line_start);
-                //     line_start = pos;
-                // }
-                unimplemented!("compute_line_starts(char = {:?})", c)
-            }
+            '\n' => last_line_start = pos + 1,
+            '\u{2028}' | '\u{2029}' => last_line_start = pos + c.len_utf8(),
+            _ => continue,
         }
+        lines += 1;
     }
 
-    // Last line.
-    res.push(line_start);
-    res
+    if lines == 0 {
+        None
+    } else {
+        Some((lines, last_line_start))
+    }
 }
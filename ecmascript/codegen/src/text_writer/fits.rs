@@ -0,0 +1,128 @@
+use super::{Result, WriteJs};
+use std::io::{self, ErrorKind};
+use swc_common::Span;
+
+/// Checks if code fits in a line, without storing it.
+///
+/// Writing stops with an error as soon as the answer is known, so measuring a
+/// list costs at most `width` bytes regardless of the size of the list.
+pub(crate) struct Fits {
+    column: usize,
+    width: usize,
+    fits: Option<bool>,
+}
+
+impl Fits {
+    pub fn new(column: usize, width: usize) -> Self {
+        Fits {
+            column,
+            width,
+            fits: None,
+        }
+    }
+
+    /// Returns the answer for the result of writing code.
+    pub fn result(&self, res: Result) -> io::Result<bool> {
+        match self.fits {
+            Some(fits) => Ok(fits),
+            None => res.map(|()| self.column <= self.width),
+        }
+    }
+
+    fn stop(&mut self, fits: bool) -> Result {
+        if self.fits.is_none() {
+            self.fits = Some(fits);
+        }
+        Err(io::Error::new(ErrorKind::Other, "measured"))
+    }
+
+    fn write(&mut self, s: &str) -> Result {
+        if let Some(fits) = self.fits {
+            return self.stop(fits);
+        }
+        match s.find('\n') {
+            Some(i) => {
+                self.column += i;
+                let fits = self.column <= self.width;
+                self.stop(fits)
+            }
+            None => {
+                self.column += s.len();
+                if self.column > self.width {
+                    return self.stop(false);
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl WriteJs for Fits {
+    fn column(&self) -> usize {
+        self.column
+    }
+
+    fn line(&self) -> usize {
+        0
+    }
+
+    fn increase_indent(&mut self) -> Result {
+        Ok(())
+    }
+
+    fn decrease_indent(&mut self) -> Result {
+        Ok(())
+    }
+
+    fn write_semi(&mut self) -> Result {
+        self.write(";")
+    }
+
+    fn write_space(&mut self) -> Result {
+        self.write(" ")
+    }
+
+    fn write_keyword(&mut self, _: Option<Span>, s: &'static str) -> Result {
+        self.write(s)
+    }
+
+    fn write_operator(&mut self, s: &str) -> Result {
+        self.write(s)
+    }
+
+    fn write_param(&mut self, s: &str) -> Result {
+        self.write(s)
+    }
+
+    fn write_property(&mut self, s: &str) -> Result {
+        self.write(s)
+    }
+
+    fn write_line(&mut self) -> Result {
+        self.write("\n")
+    }
+
+    fn write_lit(&mut self, _: Span, s: &str) -> Result {
+        self.write(s)
+    }
+
+    fn write_comment(&mut self, _: Span, s: &str) -> Result {
+        // Code after a line comment would be commented out.
+        if s == "//" {
+            return self.stop(false);
+        }
+        self.write(s)
+    }
+
+    fn write_str_lit(&mut self, _: Span, s: &str) -> Result {
+        self.write(s)
+    }
+
+    fn write_symbol(&mut self, _: Span, s: &str) -> Result {
+        self.write(s)
+    }
+
+    fn write_punct(&mut self, s: &'static str) -> Result {
+        self.write(s)
+    }
+}
@@ -1,8 +1,13 @@
-pub use self::{basic_impl::JsWriter, semicolon::omit_trailing_semi};
+pub(crate) use self::fits::Fits;
+pub use self::{
+    basic_impl::{IoWriter, JsWriter},
+    semicolon::omit_trailing_semi,
+};
 use super::*;
 use swc_common::Span;
 
 mod basic_impl;
+mod fits;
 mod semicolon;
 
 /// TODO
@@ -12,6 +17,12 @@ pub type Symbol = Str;
 ///
 /// Ported from `EmitWriteJs`.
 pub trait WriteJs {
+    /// Returns the number of bytes written to the current line.
+    fn column(&self) -> usize;
+
+    /// Returns the number of line terminators written.
+    fn line(&self) -> usize;
+
     fn increase_indent(&mut self) -> Result;
     fn decrease_indent(&mut self) -> Result;
 
@@ -40,6 +51,14 @@ impl<W> WriteJs for Box<W>
 where
     W: ?Sized + WriteJs,
 {
+    fn column(&self) -> usize {
+        (**self).column()
+    }
+
+    fn line(&self) -> usize {
+        (**self).line()
+    }
+
     fn increase_indent(&mut self) -> Result {
         (**self).increase_indent()
     }
@@ -34,6 +34,14 @@ macro_rules! with_semi {
 }
 
 impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
+    fn column(&self) -> usize {
+        self.inner.column()
+    }
+
+    fn line(&self) -> usize {
+        self.inner.line()
+    }
+
     with_semi!(increase_indent());
     with_semi!(decrease_indent());
 
@@ -1,5 +1,5 @@
 use super::list::ListFormat;
-use std::sync::Arc;
+use std::{borrow::Cow, fmt::Write, sync::Arc};
 use swc_common::{
     errors::SourceMapper, BytePos, SourceMap, SourceMapperDyn, Span, Spanned, SyntaxContext,
 };
@@ -157,9 +157,11 @@ impl StartsWithAlphaNum for Expr {
             | Expr::This(_)
             | Expr::Yield(_)
             | Expr::New(_)
-            | Expr::MetaProp(_) => true,
+            | Expr::MetaProp(_)
+            | Expr::Do(_)
+            | Expr::Throw(_) => true,
 
-            Expr::PrivateName(_) => false,
+            Expr::PrivateName(_) | Expr::ArgPlaceholder(_) => false,
 
             // Handle other literals.
             Expr::Lit(_) => false,
@@ -275,7 +277,9 @@ impl StartsWithAlphaNum for Stmt {
             | Stmt::For(..)
             | Stmt::ForIn(..)
             | Stmt::ForOf(..)
-            | Stmt::If(..) => true,
+            | Stmt::If(..)
+            // Source text is not known here
+            | Stmt::Lazy(..) => true,
             Stmt::Block(..) | Stmt::Empty(..) => false,
         }
     }
@@ -294,3 +298,179 @@ impl StartsWithAlphaNum for Decl {
         }
     }
 }
+
+/// Computes the value of a string literal from its source text.
+///
+/// Returns `None` if `raw` is not a quoted string or contains an escape which
+/// is not handled here (e.g. legacy octal escapes).
+pub fn unescape_str_lit(raw: &str) -> Option<String> {
+    let quote = raw.chars().next()?;
+    if (quote != '\'' && quote != '"') || raw.len() < 2 || !raw.ends_with(quote) {
+        return None;
+    }
+
+    fn read_hex<I: Iterator<Item = char>>(chars: &mut I, len: usize) -> Option<char> {
+        let mut v = 0;
+        for _ in 0..len {
+            v = v * 16 + chars.next()?.to_digit(16)?;
+        }
+        ::std::char::from_u32(v)
+    }
+
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw[1..raw.len() - 1].chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            'b' => out.push('\u{0008}'),
+            'v' => out.push('\u{000b}'),
+            'f' => out.push('\u{000c}'),
+            // Line continuations
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            'x' => out.push(read_hex(&mut chars, 2)?),
+            'u' => {
+                if chars.peek() == Some(&'{') {
+                    chars.next();
+                    let mut v: u32 = 0;
+                    loop {
+                        match chars.next()? {
+                            '}' => break,
+                            c => v = v.checked_mul(16)?.checked_add(c.to_digit(16)?)?,
+                        }
+                    }
+                    out.push(::std::char::from_u32(v)?);
+                } else {
+                    out.push(read_hex(&mut chars, 4)?);
+                }
+            }
+            '0' => match chars.peek() {
+                Some(c) if c.is_digit(10) => return None,
+                _ => out.push('\0'),
+            },
+            '1'..='9' => return None,
+            c => out.push(c),
+        }
+    }
+
+    Some(out)
+}
+
+/// Computes the value of a numeric literal from its source text.
+///
+/// Returns `None` if `raw` is not a numeric literal.
+pub fn parse_num_lit(raw: &str) -> Option<f64> {
+    fn parse_int(s: &str, radix: u32) -> Option<f64> {
+        if s.is_empty() {
+            return None;
+        }
+        let mut v = 0.0;
+        for c in s.chars() {
+            v = v * radix as f64 + c.to_digit(radix)? as f64;
+        }
+        Some(v)
+    }
+
+    let raw = if raw.contains('_') {
+        raw.replace('_', "")
+    } else {
+        raw.to_string()
+    };
+
+    match raw.get(..2) {
+        Some("0x") | Some("0X") => return parse_int(&raw[2..], 16),
+        Some("0o") | Some("0O") => return parse_int(&raw[2..], 8),
+        Some("0b") | Some("0B") => return parse_int(&raw[2..], 2),
+        _ => {}
+    }
+
+    if is_legacy_octal(&raw) {
+        return parse_int(&raw[1..], 8);
+    }
+
+    if !raw.starts_with(|c: char| c.is_digit(10) || c == '.') {
+        // `inf` and `NaN` are accepted by `str::parse`.
+        return None;
+    }
+    raw.parse().ok()
+}
+
+/// `010`
+pub fn is_legacy_octal(raw: &str) -> bool {
+    raw.len() > 1 && raw.starts_with('0') && raw.bytes().all(|b| b'0' <= b && b <= b'7')
+}
+
+/// Replaces non-ascii characters of `s` with unicode escapes.
+///
+/// Characters outside of the basic multilingual plane are written as
+/// surrogate pairs (`\uD83D\uDE00`), which works in string literals, template
+/// literals and regular expressions of all targets. Identifiers can't contain
+/// surrogate pairs, so `\u{1F600}` is used if `ident` is true.
+///
+/// `s` may be a raw source text. An escaped non-ascii character (`\é`) is
+/// the same as the character itself, except line continuations, which are
+/// removed.
+pub fn escape_non_ascii(s: &str, ident: bool) -> Cow<str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut out = String::with_capacity(s.len() + 16);
+    let mut escaped = false;
+    for c in s.chars() {
+        if c.is_ascii() {
+            escaped = c == '\\' && !escaped;
+            out.push(c);
+            continue;
+        }
+
+        if escaped {
+            escaped = false;
+            out.pop();
+            if c == '\u{2028}' || c == '\u{2029}' {
+                continue;
+            }
+        }
+
+        if ident && c as u32 > 0xffff {
+            write!(out, "\\u{{{:X}}}", c as u32).unwrap();
+        } else {
+            let mut buf = [0; 2];
+            for unit in c.encode_utf16(&mut buf) {
+                write!(out, "\\u{:04X}", unit).unwrap();
+            }
+        }
+    }
+
+    Cow::Owned(out)
+}
+
+/// Replaces non-ascii characters of `s` with html character references
+/// (`&#xE9;`), as unicode escapes are not allowed in jsx text.
+pub fn escape_non_ascii_jsx_text(s: &str) -> Cow<str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut out = String::with_capacity(s.len() + 16);
+    for c in s.chars() {
+        if c.is_ascii() {
+            out.push(c);
+        } else {
+            write!(out, "&#x{:X};", c as u32).unwrap();
+        }
+    }
+
+    Cow::Owned(out)
+}
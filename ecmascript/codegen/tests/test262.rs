@@ -11,11 +11,11 @@ extern crate testing;
 use std::{
     env,
     fs::{read_dir, File},
-    io::{self, Read, Write},
+    io::{self, Read},
     path::Path,
-    sync::{Arc, RwLock},
 };
-use swc_common::comments::Comments;
+use swc_common::{comments::Comments, Fold, FoldWith};
+use swc_ecma_ast::*;
 use swc_ecma_codegen::Emitter;
 use swc_ecma_parser::{lexer::Lexer, Parser, Session, SourceFileInput, Syntax};
 use test::{
@@ -90,10 +90,6 @@ fn add_test<F: FnOnce() + Send + 'static>(
     });
 }
 
-struct MyHandlers;
-
-impl swc_ecma_codegen::Handlers for MyHandlers {}
-
 fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
     let ref_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
         .join("tests")
@@ -136,8 +132,6 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                 "\n\n========== Running codegen test {}\nSource:\n{}\n",
                 file_name, input
             );
-            let mut wr = Buf(Arc::new(RwLock::new(vec![])));
-
             ::testing::run_test(false, |cm, handler| {
                 let src = cm.load_file(&entry.path()).expect("failed to load file");
                 eprintln!(
@@ -148,8 +142,8 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                     src.count_lines()
                 );
 
+                let mut wr = String::new();
                 let comments = Comments::default();
-                let handlers = box MyHandlers;
                 let mut parser: Parser<Lexer<SourceFileInput>> = Parser::new(
                     Session { handler: &handler },
                     Syntax::default(),
@@ -161,39 +155,49 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                     let mut emitter = Emitter {
                         cfg: Default::default(),
                         cm: cm.clone(),
-                        wr: box swc_ecma_codegen::text_writer::JsWriter::new(
+                        wr: swc_ecma_codegen::text_writer::JsWriter::new(
                             cm.clone(),
                             "\n",
                             &mut wr,
                             None,
                         ),
                         comments: Some(&comments),
-                        handlers,
+                        handlers: None,
                         pos_of_leading_comments: Default::default(),
+                        in_ambient: false,
+                        unchanged: Default::default(),
                     };
 
                     // Parse source
                     if module {
                         emitter
-                            .emit_module(&parser.parse_module().map_err(|mut e| {
-                                e.emit();
-                                ()
-                            })?)
+                            .emit_module(
+                                &parser
+                                    .parse_module()
+                                    .map_err(|mut e| {
+                                        e.emit();
+                                        ()
+                                    })?
+                                    .fold_with(&mut DropRaw),
+                            )
                             .unwrap();
                     } else {
                         emitter
-                            .emit_script(&parser.parse_script().map_err(|mut e| {
-                                e.emit();
-                                ()
-                            })?)
+                            .emit_script(
+                                &parser
+                                    .parse_script()
+                                    .map_err(|mut e| {
+                                        e.emit();
+                                        ()
+                                    })?
+                                    .fold_with(&mut DropRaw),
+                            )
                             .unwrap();
                     }
                 }
                 let ref_file = format!("{}", ref_dir.join(&file_name).display());
 
-                let code_output = wr.0.read().unwrap();
-                let with_srcmap =
-                    NormalizedOutput::from(String::from_utf8_lossy(&code_output).into_owned());
+                let with_srcmap = NormalizedOutput::from(wr);
                 with_srcmap.compare_to_file(ref_file).unwrap();
                 Ok(())
             })
@@ -204,6 +208,21 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
     Ok(())
 }
 
+/// References are printed from the value of literals, not from the source text.
+struct DropRaw;
+
+impl Fold<Str> for DropRaw {
+    fn fold(&mut self, s: Str) -> Str {
+        Str { raw: None, ..s }
+    }
+}
+
+impl Fold<Number> for DropRaw {
+    fn fold(&mut self, n: Number) -> Number {
+        Number { raw: None, ..n }
+    }
+}
+
 #[test]
 fn identity() {
     let args: Vec<_> = env::args().collect();
@@ -211,15 +230,3 @@ fn identity() {
     error_tests(&mut tests).expect("failed to load testss");
     test_main(&args, tests, Some(Options::new()));
 }
-
-#[derive(Debug, Clone)]
-struct Buf(Arc<RwLock<Vec<u8>>>);
-impl Write for Buf {
-    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
-        self.0.write().unwrap().write(data)
-    }
-
-    fn flush(&mut self) -> io::Result<()> {
-        self.0.write().unwrap().flush()
-    }
-}
@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 
 use self::SyntaxError::*;
-use crate::token::Token;
+use crate::{token::Token, EsVersion};
 use std::{
     borrow::Cow,
     fmt::{self, Debug, Formatter},
@@ -62,6 +62,8 @@ pub enum SyntaxError {
     UnterminatedRegxp,
     UnterminatedTpl,
     IdentAfterNum,
+    NumSepNotBetweenDigits,
+    NumSepAfterLeadingZero,
     UnexpectedChar {
         c: char,
     },
@@ -120,6 +122,8 @@ pub enum SyntaxError {
     AsyncGenerator,
     NonTopLevelImportExport,
     ImportExportInScript,
+    ImportMetaInScript,
+    InvalidImportMeta,
     PatVarWithoutInit,
     WithInStrict,
     ReturnNotAllowed,
@@ -139,6 +143,17 @@ pub enum SyntaxError {
     },
     InvalidLeadingDecorator,
     DecoratorOnExport,
+    DecoratorAfterExport,
+    DecoratorsBeforeAndAfterExport,
+    DecoratorOnPrivate,
+    PipelineBodyNoArrow,
+    ArgPlaceholderInNew,
+
+    JsonComment,
+    JsonWhitespace,
+    JsonInvalidStr,
+    JsonInvalidNum,
+    JsonTrailingComma,
 
     TsNonLastRest,
     TsRequiredAfterOptional,
@@ -159,6 +174,49 @@ pub enum SyntaxError {
     ExportNamespaceFrom,
 
     DotsWithoutIdentifier,
+
+    InvalidRegexFlag {
+        flag: char,
+    },
+    DuplicateRegexFlag {
+        flag: char,
+    },
+    RegexUnterminatedGroup,
+    RegexUnmatchedParen,
+    RegexInvalidGroup,
+    RegexInvalidGroupName,
+    RegexDuplicateGroupName {
+        name: JsWord,
+    },
+    RegexInvalidNamedReference,
+    RegexNothingToRepeat,
+    RegexLoneQuantifierBrackets,
+    RegexQuantifierOutOfOrder,
+    RegexUnterminatedCharClass,
+    RegexRangeOutOfOrder,
+    RegexInvalidCharClass,
+    RegexInvalidEscape,
+    RegexEscapeAtEnd,
+    RegexInvalidPropertyName,
+
+    /// Nesting depth exceeded the limit.
+    TooDeep,
+
+    SuperOutsideMethod,
+
+    FlowVarianceOnMethod,
+
+    TsDtsMissingDeclare,
+    TsStmtInAmbient,
+    TsInitializerInAmbient,
+    TsImplInAmbient,
+    TsExportAssignmentInAmbient,
+
+    /// Syntax is newer than `jsc.parser.target`.
+    RequiresEsVersion {
+        syntax: &'static str,
+        version: EsVersion,
+    },
 }
 
 impl<'a> From<ErrorToDiag<'a>> for Error {
@@ -198,6 +256,10 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             UnterminatedRegxp => "Unterminated regexp literal".into(),
             UnterminatedTpl => "Unterminated template".into(),
             IdentAfterNum => "Identifier cannot follow number".into(),
+            NumSepNotBetweenDigits => "Numeric separators are only allowed between digits".into(),
+            NumSepAfterLeadingZero => {
+                "Numeric separators are not allowed after a leading zero".into()
+            }
             UnexpectedChar { c } => format!("Unexpected character {:?}", c).into(),
             InvalidStrEscape => "Invalid string escape".into(),
             InvalidUnicodeEscape => "Invalid unciode escape".into(),
@@ -249,6 +311,8 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             ImportExportInScript => {
                 "'import', and 'export' cannot be used outside of module code".into()
             }
+            ImportMetaInScript => "'import.meta' cannot be used outside of module code".into(),
+            InvalidImportMeta => "The only valid meta property for import is 'import.meta'".into(),
 
             PatVarWithoutInit => "Destructuring bindings require initializers".into(),
             WithInStrict => "With statement are not allowed in strict mode".into(),
@@ -274,6 +338,27 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             DecoratorOnExport => "Using the export keyword between a decorator and a class is not \
                                   allowed. Please use `export @dec class` instead."
                 .into(),
+            DecoratorAfterExport => "Decorators must be placed *before* the 'export' keyword. You \
+                                     can set `decoratorsBeforeExport` to false to use the \
+                                     `export @decorator class {}` syntax."
+                .into(),
+            DecoratorsBeforeAndAfterExport => "Decorators can be placed *either* before or after \
+                                               the 'export' keyword, but not in both locations."
+                .into(),
+            DecoratorOnPrivate => "Decorators cannot be used to decorate private class members \
+                                   with legacy decorators."
+                .into(),
+            PipelineBodyNoArrow => "Unexpected arrow \"=>\" after pipeline body; arrow function \
+                                    in pipeline body must be parenthesized"
+                .into(),
+            ArgPlaceholderInNew => "Partial application is not allowed in new expressions".into(),
+            JsonComment => "Comments are not allowed in JSON".into(),
+            JsonWhitespace => {
+                "Only spaces, tabs and line breaks are allowed between JSON tokens".into()
+            }
+            JsonInvalidStr => "JSON strings must be double-quoted and use only JSON escapes".into(),
+            JsonInvalidNum => "Invalid JSON number".into(),
+            JsonTrailingComma => "Trailing commas are not allowed in JSON".into(),
             TsNonLastRest => "A rest element must be last in a tuple type.".into(),
             TsRequiredAfterOptional => {
                 "A required element cannot follow an optional element.".into()
@@ -306,6 +391,44 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             DotsWithoutIdentifier => {
                 "`...` must be followed by an identifier in declaration contexts".into()
             }
+            InvalidRegexFlag { flag } => {
+                format!("Invalid regular expression flag '{}'", flag).into()
+            }
+            DuplicateRegexFlag { flag } => {
+                format!("Duplicate regular expression flag '{}'", flag).into()
+            }
+            RegexUnterminatedGroup => "Unterminated group".into(),
+            RegexUnmatchedParen => "Unmatched ')'".into(),
+            RegexInvalidGroup => "Invalid group".into(),
+            RegexInvalidGroupName => "Invalid capture group name".into(),
+            RegexDuplicateGroupName { ref name } => {
+                format!("Duplicate capture group name '{}'", name).into()
+            }
+            RegexInvalidNamedReference => "Invalid named reference".into(),
+            RegexNothingToRepeat => "Nothing to repeat".into(),
+            RegexLoneQuantifierBrackets => "Lone quantifier brackets".into(),
+            RegexQuantifierOutOfOrder => "Numbers out of order in {} quantifier".into(),
+            RegexUnterminatedCharClass => "Unterminated character class".into(),
+            RegexRangeOutOfOrder => "Range out of order in character class".into(),
+            RegexInvalidCharClass => "Invalid character class".into(),
+            RegexInvalidEscape => "Invalid escape".into(),
+            RegexEscapeAtEnd => "\\ at end of pattern".into(),
+            RegexInvalidPropertyName => "Invalid property name".into(),
+            TooDeep => "Maximum nesting depth exceeded".into(),
+            SuperOutsideMethod => "'super' is only allowed in object methods and classes".into(),
+            FlowVarianceOnMethod => "Variance annotations are not allowed on methods".into(),
+            TsDtsMissingDeclare => "Top-level declarations in .d.ts files must start with either \
+                                    a 'declare' or 'export' modifier."
+                .into(),
+            TsStmtInAmbient => "Statements are not allowed in ambient contexts.".into(),
+            TsInitializerInAmbient => "Initializers are not allowed in ambient contexts.".into(),
+            TsImplInAmbient => "An implementation cannot be declared in ambient contexts.".into(),
+            TsExportAssignmentInAmbient => "The expression of an export assignment must be an \
+                                            identifier or qualified name in an ambient context."
+                .into(),
+            RequiresEsVersion { syntax, version } => {
+                format!("{} requires {}", syntax, version).into()
+            }
         };
 
         let mut db = e.handler.struct_err(&msg);
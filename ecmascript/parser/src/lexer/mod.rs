@@ -7,6 +7,7 @@
 pub use self::{
     input::Input,
     state::{TokenContext, TokenContexts},
+    trivia::{TokenOrTrivia, Trivia, TriviaKind, TriviaLexer},
 };
 use self::{state::State, util::*};
 use crate::{
@@ -30,6 +31,7 @@ mod number;
 mod state;
 #[cfg(test)]
 mod tests;
+mod trivia;
 pub mod util;
 
 pub(crate) type LexResult<T> = Result<T, Error>;
@@ -258,6 +260,12 @@ impl<'a, I: Input> Lexer<'a, I> {
                     })));
                 }
 
+                // '|>'
+                if c == '|' && self.syntax.pipeline_operator() && self.input.cur() == Some('>') {
+                    self.input.bump();
+                    return Ok(Some(tok!("|>")));
+                }
+
                 // '||', '&&'
                 if self.input.cur() == Some(c) {
                     self.input.bump();
@@ -295,6 +303,7 @@ impl<'a, I: Input> Lexer<'a, I> {
                         }
                         self.skip_line_comment(0);
                         self.skip_space()?;
+                        self.state.start = self.cur_pos();
                         return self.read_token();
                     }
 
@@ -549,6 +558,7 @@ impl<'a, I: Input> Lexer<'a, I> {
             if self.ctx.module {
                 self.error(start, SyntaxError::LegacyCommentInModule)?;
             }
+            self.state.start = self.cur_pos();
             return self.read_token();
         }
 
@@ -811,6 +821,7 @@ impl<'a, I: Input> Lexer<'a, I> {
             span: Span::new(flags_start, self.cur_pos(), Default::default()),
             value,
             has_escape,
+            raw: None,
         });
 
         Ok(Regex(
@@ -819,6 +830,7 @@ impl<'a, I: Input> Lexer<'a, I> {
                 value: content.into(),
                 // TODO
                 has_escape: false,
+                raw: None,
             },
             flags,
         ))
@@ -4,7 +4,7 @@
 //! See https://tc39.github.io/ecma262/#sec-literals-numeric-literals
 
 use super::*;
-use crate::error::SyntaxError;
+use crate::{error::SyntaxError, EsVersion};
 use std::fmt::Display;
 
 impl<'a, I: Input> Lexer<'a, I> {
@@ -29,6 +29,14 @@ impl<'a, I: Input> Lexer<'a, I> {
             // Use read_number_no_dot to support long numbers.
             let val = self.read_number_no_dot(10)?;
             if starts_with_zero {
+                // e.g. `0_1` and `08_1` are invalid.
+                if self.syntax.num_sep() {
+                    let end = self.cur_pos();
+                    if self.input.slice(start, end).contains('_') {
+                        self.error(start, SyntaxError::NumSepAfterLeadingZero)?
+                    }
+                }
+
                 // TODO: I guess it would be okay if I don't use -ffast-math
                 // (or something like that), but needs review.
 
@@ -139,12 +147,23 @@ impl<'a, I: Input> Lexer<'a, I> {
         );
         debug_assert_eq!(self.cur(), Some('0'));
 
-        let start = self.bump(); // 0
+        let start = self.cur_pos();
+        self.bump(); // 0
         self.bump(); // x
 
         let val = self.read_number_no_dot(radix)?;
         self.ensure_not_ident()?;
 
+        if radix != 16 && self.syntax.target() < EsVersion::Es2015 {
+            self.error(
+                start,
+                SyntaxError::RequiresEsVersion {
+                    syntax: "binary and octal literal",
+                    version: EsVersion::Es2015,
+                },
+            )?
+        }
+
         Ok(val)
     }
 
@@ -157,6 +176,7 @@ impl<'a, I: Input> Lexer<'a, I> {
             radix
         );
         let start = self.cur_pos();
+        let allow_num_sep = self.syntax.num_sep();
 
         let mut read_any = false;
 
@@ -167,6 +187,7 @@ impl<'a, I: Input> Lexer<'a, I> {
                 (f64::mul_add(total, radix as f64, v as f64), true)
             },
             &mut Raw(None),
+            allow_num_sep,
         );
 
         if !read_any {
@@ -191,6 +212,7 @@ impl<'a, I: Input> Lexer<'a, I> {
     /// When `len` is not zero, this
     /// will return `None` unless the integer has exactly `len` digits.
     pub(super) fn read_int(&mut self, radix: u8, len: u8, raw: &mut Raw) -> LexResult<Option<f64>> {
+        let allow_num_sep = self.syntax.num_sep();
         let mut count = 0;
         let v = self.read_digits(
             radix,
@@ -200,6 +222,7 @@ impl<'a, I: Input> Lexer<'a, I> {
                 (Some(total), count != len)
             },
             raw,
+            allow_num_sep,
         )?;
         if len != 0 && count != len {
             Ok(None)
@@ -223,6 +246,7 @@ impl<'a, I: Input> Lexer<'a, I> {
                 (Some(total), count != len)
             },
             raw,
+            false,
         )?;
         if len != 0 && count != len {
             Ok(None)
@@ -232,7 +256,16 @@ impl<'a, I: Input> Lexer<'a, I> {
     }
 
     /// `op`- |total, radix, value| -> (total * radix + value, continue)
-    fn read_digits<F, Ret>(&mut self, radix: u8, mut op: F, raw: &mut Raw) -> LexResult<Ret>
+    ///
+    /// `allow_num_sep` should be false for escapes, which cannot contain
+    /// numeric separators.
+    fn read_digits<F, Ret>(
+        &mut self,
+        radix: u8,
+        mut op: F,
+        raw: &mut Raw,
+        allow_num_sep: bool,
+    ) -> LexResult<Ret>
     where
         F: FnMut(Ret, u8, u32) -> (Ret, bool),
         Ret: Copy + Default,
@@ -247,27 +280,20 @@ impl<'a, I: Input> Lexer<'a, I> {
         let start = self.cur_pos();
 
         let mut total: Ret = Default::default();
+        let mut prev = None;
 
         while let Some(c) = self.cur() {
-            if self.syntax.num_sep() {
-                // let prev: char = unimplemented!("prev");
-                // let next = self.input.peek();
-
-                // if c == '_' {
-                //     if !allowed_siblings.contains(&next) {
-                //         unimplemented!("Error(Invalid or unexpected token)");
-                //     }
-
-                // if forbidden_siblings.contains(&prev) ||
-                // forbidden_siblings.contains(&next) ||
-                // Number::is_nan(next)     {
-                //         unimplemented!("Error(Invalid or unexpected token)");
-                //     }
-
-                //     // Ignore this _ character
-                //     self.input.bump();
-                // }
-                unimplemented!("numeric separator")
+            if allow_num_sep && c == '_' {
+                let is_digit = |c: Option<char>| c.map_or(false, |c| c.is_digit(radix as _));
+                if !is_digit(prev) || !is_digit(self.peek()) {
+                    let span = pos_span(self.cur_pos());
+                    self.error_span(span, SyntaxError::NumSepNotBetweenDigits)?
+                }
+
+                // Ignore this _ character
+                self.bump();
+                prev = Some(c);
+                continue;
             }
 
             // e.g. (val for a) = 10  where radix = 16
@@ -280,6 +306,7 @@ impl<'a, I: Input> Lexer<'a, I> {
             raw.push(c);
 
             self.bump();
+            prev = Some(c);
             let (t, cont) = op(total, radix, val);
             total = t;
             if !cont {
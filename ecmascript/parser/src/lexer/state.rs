@@ -4,7 +4,8 @@ use enum_kind::Kind;
 use input::Tokens;
 use smallvec::SmallVec;
 use std::mem;
-use swc_common::BytePos;
+use swc_atoms::JsWord;
+use swc_common::{BytePos, Span};
 use Context;
 
 /// State of lexer.
@@ -113,6 +114,11 @@ impl<I: Input> Tokens for Lexer<'_, I> {
     fn set_token_context(&mut self, c: TokenContexts) {
         self.state.context = c;
     }
+
+    fn raw(&mut self, span: Span) -> Option<JsWord> {
+        let mut input = self.input.clone();
+        Some(input.slice(span.lo(), span.hi()).into())
+    }
 }
 
 impl<'a, I: Input> Iterator for Lexer<'a, I> {
@@ -154,7 +160,7 @@ impl<'a, I: Input> Iterator for Lexer<'a, I> {
 
             self.state.start = start;
 
-            if self.syntax.typescript() && self.ctx.in_type {
+            if self.syntax.types() && self.ctx.in_type {
                 if c == '<' {
                     self.input.bump();
                     return Ok(Some(tok!('<')));
@@ -213,6 +219,11 @@ impl<'a, I: Input> Iterator for Lexer<'a, I> {
             Err(e) => e,
         };
 
+        // Legacy html comments are skipped while reading a token.
+        if self.state.start > start {
+            start = self.state.start;
+        }
+
         let span = self.span(start);
         if let Some(ref token) = token {
             if self.leading_comments_buffer.is_some()
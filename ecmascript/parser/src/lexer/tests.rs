@@ -325,6 +325,7 @@ fn regexp_unary_void() {
                     value: "test".into(),
                     span: sp(6..10),
                     has_escape: false,
+                    raw: None,
                 },
                 None,
             )
@@ -341,6 +342,7 @@ fn regexp_unary_void() {
                     span: sp(7..11),
                     value: "test".into(),
                     has_escape: false,
+                    raw: None,
                 },
                 None,
             )
@@ -408,11 +410,13 @@ fn simple_regex() {
                     span: sp(5..7),
                     value: "42".into(),
                     has_escape: false,
+                    raw: None,
                 },
                 Some(Str {
                     span: sp(8..9),
                     value: "i".into(),
                     has_escape: false,
+                    raw: None,
                 }),
             )
             .span(4..9),
@@ -426,6 +430,7 @@ fn simple_regex() {
                 span: sp(1..3),
                 value: "42".into(),
                 has_escape: false,
+                raw: None,
             },
             None,
         )
@@ -454,11 +459,13 @@ fn complex_regex() {
                     span: Default::default(),
                     value: "42".into(),
                     has_escape: false,
+                    raw: None,
                 },
                 Some(Str {
                     span: Default::default(),
                     value: "i".into(),
                     has_escape: false,
+                    raw: None,
                 }),
             ),
         ]
@@ -552,6 +559,7 @@ fn after_if() {
                     span: sp(9..10),
                     value: "y".into(),
                     has_escape: false,
+                    raw: None,
                 },
                 None,
             )
@@ -604,6 +612,7 @@ fn migrated_0002() {
                     span: sp(10..12),
                     value: "42".into(),
                     has_escape: false,
+                    raw: None,
                 },
                 None,
             )
@@ -644,6 +653,7 @@ fn migrated_0004() {
                     span: sp(16..18),
                     value: "42".into(),
                     has_escape: false,
+                    raw: None,
                 },
                 None,
             )
@@ -688,6 +698,7 @@ fn migrated_0006() {
                     span: sp(4..6),
                     value: "42".into(),
                     has_escape: false,
+                    raw: None,
                 },
                 None,
             )
@@ -1085,6 +1096,142 @@ fn issue_401() {
     );
 }
 
+fn num_sep() -> Syntax {
+    Syntax::Es(::EsConfig {
+        num_sep: true,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn num_sep_valid() {
+    assert_eq!(lex_tokens(num_sep(), "1_000_000"), vec![Num(1_000_000.0)]);
+    assert_eq!(lex_tokens(num_sep(), "1_0.2_5e1_0"), vec![Num(10.25e10)]);
+    assert_eq!(lex_tokens(num_sep(), ".2_5"), vec![Num(0.25)]);
+    assert_eq!(lex_tokens(num_sep(), "0xF_F"), vec![Num(255.0)]);
+    assert_eq!(lex_tokens(num_sep(), "0b1_0"), vec![Num(2.0)]);
+    assert_eq!(lex_tokens(num_sep(), "0o1_0"), vec![Num(8.0)]);
+}
+
+fn num_sep_error(s: &'static str) -> SyntaxError {
+    match lex_tokens(num_sep(), s).into_iter().next() {
+        Some(Token::Error(Error { error, .. })) => error,
+        t => panic!("expected an error for {}, got {:?}", s, t),
+    }
+}
+
+#[test]
+fn num_sep_invalid() {
+    for s in &["1__0", "1_", "1_.0", "1._0", "1_e1", "1e_1", "1e+_1", "0x_1", "0b1_"] {
+        assert_eq!(num_sep_error(s), SyntaxError::NumSepNotBetweenDigits, "{}", s);
+    }
+    for s in &["0_1", "00_1", "08_1"] {
+        assert_eq!(num_sep_error(s), SyntaxError::NumSepAfterLeadingZero, "{}", s);
+    }
+}
+
+#[test]
+fn num_sep_not_in_escapes() {
+    assert!(lex_tokens(num_sep(), r"'\u{1_0}'").into_iter().any(|t| match t {
+        Token::Error(..) => true,
+        _ => false,
+    }));
+}
+
+#[test]
+fn num_sep_disabled() {
+    assert_eq!(
+        lex_tokens(Syntax::default(), "1_0")[0],
+        Token::Error(Error {
+            span: sp(1..1),
+            error: SyntaxError::IdentAfterNum,
+        })
+    );
+}
+
+fn lex_with_trivia(syntax: Syntax, s: &'static str) -> Vec<(TriviaKind, &'static str)> {
+    ::with_test_sess(s, |sess, fm| {
+        let items: Vec<_> = Lexer::new(sess, syntax, fm, None).with_trivia().collect();
+
+        let mut expected = items[0].span().lo();
+        let mut trivia = vec![];
+        let mut text = String::new();
+        for item in items {
+            let span = item.span();
+            assert_eq!(span.lo(), expected, "{:?} is not contiguous", item);
+            expected = span.hi();
+
+            let slice = &s[span.lo().0 as usize..span.hi().0 as usize];
+            text.push_str(slice);
+            if let TokenOrTrivia::Trivia(t) = item {
+                trivia.push((t.kind, slice));
+            }
+        }
+        assert_eq!(text, s);
+
+        Ok(trivia)
+    })
+    .unwrap()
+}
+
+#[test]
+fn trivia_round_trip() {
+    use self::TriviaKind::*;
+
+    assert_eq!(
+        lex_with_trivia(
+            Syntax::default(),
+            "  // a\r\nfoo /* b */ = 1;\n\t/** c\n */bar\u{2028}\n"
+        ),
+        vec![
+            (Whitespace, "  "),
+            (LineComment, "// a"),
+            (Newline, "\r\n"),
+            (Whitespace, " "),
+            (BlockComment, "/* b */"),
+            (Whitespace, " "),
+            (Whitespace, " "),
+            (Newline, "\n"),
+            (Whitespace, "\t"),
+            (BlockComment, "/** c\n */"),
+            (Newline, "\u{2028}"),
+            (Newline, "\n"),
+        ]
+    );
+}
+
+#[test]
+fn trivia_shebang_and_template() {
+    use self::TriviaKind::*;
+
+    assert_eq!(
+        lex_with_trivia(Syntax::default(), "#!/usr/bin/env node\n`a ${ b } c` // d"),
+        vec![
+            (Newline, "\n"),
+            (Whitespace, " "),
+            (Whitespace, " "),
+            (Whitespace, " "),
+            (LineComment, "// d"),
+        ]
+    );
+}
+
+#[test]
+fn trivia_html_comment() {
+    use self::TriviaKind::*;
+
+    assert_eq!(
+        lex_with_trivia(Syntax::default(), "a <!-- b\n--> c\nd"),
+        vec![
+            (Whitespace, " "),
+            (LineComment, "<!-- b"),
+            (Newline, "\n"),
+            (LineComment, "--> c"),
+            (Newline, "\n"),
+        ]
+    );
+}
+
 #[bench]
 fn lex_colors_js(b: &mut Bencher) {
     b.bytes = include_str!("../../colors.js").len() as _;
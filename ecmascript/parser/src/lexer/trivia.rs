@@ -0,0 +1,154 @@
+//! Token stream which preserves whitespaces and comments.
+//!
+//! Concatenating the source text of all items yielded by [TriviaLexer]
+//! reproduces the input.
+use super::{util::CharExt, Input, Lexer};
+use crate::token::TokenAndSpan;
+use std::collections::VecDeque;
+use swc_common::{BytePos, Span, SyntaxContext};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    /// Run of white spaces.
+    Whitespace,
+    /// A single line terminator. `\r\n` is one newline.
+    Newline,
+    /// `// foo`, `<!-- foo` or `--> foo`, without the line terminator.
+    LineComment,
+    /// `/* foo */`
+    BlockComment,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenOrTrivia {
+    Token(TokenAndSpan),
+    Trivia(Trivia),
+}
+
+impl TokenOrTrivia {
+    pub fn span(&self) -> Span {
+        match *self {
+            TokenOrTrivia::Token(ref t) => t.span,
+            TokenOrTrivia::Trivia(ref t) => t.span,
+        }
+    }
+}
+
+/// Wraps a [Lexer] and yields trivia between tokens.
+pub struct TriviaLexer<'a, I: Input> {
+    lexer: Lexer<'a, I>,
+    /// Used to read source text of gaps between tokens.
+    input: I,
+    last_hi: BytePos,
+    pending: VecDeque<TokenOrTrivia>,
+    done: bool,
+}
+
+impl<'a, I: Input> Lexer<'a, I> {
+    /// Creates a token stream which also yields whitespaces and comments.
+    pub fn with_trivia(mut self) -> TriviaLexer<'a, I> {
+        let last_hi = self.input.cur_pos();
+        TriviaLexer {
+            input: self.input.clone(),
+            lexer: self,
+            last_hi,
+            pending: Default::default(),
+            done: false,
+        }
+    }
+}
+
+impl<'a, I: Input> TriviaLexer<'a, I> {
+    pub fn into_inner(self) -> Lexer<'a, I> {
+        self.lexer
+    }
+
+    /// Splits `[self.last_hi, end)` into trivia.
+    fn push_gap(&mut self, end: BytePos) {
+        let start = self.last_hi;
+        if start >= end {
+            return;
+        }
+        self.last_hi = end;
+
+        let text = self.input.slice(start, end).to_string();
+        let mut pos = start;
+        let mut rest = &*text;
+
+        while let Some(c) = rest.chars().next() {
+            let (kind, len) = if rest.starts_with("\r\n") {
+                (TriviaKind::Newline, 2)
+            } else if c.is_line_break() {
+                (TriviaKind::Newline, c.len_utf8())
+            } else if rest.starts_with("/*") {
+                let len = rest[2..].find("*/").map(|i| i + 4).unwrap_or(rest.len());
+                (TriviaKind::BlockComment, len)
+            } else if rest.starts_with("//") || rest.starts_with("<!--") || rest.starts_with("-->")
+            {
+                let len = rest.find(|c: char| c.is_line_break()).unwrap_or(rest.len());
+                (TriviaKind::LineComment, len)
+            } else {
+                // Anything the lexer skipped which is not a comment is treated as a
+                // white space, so that the source text is always preserved.
+                let len = rest
+                    .find(|c: char| c.is_line_break() || c == '/' || c == '<' || c == '-')
+                    .unwrap_or(rest.len());
+                (TriviaKind::Whitespace, if len == 0 { c.len_utf8() } else { len })
+            };
+
+            let hi = pos + BytePos(len as u32);
+            // Merge adjacent white spaces.
+            let merged = match self.pending.back_mut() {
+                Some(&mut TokenOrTrivia::Trivia(ref mut last))
+                    if kind == TriviaKind::Whitespace && last.kind == TriviaKind::Whitespace =>
+                {
+                    last.span = last.span.with_hi(hi);
+                    true
+                }
+                _ => false,
+            };
+            if !merged {
+                self.pending.push_back(TokenOrTrivia::Trivia(Trivia {
+                    kind,
+                    span: Span::new(pos, hi, SyntaxContext::empty()),
+                }));
+            }
+            pos = hi;
+            rest = &rest[len..];
+        }
+    }
+}
+
+impl<'a, I: Input> Iterator for TriviaLexer<'a, I> {
+    type Item = TokenOrTrivia;
+
+    fn next(&mut self) -> Option<TokenOrTrivia> {
+        if let Some(item) = self.pending.pop_front() {
+            return Some(item);
+        }
+        if self.done {
+            return None;
+        }
+
+        match self.lexer.next() {
+            Some(token) => {
+                self.push_gap(token.span.lo());
+                self.last_hi = token.span.hi();
+                self.pending.push_back(TokenOrTrivia::Token(token));
+            }
+            None => {
+                self.done = true;
+                let end = self.lexer.input.cur_pos();
+                self.push_gap(end);
+            }
+        }
+
+        self.next()
+    }
+}
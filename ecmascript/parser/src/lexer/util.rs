@@ -81,7 +81,7 @@ impl<'a, I: Input> Lexer<'a, I> {
         self.input.peek_ahead()
     }
 
-    pub(super) fn cur_pos(&mut self) -> BytePos {
+    pub(crate) fn cur_pos(&mut self) -> BytePos {
         self.input.cur_pos()
     }
     pub(super) fn last_pos(&self) -> BytePos {
@@ -109,6 +109,7 @@ pub use self::{
     parser::*,
 };
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use swc_common::errors::Handler;
 
 #[macro_use]
@@ -126,6 +127,8 @@ pub enum Syntax {
     Es(EsConfig),
     #[serde(rename = "typescript")]
     Typescript(TsConfig),
+    #[serde(rename = "flow")]
+    Flow(FlowConfig),
 }
 
 impl Default for Syntax {
@@ -139,7 +142,8 @@ impl Syntax {
     pub fn jsx(self) -> bool {
         match self {
             Syntax::Es(EsConfig { jsx: true, .. })
-            | Syntax::Typescript(TsConfig { tsx: true, .. }) => true,
+            | Syntax::Typescript(TsConfig { tsx: true, .. })
+            | Syntax::Flow(FlowConfig { jsx: true, .. }) => true,
             _ => false,
         }
     }
@@ -153,6 +157,10 @@ impl Syntax {
             | Syntax::Typescript(TsConfig {
                 dynamic_import: true,
                 ..
+            })
+            | Syntax::Flow(FlowConfig {
+                dynamic_import: true,
+                ..
             }) => true,
             _ => false,
         }
@@ -165,6 +173,55 @@ impl Syntax {
         }
     }
 
+    pub fn pipeline_operator(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                pipeline_operator: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    pub fn pipeline_proposal(self) -> PipelineProposal {
+        match self {
+            Syntax::Es(EsConfig {
+                pipeline_proposal, ..
+            }) => pipeline_proposal,
+            _ => PipelineProposal::Minimal,
+        }
+    }
+
+    pub fn partial_application(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                partial_application: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    pub fn do_expressions(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                do_expressions: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    pub fn throw_expressions(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                throw_expressions: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
     pub fn num_sep(self) -> bool {
         match self {
             Syntax::Es(EsConfig { num_sep: true, .. }) => true,
@@ -205,7 +262,7 @@ impl Syntax {
     }
 
     pub fn class_props(self) -> bool {
-        if self.typescript() {
+        if self.typescript() || self.flow() {
             return true;
         }
         match self {
@@ -227,6 +284,15 @@ impl Syntax {
         }
     }
 
+    pub fn decorators_version(self) -> DecoratorsVersion {
+        match self {
+            Syntax::Es(EsConfig {
+                decorators_version, ..
+            }) => decorators_version,
+            _ => DecoratorsVersion::Legacy,
+        }
+    }
+
     /// Should we pare typescript?
     pub fn typescript(self) -> bool {
         match self {
@@ -235,6 +301,38 @@ impl Syntax {
         }
     }
 
+    /// Should we parse a typescript declaration file?
+    pub fn dts(self) -> bool {
+        match self {
+            Syntax::Typescript(TsConfig { dts: true, .. }) => true,
+            _ => false,
+        }
+    }
+
+    /// Should we parse flow?
+    pub fn flow(self) -> bool {
+        match self {
+            Syntax::Flow(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Should we parse type annotations?
+    ///
+    /// True for both typescript and flow.
+    pub fn types(self) -> bool {
+        self.typescript() || self.flow()
+    }
+
+    /// Syntax newer than this version is rejected.
+    pub fn target(self) -> EsVersion {
+        match self {
+            Syntax::Es(EsConfig { target, .. })
+            | Syntax::Typescript(TsConfig { target, .. })
+            | Syntax::Flow(FlowConfig { target, .. }) => target,
+        }
+    }
+
     pub fn export_default_from(self) -> bool {
         match self {
             Syntax::Es(EsConfig {
@@ -254,23 +352,145 @@ impl Syntax {
             _ => false,
         }
     }
+
+    pub fn allow_return_outside_function(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                allow_return_outside_function: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                allow_return_outside_function: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    pub fn allow_await_outside_function(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                allow_await_outside_function: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                allow_await_outside_function: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    pub fn allow_import_export_everywhere(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                allow_import_export_everywhere: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                allow_import_export_everywhere: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    pub fn allow_super_outside_method(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                allow_super_outside_method,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                allow_super_outside_method,
+                ..
+            }) => allow_super_outside_method,
+            _ => true,
+        }
+    }
 }
 
-#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Clone, Copy, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct TsConfig {
     #[serde(default)]
     pub tsx: bool,
 
+    /// Parse the input as a declaration file (`.d.ts`).
+    ///
+    /// Executable statements, initializers and implementations are rejected,
+    /// and top-level declarations must be `declare`d or exported.
+    #[serde(default)]
+    pub dts: bool,
+
     #[serde(default)]
     pub decorators: bool,
 
     #[serde(default)]
     pub dynamic_import: bool,
+
+    /// Syntax newer than this version is rejected.
+    #[serde(alias = "ecmaVersion")]
+    #[serde(default)]
+    pub target: EsVersion,
+
+    /// Allow `return` statements outside of functions.
+    #[serde(default)]
+    pub allow_return_outside_function: bool,
+
+    /// Parse `await` outside of functions as an await expression.
+    #[serde(default)]
+    pub allow_await_outside_function: bool,
+
+    /// Allow `import` and `export` in module item lists which are not at the
+    /// top level of a module.
+    ///
+    /// Module declarations nested in statements cannot be represented in the
+    /// ast, so they are still rejected.
+    #[serde(default)]
+    pub allow_import_export_everywhere: bool,
+
+    /// Allow `super` outside of methods and class properties.
+    ///
+    /// This is on by default because transforms emit `super` outside of
+    /// classes, and their output is parsed again.
+    #[serde(default = "default_allow_super_outside_method")]
+    pub allow_super_outside_method: bool,
+}
+
+impl Default for TsConfig {
+    fn default() -> Self {
+        TsConfig {
+            tsx: false,
+            dts: false,
+            decorators: false,
+            dynamic_import: false,
+            target: Default::default(),
+            allow_return_outside_function: false,
+            allow_await_outside_function: false,
+            allow_import_export_everywhere: false,
+            allow_super_outside_method: default_allow_super_outside_method(),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FlowConfig {
+    #[serde(default)]
+    pub jsx: bool,
+
+    #[serde(default)]
+    pub dynamic_import: bool,
+
+    /// Syntax newer than this version is rejected.
+    #[serde(alias = "ecmaVersion")]
+    #[serde(default)]
+    pub target: EsVersion,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct EsConfig {
     #[serde(default)]
     pub jsx: bool,
@@ -296,17 +516,45 @@ pub struct EsConfig {
     #[serde(default)]
     pub fn_bind: bool,
 
+    /// Support pipeline operator (`a |> f`).
+    #[serde(default)]
+    pub pipeline_operator: bool,
+
+    /// Version of the pipeline operator proposal.
+    ///
+    /// Effective only if `pipeline_operator` is true.
+    #[serde(default)]
+    pub pipeline_proposal: PipelineProposal,
+
+    /// Support partial application (`f(?, x)`).
+    #[serde(default)]
+    pub partial_application: bool,
+
+    /// Support do expressions (`do { ... }`).
+    #[serde(default)]
+    pub do_expressions: bool,
+
+    /// Support throw expressions (`x || throw new Error()`).
+    #[serde(default)]
+    pub throw_expressions: bool,
+
     /// Enable decorators.
     #[serde(default)]
     pub decorators: bool,
 
     /// babel: `decorators.decoratorsBeforeExport`
     ///
-    /// Effective only if `decorator` is true.
+    /// Effective only if `decorators` is true.
     #[serde(rename = "decoratorsBeforeExport")]
     #[serde(default)]
     pub decorators_before_export: bool,
 
+    /// Version of the decorators proposal.
+    ///
+    /// Effective only if `decorators` is true.
+    #[serde(default)]
+    pub decorators_version: DecoratorsVersion,
+
     #[serde(default)]
     pub export_default_from: bool,
 
@@ -315,6 +563,157 @@ pub struct EsConfig {
 
     #[serde(default)]
     pub dynamic_import: bool,
+
+    /// Syntax newer than this version is rejected.
+    #[serde(alias = "ecmaVersion")]
+    #[serde(default)]
+    pub target: EsVersion,
+
+    /// Allow `return` statements outside of functions.
+    #[serde(default)]
+    pub allow_return_outside_function: bool,
+
+    /// Parse `await` outside of functions as an await expression.
+    #[serde(default)]
+    pub allow_await_outside_function: bool,
+
+    /// Allow `import` and `export` in module item lists which are not at the
+    /// top level of a module.
+    ///
+    /// Module declarations nested in statements cannot be represented in the
+    /// ast, so they are still rejected.
+    #[serde(default)]
+    pub allow_import_export_everywhere: bool,
+
+    /// Allow `super` outside of methods and class properties.
+    ///
+    /// This is on by default because transforms emit `super` outside of
+    /// classes, and their output is parsed again.
+    #[serde(default = "default_allow_super_outside_method")]
+    pub allow_super_outside_method: bool,
+}
+
+impl Default for EsConfig {
+    fn default() -> Self {
+        EsConfig {
+            jsx: false,
+            num_sep: false,
+            class_private_props: false,
+            class_private_methods: false,
+            class_props: false,
+            fn_bind: false,
+            pipeline_operator: false,
+            pipeline_proposal: Default::default(),
+            partial_application: false,
+            do_expressions: false,
+            throw_expressions: false,
+            decorators: false,
+            decorators_before_export: false,
+            decorators_version: Default::default(),
+            export_default_from: false,
+            export_namespace_from: false,
+            dynamic_import: false,
+            target: Default::default(),
+            allow_return_outside_function: false,
+            allow_await_outside_function: false,
+            allow_import_export_everywhere: false,
+            allow_super_outside_method: default_allow_super_outside_method(),
+        }
+    }
+}
+
+fn default_allow_super_outside_method() -> bool {
+    true
+}
+
+/// Version of the pipeline operator proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PipelineProposal {
+    /// The right hand side is called with the left hand side.
+    #[serde(rename = "minimal")]
+    Minimal,
+
+    /// Same as minimal, but the right hand side may also be a bare `await` or
+    /// an arrow function whose body ends at the next `|>`.
+    #[serde(rename = "fsharp")]
+    FSharp,
+}
+
+impl Default for PipelineProposal {
+    fn default() -> Self {
+        PipelineProposal::Minimal
+    }
+}
+
+/// Version of the decorators proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DecoratorsVersion {
+    /// Decorators are allowed on classes and public class members.
+    ///
+    /// `decoratorsBeforeExport` decides if decorators come before or after
+    /// `export`.
+    #[serde(rename = "legacy")]
+    Legacy,
+
+    /// Decorators may come either before or after `export`, and are also
+    /// allowed on private members and `accessor` fields.
+    #[serde(rename = "2019-11")]
+    V201911,
+}
+
+impl Default for DecoratorsVersion {
+    fn default() -> Self {
+        DecoratorsVersion::Legacy
+    }
+}
+
+/// Version of ecmascript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum EsVersion {
+    #[serde(rename = "es3")]
+    Es3,
+    #[serde(rename = "es5")]
+    Es5,
+    #[serde(rename = "es2015")]
+    Es2015,
+    #[serde(rename = "es2016")]
+    Es2016,
+    #[serde(rename = "es2017")]
+    Es2017,
+    #[serde(rename = "es2018")]
+    Es2018,
+    #[serde(rename = "es2019")]
+    Es2019,
+    #[serde(rename = "es2020")]
+    Es2020,
+    #[serde(rename = "es2021")]
+    Es2021,
+    #[serde(rename = "esnext")]
+    EsNext,
+}
+
+impl Default for EsVersion {
+    fn default() -> Self {
+        EsVersion::EsNext
+    }
+}
+
+impl fmt::Display for EsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            EsVersion::Es3 => "es3",
+            EsVersion::Es5 => "es5",
+            EsVersion::Es2015 => "es2015",
+            EsVersion::Es2016 => "es2016",
+            EsVersion::Es2017 => "es2017",
+            EsVersion::Es2018 => "es2018",
+            EsVersion::Es2019 => "es2019",
+            EsVersion::Es2020 => "es2020",
+            EsVersion::Es2021 => "es2021",
+            EsVersion::EsNext => "esnext",
+        };
+        f.write_str(s)
+    }
 }
 
 /// Syntactic context.
@@ -348,6 +747,13 @@ pub struct Context {
     in_property_name: bool,
 
     in_forced_jsx_context: bool,
+
+    /// If true, `|>` ends the expression being parsed.
+    ///
+    /// Set while parsing the right hand side of a F# pipeline, so that the
+    /// body of an arrow function stops at the next `|>`. Reset by
+    /// `include_in_expr(true)`, which is used for bracketed expressions.
+    in_pipeline_body: bool,
 }
 
 #[derive(Clone, Copy)]
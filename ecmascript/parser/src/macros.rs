@@ -29,6 +29,9 @@ macro_rules! tok {
     ('|') => {
         crate::token::Token::BinOp(crate::token::BinOpToken::BitOr)
     };
+    ("|>") => {
+        crate::token::Token::BinOp(crate::token::BinOpToken::Pipeline)
+    };
 
     (',') => {
         crate::token::Token::Comma
@@ -335,6 +338,15 @@ macro_rules! tok {
     ("undefined") => {
         crate::token::Token::Word(crate::token::Word::Ident(js_word!("undefined")))
     };
+    ("mixed") => {
+        crate::token::Token::Word(crate::token::Word::Ident(js_word!("mixed")))
+    };
+    ("opaque") => {
+        crate::token::Token::Word(crate::token::Word::Ident(js_word!("opaque")))
+    };
+    ("type") => {
+        crate::token::Token::Word(crate::token::Word::Ident(js_word!("type")))
+    };
 }
 
 macro_rules! token_including_semi {
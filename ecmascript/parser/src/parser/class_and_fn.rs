@@ -68,17 +68,18 @@ impl<'a, I: Tokens> Parser<'a, I> {
         self.strict_mode().parse_with(|p| {
             let start = cur_pos!();
             expect!("class");
+            p.ensure_es_version(span!(start), EsVersion::Es2015, "class")?;
 
             let ident = p.parse_maybe_opt_binding_ident()?;
-            let type_params = if p.input.syntax().typescript() {
+            let type_params = if p.input.syntax().types() {
                 p.try_parse_ts_type_params()?
             } else {
                 None
             };
 
             let (super_class, super_type_params) = if eat!("extends") {
-                let super_class = p.parse_lhs_expr().map(Some)?;
-                let super_type_params = if p.input.syntax().typescript() && is!('<') {
+                let super_class = p.nested(|p| p.parse_lhs_expr()).map(Some)?;
+                let super_type_params = if p.input.syntax().types() && is!('<') {
                     Some(p.parse_ts_type_args()?)
                 } else {
                     None
@@ -88,7 +89,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 (None, None)
             };
 
-            let implements = if p.input.syntax().typescript() && eat!("implements") {
+            let implements = if p.input.syntax().types() && eat!("implements") {
                 p.parse_ts_heritage_clause()?
             } else {
                 vec![]
@@ -135,7 +136,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 unexpected!();
             }
 
-            if !self.syntax().decorators_before_export() {
+            // Both positions are allowed by the 2019-11 proposal.
+            if !self.syntax().decorators_before_export()
+                && self.syntax().decorators_version() == DecoratorsVersion::Legacy
+            {
                 syntax_error!(span!(start), SyntaxError::DecoratorOnExport);
             }
         } else if !is!("class") {
@@ -288,6 +292,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     is_optional,
                     false,
                     false,
+                    false,
                 );
             } else {
                 // TODO: error if static contains escape
@@ -324,6 +329,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         if eat!('*') {
             // generator method
             let key = self.parse_class_prop_name()?;
+            self.ensure_decorators_allowed(&key, &decorators)?;
             if readonly {
                 syntax_error!(span!(start), SyntaxError::ReadOnlyMethod);
             }
@@ -348,6 +354,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         let key = self.parse_class_prop_name()?;
+        self.ensure_decorators_allowed(&key, &decorators)?;
 
         let is_private = match key {
             Either::Left(PrivateName { .. }) => true,
@@ -368,10 +375,17 @@ impl<'a, I: Tokens> Parser<'a, I> {
             let is_constructor = is_constructor(&key);
 
             if is_constructor {
-                expect!('(');
-                let params = self.parse_constructor_params()?;
-                expect!(')');
-                let body = self.parse_fn_body(false, false)?;
+                let ctx = Context {
+                    in_method: true,
+                    ..self.ctx()
+                };
+                let (params, body) = self.with_ctx(ctx).parse_with(|p| {
+                    expect!('(');
+                    let params = p.parse_constructor_params()?;
+                    expect!(')');
+                    let body = p.parse_fn_body(false, false)?;
+                    Ok((params, body))
+                })?;
 
                 // TODO: check for duplicate constructors
                 return Ok(ClassMember::Constructor(Constructor {
@@ -414,6 +428,32 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 is_optional,
                 false,
                 is_abstract,
+                false,
+            );
+        }
+
+        if self.input.syntax().decorators_version() == DecoratorsVersion::V201911
+            && match key {
+                Either::Right(PropName::Ident(ref i)) => &*i.sym == "accessor",
+                _ => false,
+            }
+            && !is_optional
+            && !self.input.had_line_break_before_cur()
+        {
+            // handle accessor foo = 1;
+            let key = self.parse_class_prop_name()?;
+            self.ensure_decorators_allowed(&key, &decorators)?;
+            let is_optional = self.input.syntax().typescript() && eat!('?');
+            return self.make_property(
+                start,
+                decorators,
+                accessibility,
+                key,
+                is_static,
+                is_optional,
+                readonly,
+                is_abstract,
+                true,
             );
         }
 
@@ -423,8 +463,12 @@ impl<'a, I: Tokens> Parser<'a, I> {
         } && !self.input.had_line_break_before_cur()
         {
             // handle async foo(){}
+            self.ensure_es_version(span!(start), EsVersion::Es2017, "async function")?;
 
             let is_generator = eat!('*');
+            if is_generator {
+                self.ensure_es_version(span!(start), EsVersion::Es2018, "async generator")?;
+            }
             let key = self.parse_class_prop_name()?;
             if is_constructor(&key) {
                 syntax_error!(key.span(), SyntaxError::AsyncConstructor)
@@ -515,6 +559,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         is_optional: bool,
         readonly: bool,
         is_abstract: bool,
+        is_accessor: bool,
     ) -> PResult<'a, ClassMember> {
         if !self.input.syntax().class_props() {
             syntax_error!(span!(start), SyntaxError::ClassProperty)
@@ -538,7 +583,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     syntax_error!(span!(start), SyntaxError::ClassProperty);
                 }
                 assert_and_bump!('=');
-                Some(p.parse_assignment_expr()?)
+                let value = p.parse_assignment_expr()?;
+                if p.in_dts_ambient() {
+                    syntax_error!(value.span(), SyntaxError::TsInitializerInAmbient)
+                }
+                Some(value)
             } else {
                 None
             };
@@ -559,6 +608,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     definite,
                     type_ann,
                     computed: false,
+                    is_accessor,
                 }
                 .into(),
                 Either::Right(key) => ClassProp {
@@ -582,6 +632,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     readonly,
                     definite,
                     type_ann,
+                    is_accessor,
                 }
                 .into(),
             })
@@ -590,12 +641,12 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     #[inline(always)]
     fn is_class_method(&mut self) -> PResult<'a, bool> {
-        Ok(is!('(') || (self.input.syntax().typescript() && is!('<')))
+        Ok(is!('(') || (self.input.syntax().types() && is!('<')))
     }
 
     #[inline(always)]
     fn is_class_property(&mut self) -> PResult<'a, bool> {
-        Ok((self.input.syntax().typescript() && is_one_of!('!', ':')) || is_one_of!('=', ';', '}'))
+        Ok((self.input.syntax().types() && is_one_of!('!', ':')) || is_one_of!('=', ';', '}'))
     }
 
     fn parse_fn<T>(
@@ -613,19 +664,21 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         let is_generator = {
             let start = cur_pos!();
-            if eat!('*') {
-                if is_async {
-                    syntax_error!(span!(start), SyntaxError::AsyncGenerator {});
-                }
-                true
-            } else {
-                false
-            }
+            eat!('*')
         };
+        if is_async && is_generator {
+            self.ensure_es_version(span!(start), EsVersion::Es2018, "async generator")?;
+        } else if is_async {
+            self.ensure_es_version(span!(start), EsVersion::Es2017, "async function")?;
+        } else if is_generator {
+            self.ensure_es_version(span!(start), EsVersion::Es2015, "generator")?;
+        }
 
         let ctx = Context {
             in_async: is_async,
             in_generator: is_generator,
+            in_method: false,
+            in_class_prop: false,
             ..self.ctx()
         };
 
@@ -679,7 +732,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         };
 
         self.with_ctx(ctx).parse_with(|p| {
-            let type_params = if p.syntax().typescript() && is!('<') {
+            let type_params = if p.syntax().types() && is!('<') {
                 //
                 Some(p.parse_ts_type_params()?)
             } else {
@@ -697,7 +750,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             expect!(')');
 
             // typescript extension
-            let return_type = if p.syntax().typescript() && is!(':') {
+            let return_type = if p.syntax().types() && is!(':') {
                 p.parse_ts_type_or_type_predicate_ann(&tok!(':'))
                     .map(Some)?
             } else {
@@ -719,6 +772,43 @@ impl<'a, I: Tokens> Parser<'a, I> {
         })
     }
 
+    /// Same as [Parser::parse_fn_args_body], but `super` is allowed.
+    pub(super) fn parse_method_args_body<F>(
+        &mut self,
+        decorators: Vec<Decorator>,
+        start: BytePos,
+        parse_args: F,
+        is_async: bool,
+        is_generator: bool,
+    ) -> PResult<'a, Function>
+    where
+        F: FnOnce(&mut Self) -> PResult<'a, Vec<Pat>>,
+    {
+        let ctx = Context {
+            in_method: true,
+            ..self.ctx()
+        };
+        self.with_ctx(ctx)
+            .parse_fn_args_body(decorators, start, parse_args, is_async, is_generator)
+    }
+
+    /// Legacy decorators cannot be applied to private members.
+    fn ensure_decorators_allowed(
+        &self,
+        key: &Either<PrivateName, PropName>,
+        decorators: &[Decorator],
+    ) -> PResult<'a, ()> {
+        match *key {
+            Either::Left(ref key)
+                if !decorators.is_empty()
+                    && self.syntax().decorators_version() == DecoratorsVersion::Legacy =>
+            {
+                syntax_error!(key.span, SyntaxError::DecoratorOnPrivate)
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn parse_class_prop_name(&mut self) -> PResult<'a, Either<PrivateName, PropName>> {
         if is!('#') {
             self.parse_private_name().map(Either::Left)
@@ -747,7 +837,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         F: FnOnce(&mut Self) -> PResult<'a, Vec<Pat>>,
     {
         let function =
-            self.parse_fn_args_body(decorators, start, parse_args, is_async, is_generator)?;
+            self.parse_method_args_body(decorators, start, parse_args, is_async, is_generator)?;
 
         match key {
             Either::Left(key) => Ok(PrivateMethod {
@@ -789,7 +879,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             in_function: true,
             ..self.ctx()
         };
-        self.with_ctx(ctx).parse_fn_body_inner()
+        self.nested(|p| p.with_ctx(ctx).parse_fn_body_inner())
     }
 }
 
@@ -893,7 +983,7 @@ pub(super) trait FnBodyParser<'a, Body> {
 impl<'a, I: Tokens> FnBodyParser<'a, BlockStmtOrExpr> for Parser<'a, I> {
     fn parse_fn_body_inner(&mut self) -> PResult<'a, BlockStmtOrExpr> {
         if is!('{') {
-            self.parse_block(false).map(BlockStmtOrExpr::BlockStmt)
+            self.parse_fn_block(false).map(BlockStmtOrExpr::BlockStmt)
         } else {
             self.parse_assignment_expr().map(BlockStmtOrExpr::Expr)
         }
@@ -904,10 +994,51 @@ impl<'a, I: Tokens> FnBodyParser<'a, BlockStmtOrExpr> for Parser<'a, I> {
 impl<'a, I: Tokens> FnBodyParser<'a, Option<BlockStmt>> for Parser<'a, I> {
     fn parse_fn_body_inner(&mut self) -> PResult<'a, Option<BlockStmt>> {
         // allow omitting body and allow placing `{` on next line
-        if self.input.syntax().typescript() && !is!('{') && eat!(';') {
+        if self.input.syntax().types() && !is!('{') && eat!(';') {
             return Ok(None);
         }
-        self.include_in_expr(true).parse_block(true).map(Some)
+        if self.in_dts_ambient() {
+            // The body itself is not in the ambient context.
+            let ctx = Context {
+                in_declare: false,
+                include_in_expr: true,
+                ..self.ctx()
+            };
+            let body = self.with_ctx(ctx).parse_fn_block(true)?;
+            syntax_error!(body.span, SyntaxError::TsImplInAmbient)
+        }
+        self.include_in_expr(true).parse_fn_block(true).map(Some)
+    }
+}
+
+#[parser]
+impl<'a, I: Tokens> Parser<'a, I> {
+    /// Parses a function body, or skips it if lazy body parsing is enabled.
+    fn parse_fn_block(&mut self, allow_directives: bool) -> PResult<'a, BlockStmt> {
+        if !self.lazy_bodies || !is!('{') {
+            return self.parse_block(allow_directives);
+        }
+
+        let start = cur_pos!();
+        if !self.input.skip_braces() {
+            // Reports eof.
+            expect!('}');
+        }
+
+        let span = span!(start);
+        let ctx = self.ctx();
+        Ok(BlockStmt {
+            span,
+            stmts: vec![Stmt::Lazy(LazyBody {
+                span,
+                is_async: ctx.in_async,
+                is_generator: ctx.in_generator,
+                strict: ctx.strict,
+                in_module: ctx.module,
+                in_method: ctx.in_method,
+                in_class_prop: ctx.in_class_prop,
+            })],
+        })
     }
 }
 
@@ -0,0 +1,161 @@
+//! Restrictions of typescript declaration files (`.d.ts`).
+//!
+//! Everything in a declaration file is parsed in the `in_declare` context, and
+//! executable code is rejected as soon as it is parsed.
+use super::{
+    stmt::{IsDirective, StmtLikeParser},
+    *,
+};
+use swc_common::Spanned;
+
+#[parser]
+impl<'a, I: Tokens> Parser<'a, I> {
+    /// Returns true if restrictions of declaration files apply.
+    pub(super) fn in_dts_ambient(&self) -> bool {
+        self.input.syntax().dts() && self.ctx().in_declare
+    }
+
+    /// Parses an item at the top level of a declaration file.
+    ///
+    /// Declarations must start with `declare` or `export`, except for
+    /// interfaces and type aliases.
+    pub(super) fn parse_dts_top_level_item<Type>(
+        &mut self,
+        include_decl: bool,
+        top_level: bool,
+    ) -> PResult<'a, Type>
+    where
+        Self: StmtLikeParser<'a, Type>,
+        Type: IsDirective + From<Stmt>,
+    {
+        let start = cur_pos!();
+        let declared = is_one_of!("declare", "import", "export", "interface", "type")
+            || self.input.is(&Token::Semi);
+
+        let ctx = Context {
+            in_declare: true,
+            ..self.ctx()
+        };
+        let item = self
+            .with_ctx(ctx)
+            .parse_stmt_like(include_decl, top_level)?;
+        if !declared {
+            syntax_error!(span!(start), SyntaxError::TsDtsMissingDeclare)
+        }
+
+        Ok(item)
+    }
+
+    pub(super) fn check_dts_stmt(&self, stmt: &Stmt) -> PResult<'a, ()> {
+        match *stmt {
+            Stmt::Decl(..) | Stmt::Empty(..) => Ok(()),
+            _ => syntax_error!(stmt.span(), SyntaxError::TsStmtInAmbient),
+        }
+    }
+
+    pub(super) fn check_dts_var_decls(
+        &self,
+        kind: VarDeclKind,
+        decls: &[VarDeclarator],
+    ) -> PResult<'a, ()> {
+        for decl in decls {
+            match decl.init {
+                // `declare const a = 1;` is allowed.
+                Some(ref init) if kind == VarDeclKind::Const && is_literal(init) => {}
+                Some(ref init) => syntax_error!(init.span(), SyntaxError::TsInitializerInAmbient),
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn check_dts_export_assignment(&self, expr: &Expr) -> PResult<'a, ()> {
+        if !is_entity_name(expr) {
+            syntax_error!(expr.span(), SyntaxError::TsExportAssignmentInAmbient)
+        }
+
+        Ok(())
+    }
+}
+
+fn is_literal(e: &Expr) -> bool {
+    match *e {
+        Expr::Lit(Lit::Str(..)) | Expr::Lit(Lit::Num(..)) => true,
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            ref arg,
+            ..
+        }) => match **arg {
+            Expr::Lit(Lit::Num(..)) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns true for `a` and `a.b.c`.
+fn is_entity_name(e: &Expr) -> bool {
+    match *e {
+        Expr::Ident(..) => true,
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(ref obj),
+            computed: false,
+            ..
+        }) => is_entity_name(obj),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TsConfig;
+
+    #[test]
+    fn valid_declaration_file() {
+        let src = "
+import { B } from './b';
+export as namespace Lib;
+export = Lib;
+interface A { a: string }
+type T = A | B;
+declare const version = '1.0';
+declare let count: number;
+declare function f(a: A): void;
+declare class C extends B {
+    constructor(a: A);
+    private p;
+    m(): void;
+}
+declare enum E { A = 1 }
+declare namespace Lib {
+    function g(): void;
+    const h: number;
+    namespace Inner { class D {} }
+}
+declare module 'mod' {
+    export default function (): void;
+}
+declare global {
+    interface Window { lib: typeof Lib }
+}
+export declare function i(): void;
+export const j: string;
+;
+";
+        test_parser(
+            src,
+            Syntax::Typescript(TsConfig {
+                dts: true,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_module().map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+            },
+        );
+    }
+}
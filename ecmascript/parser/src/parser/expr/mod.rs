@@ -31,6 +31,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     ///`parseMaybeAssign` (overrided)
     pub(super) fn parse_assignment_expr(&mut self) -> PResult<'a, (Box<Expr>)> {
+        self.nested(|p| p.parse_assignment_expr_internal())
+    }
+
+    fn parse_assignment_expr_internal(&mut self) -> PResult<'a, (Box<Expr>)> {
         if self.input.syntax().typescript() {
             // Note: When the JSX plugin is on, type assertions (`<T> x`) aren't valid
             // syntax.
@@ -121,7 +125,25 @@ impl<'a, I: Tokens> Parser<'a, I> {
     ) -> PResult<'a, Box<Expr>> {
         match cur!(false) {
             Ok(&Token::AssignOp(op)) => {
+                if op == AssignOpToken::ExpAssign {
+                    self.ensure_es_version(
+                        self.input.cur_span(),
+                        EsVersion::Es2016,
+                        "exponentiation operator",
+                    )?;
+                }
+
                 let left = if op == AssignOpToken::Assign {
+                    match *cond {
+                        Expr::Array(..) | Expr::Object(..) => {
+                            self.ensure_es_version(
+                                cond.span(),
+                                EsVersion::Es2015,
+                                "destructuring assignment",
+                            )?;
+                        }
+                        _ => {}
+                    }
                     self.reparse_expr_as_pat(PatType::AssignPat, cond)
                         .map(Box::new)
                         .map(PatOrExpr::Pat)?
@@ -198,6 +220,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         if eat!("import") {
+            if is!('.') {
+                return self.parse_import_meta(start);
+            }
             return self.parse_dynamic_import(start);
         }
 
@@ -207,7 +232,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 return self.parse_async_fn_expr();
             }
 
-            if can_be_arrow && self.input.syntax().typescript() && peeked_is!('<') {
+            if can_be_arrow && self.input.syntax().types() && peeked_is!('<') {
                 // try parsing `async<T>() => {}`
                 if let Some(res) = self.try_parse_ts(|p| {
                     let start = cur_pos!();
@@ -226,10 +251,18 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         if is!('[') {
-            return self.parse_array_lit();
+            return self.nested(|p| p.parse_array_lit());
         }
         if is!('{') {
-            return self.parse_object();
+            return self.nested(|p| p.parse_object());
+        }
+
+        if self.input.syntax().do_expressions() && eat!("do") {
+            let body = self.include_in_expr(true).parse_block(false)?;
+            return Ok(Box::new(Expr::Do(DoExpr {
+                span: span!(start),
+                body,
+            })));
         }
 
         let decorators = self.parse_decorators(false)?;
@@ -264,6 +297,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         } {
             match bump!() {
                 Token::Regex(exp, flags) => {
+                    self.validate_regex(&exp, flags.as_ref())?;
                     return Ok(Box::new(Expr::Lit(Lit::Regex(Regex {
                         span: span!(start),
                         exp,
@@ -283,13 +317,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
             return self.parse_paren_expr_or_arrow_fn(can_be_arrow, None);
         }
 
-        if is!("let") || (self.input.syntax().typescript() && is!(IdentName)) || is!(IdentRef) {
+        if is!("let") || (self.input.syntax().types() && is!(IdentName)) || is!(IdentRef) {
             // TODO: Handle [Yield, Await]
             let id = self.parse_ident_name()?;
             if can_be_arrow && id.sym == js_word!("async") && is!(BindingIdent) {
                 // async a => body
                 let arg = self.parse_binding_ident().map(Pat::from)?;
                 let params = vec![arg];
+                self.ensure_es_version(span!(start), EsVersion::Es2017, "async function")?;
                 expect!("=>");
                 let body = self.parse_fn_body(true, false)?;
                 return Ok(Box::new(Expr::Arrow(ArrowExpr {
@@ -301,7 +336,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     return_type: None,
                     type_params: None,
                 })));
-            } else if can_be_arrow && !self.input.had_line_break_before_cur() && eat!("=>") {
+            } else if can_be_arrow && !self.input.had_line_break_before_cur() && is!("=>") {
+                self.ensure_es_version(self.input.cur_span(), EsVersion::Es2015, "arrow function")?;
+                assert_and_bump!("=>");
                 let params = vec![id.into()];
                 let body = self.parse_fn_body(false, false)?;
 
@@ -364,6 +401,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             if eat!('.') {
                 let start_of_target = cur_pos!();
                 if eat!("target") {
+                    self.ensure_es_version(span!(start), EsVersion::Es2015, "new.target")?;
                     return Ok(Box::new(Expr::MetaProp(MetaPropExpr {
                         meta: Ident::new(js_word!("new"), span_of_new),
                         prop: Ident::new(js_word!("target"), span!(start_of_target)),
@@ -374,10 +412,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
 
             // 'NewExpression' allows new call without paren.
-            let callee = self.parse_member_expr_or_new_expr(is_new_expr)?;
+            let callee = self.nested(|p| p.parse_member_expr_or_new_expr(is_new_expr))?;
             return_if_arrow!(callee);
 
-            let type_args = if self.input.syntax().typescript() && is!('<') {
+            let type_args = if self.input.syntax().types() && is!('<') {
                 self.try_parse_ts(|p| {
                     let args = p.parse_ts_type_args()?;
                     if !is!('(') {
@@ -391,7 +429,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             if !is_new_expr || is!('(') {
                 // Parsed with 'MemberExpression' production.
-                let args = self.parse_args(false).map(Some)?;
+                let args = self.parse_args(false)?;
+                for arg in &args {
+                    if let Expr::ArgPlaceholder(ArgPlaceholder { span }) = *arg.expr {
+                        syntax_error!(span, SyntaxError::ArgPlaceholderInNew)
+                    }
+                }
+                let args = Some(args);
 
                 let new_expr = ExprOrSuper::Expr(Box::new(Expr::New(NewExpr {
                     span: span!(start),
@@ -416,7 +460,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         if eat!("super") {
-            let base = ExprOrSuper::Super(Super { span: span!(start) });
+            let span = span!(start);
+            self.ensure_super_allowed(span)?;
+            let base = ExprOrSuper::Super(Super { span });
             return self.parse_subscripts(base, true);
         }
         let obj = self.parse_primary_expr()?;
@@ -457,7 +503,18 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }
             }
 
-            expr_or_spreads.push(self.include_in_expr(true).parse_expr_or_spread()?);
+            // `f(?, x)`
+            if self.input.syntax().partial_application() && !is_dynamic_import && is!('?') {
+                let start = cur_pos!();
+                bump!();
+                expr_or_spreads.push(ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::ArgPlaceholder(ArgPlaceholder { span: span!(start) })),
+                });
+                continue;
+            }
+
+            expr_or_spreads.push(self.nested(|p| p.include_in_expr(true).parse_expr_or_spread())?);
         }
 
         expect!(')');
@@ -471,6 +528,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         if eat!("...") {
             let spread = Some(span!(start));
+            self.ensure_es_version(span!(start), EsVersion::Es2015, "spread element")?;
             self.include_in_expr(true)
                 .parse_assignment_expr()
                 .map(|expr| ExprOrSpread { spread, expr })
@@ -493,28 +551,47 @@ impl<'a, I: Tokens> Parser<'a, I> {
         // But as all patterns of javascript is subset of
         // expressions, we can parse both as expression.
 
-        let paren_items = self.include_in_expr(true).parse_args_or_pats()?;
+        let paren_items = self.nested(|p| p.include_in_expr(true).parse_args_or_pats())?;
         let has_pattern = paren_items.iter().any(|item| match item {
             PatOrExprOrSpread::Pat(..) => true,
             _ => false,
         });
 
         let return_type =
-            if !self.ctx().in_cond_expr && self.input.syntax().typescript() && is!(':') {
+            if !self.ctx().in_cond_expr && self.input.syntax().types() && is!(':') {
                 let start = cur_pos!();
                 Some(self.parse_ts_type_or_type_predicate_ann(&tok!(':'))?)
             } else {
                 None
             };
 
+        // Flow: `(foo: T)` is a type cast if it's not followed by an arrow.
+        let is_flow_type_cast = self.input.syntax().flow()
+            && has_pattern
+            && return_type.is_none()
+            && !is!("=>")
+            && paren_items.iter().all(|item| match item {
+                PatOrExprOrSpread::Pat(Pat::Ident(Ident {
+                    type_ann: Some(..),
+                    optional: false,
+                    ..
+                }))
+                | PatOrExprOrSpread::ExprOrSpread(..) => true,
+                _ => false,
+            });
+
         // we parse arrow function at here, to handle it efficiently.
-        if has_pattern || return_type.is_some() || is!("=>") {
+        if (has_pattern && !is_flow_type_cast) || return_type.is_some() || is!("=>") {
             if self.input.had_line_break_before_cur() {
                 syntax_error!(span!(start), SyntaxError::LineBreakBeforeArrow);
             }
             if !can_be_arrow {
                 unexpected!()
             }
+            if async_span.is_some() {
+                self.ensure_es_version(span!(start), EsVersion::Es2017, "async function")?;
+            }
+            self.ensure_es_version(self.input.cur_span(), EsVersion::Es2015, "arrow function")?;
             expect!("=>");
 
             let params = self
@@ -539,6 +616,20 @@ impl<'a, I: Tokens> Parser<'a, I> {
             .map(|item| -> PResult<'a, _> {
                 match item {
                     PatOrExprOrSpread::ExprOrSpread(e) => Ok(e),
+                    // Flow: `(foo: T)`
+                    PatOrExprOrSpread::Pat(Pat::Ident(Ident {
+                        span,
+                        sym,
+                        type_ann: Some(type_ann),
+                        optional: false,
+                    })) if self.input.syntax().flow() => Ok(ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::TsTypeCast(TsTypeCastExpr {
+                            span: span.with_hi(type_ann.span.hi()),
+                            expr: Box::new(Expr::Ident(Ident::new(sym, span))),
+                            type_ann,
+                        })),
+                    }),
                     _ => syntax_error!(item.span(), SyntaxError::InvalidExpr),
                 }
             })
@@ -647,6 +738,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         expect!('`');
 
         let span = span!(start);
+        self.ensure_es_version(span, EsVersion::Es2015, "template literal")?;
         Ok(TaggedTpl {
             span,
             tag,
@@ -666,6 +758,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         expect!('`');
 
         let span = span!(start);
+        self.ensure_es_version(span, EsVersion::Es2015, "template literal")?;
         Ok(Tpl {
             span,
             exprs,
@@ -687,11 +780,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
                         span: span!(start),
                         value: raw,
                         has_escape,
+                        raw: None,
                     },
                     Some(Str {
                         span: span!(start),
                         value: cooked,
                         has_escape,
+                        raw: None,
                     }),
                 ),
                 _ => unreachable!(),
@@ -816,6 +911,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         let is_optional_chaining =
             self.input.syntax().typescript() && is!('?') && peeked_is!('.') && eat!('?');
+        if is_optional_chaining {
+            self.ensure_es_version(
+                self.input.prev_span(),
+                EsVersion::Es2020,
+                "optional chaining",
+            )?;
+        }
 
         /// Wrap with optional chaining
         macro_rules! wrap {
@@ -947,14 +1049,16 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         // `super()` can't be handled from parse_new_expr()
         if eat!("super") {
-            let obj = ExprOrSuper::Super(Super { span: span!(start) });
+            let span = span!(start);
+            self.ensure_super_allowed(span)?;
+            let obj = ExprOrSuper::Super(Super { span });
             return self.parse_subscripts(obj, false);
         }
 
         let callee = self.parse_new_expr()?;
         return_if_arrow!(callee);
 
-        let type_args = if self.input.syntax().typescript() && is!('<') {
+        let type_args = if self.input.syntax().types() && is!('<') {
             self.try_parse_ts(|p| {
                 let type_args = p.parse_ts_type_args()?;
                 if is!('(') {
@@ -1048,7 +1152,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             let start = cur_pos!();
 
             let mut arg = {
-                if self.input.syntax().typescript()
+                if self.input.syntax().types()
                     && (is!(IdentRef) || (is!("...") && peeked_is!(IdentRef)))
                 {
                     let spread = if eat!("...") {
@@ -1079,7 +1183,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     self.include_in_expr(true).parse_expr_or_spread()?
                 }
             };
-            let optional = if self.input.syntax().typescript() {
+            let optional = if self.input.syntax().types() {
                 if is!('?') {
                     if peeked_is!(',') || peeked_is!(':') || peeked_is!(')') || peeked_is!('=') {
                         bump!();
@@ -1132,7 +1236,27 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 false
             };
 
-            if optional || (self.input.syntax().typescript() && is!(':')) {
+            // Flow: `(foo.bar: T)` can't be reparsed as a pattern, so it must be a type
+            // cast.
+            if self.input.syntax().flow() && !optional && arg.spread.is_none() && is!(':') {
+                match *arg.expr {
+                    Expr::Ident(..) | Expr::Array(..) | Expr::Object(..) | Expr::Assign(..) => {}
+                    _ => {
+                        let pos = cur_pos!();
+                        let type_ann = self.parse_ts_type_ann(/* eat_colon */ true, pos)?;
+                        arg = ExprOrSpread {
+                            spread: None,
+                            expr: Box::new(Expr::TsTypeCast(TsTypeCastExpr {
+                                span: span!(start),
+                                expr: arg.expr,
+                                type_ann,
+                            })),
+                        };
+                    }
+                }
+            }
+
+            if optional || (self.input.syntax().types() && is!(':')) {
                 let start = cur_pos!();
                 let mut pat = self.reparse_expr_as_pat(PatType::BindingPat, arg.expr)?;
                 if optional {
@@ -1200,6 +1324,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     _ => false,
                 }
             } {
+                self.ensure_es_version(
+                    self.input.prev_span(),
+                    EsVersion::Es2015,
+                    "arrow function",
+                )?;
                 let params = self
                     .parse_paren_items_as_params(items)?
                     .into_iter()
@@ -1307,18 +1436,26 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 Lit::Bool(Bool { span, value })
             }
             Token::Str { .. } => match bump!() {
-                Token::Str { value, has_escape } => Lit::Str(Str {
-                    span: span!(start),
-                    value,
-                    has_escape,
-                }),
+                Token::Str { value, has_escape } => {
+                    let span = span!(start);
+                    Lit::Str(Str {
+                        span,
+                        value,
+                        has_escape,
+                        raw: self.input.raw(span),
+                    })
+                }
                 _ => unreachable!(),
             },
             Token::Num(..) => match bump!() {
-                Token::Num(value) => Lit::Num(Number {
-                    span: span!(start),
-                    value,
-                }),
+                Token::Num(value) => {
+                    let span = span!(start);
+                    Lit::Num(Number {
+                        span,
+                        value,
+                        raw: self.input.raw(span),
+                    })
+                }
                 _ => unreachable!(),
             },
             _ => unreachable!("parse_lit should not be called"),
@@ -1326,10 +1463,30 @@ impl<'a, I: Tokens> Parser<'a, I> {
         Ok(v)
     }
 
+    /// Parses `import.meta` after `import`.
+    fn parse_import_meta(&mut self, start: BytePos) -> PResult<'a, Box<Expr>> {
+        let span_of_import = span!(start);
+        expect!('.');
+        let prop = self.parse_ident_name()?;
+        if &*prop.sym != "meta" {
+            syntax_error!(prop.span, SyntaxError::InvalidImportMeta);
+        }
+        if !self.ctx().module {
+            syntax_error!(span!(start), SyntaxError::ImportMetaInScript);
+        }
+        self.ensure_es_version(span!(start), EsVersion::Es2020, "import.meta")?;
+
+        Ok(Box::new(Expr::MetaProp(MetaPropExpr {
+            meta: Ident::new(js_word!("import"), span_of_import),
+            prop,
+        })))
+    }
+
     pub(super) fn parse_dynamic_import(&mut self, start: BytePos) -> PResult<'a, Box<Expr>> {
         if !self.input.syntax().dynamic_import() {
             syntax_error!(span!(start), SyntaxError::DynamicImport);
         }
+        self.ensure_es_version(span!(start), EsVersion::Es2020, "dynamic import")?;
         let args = self.parse_args(true)?;
         let import = Box::new(Expr::Call(CallExpr {
             span: span!(start),
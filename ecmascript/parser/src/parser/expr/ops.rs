@@ -10,7 +10,68 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let left = self.parse_unary_expr()?;
 
         return_if_arrow!(left);
-        self.parse_bin_op_recursively(left, 0)
+        let left = self.parse_bin_op_recursively(left, 0)?;
+        self.parse_pipeline(left)
+    }
+
+    /// `a |> f |> g`
+    ///
+    /// The pipeline operator has lower precedence than any other binary
+    /// operator, so it's handled here instead of `parse_bin_op_recursively`.
+    fn parse_pipeline(&mut self, mut left: Box<Expr>) -> PResult<'a, Box<Expr>> {
+        while is!("|>") && !self.ctx().in_pipeline_body {
+            bump!();
+
+            let right = self.parse_pipeline_body()?;
+            left = Box::new(Expr::Bin(BinExpr {
+                span: span!(left.span().lo()),
+                op: op!("|>"),
+                left,
+                right,
+            }));
+        }
+
+        Ok(left)
+    }
+
+    /// Parses the right hand side of `|>`.
+    fn parse_pipeline_body(&mut self) -> PResult<'a, Box<Expr>> {
+        let start = cur_pos!();
+        let fsharp = self.input.syntax().pipeline_proposal() == PipelineProposal::FSharp;
+
+        if fsharp && self.ctx().in_async && is!("await") {
+            // `x |> await`
+            let is_bare = match self.input.peek() {
+                Some(next) => !next.starts_expr(),
+                None => true,
+            };
+            if is_bare {
+                bump!();
+                let span = span!(start);
+                return Ok(Box::new(Expr::Await(AwaitExpr {
+                    span,
+                    arg: Box::new(Expr::ArgPlaceholder(ArgPlaceholder { span })),
+                })));
+            }
+        }
+
+        let ctx = Context {
+            in_pipeline_body: fsharp,
+            ..self.ctx()
+        };
+        let right = self.with_ctx(ctx).parse_with(|p| {
+            let left = p.parse_unary_expr()?;
+            return_if_arrow!(left);
+            p.parse_bin_op_recursively(left, 0)
+        })?;
+
+        if !fsharp {
+            if let Expr::Arrow(..) = *right {
+                syntax_error!(span!(start), SyntaxError::PipelineBodyNoArrow)
+            }
+        }
+
+        Ok(right)
     }
 
     /// Parse binary operators with the operator precedence parsing
@@ -22,106 +83,118 @@ impl<'a, I: Tokens> Parser<'a, I> {
     /// `parseExprOp`
     fn parse_bin_op_recursively(
         &mut self,
-        left: Box<Expr>,
+        mut left: Box<Expr>,
         min_prec: u8,
     ) -> PResult<'a, Box<Expr>> {
         const PREC_OF_IN: u8 = 7;
 
-        if self.input.syntax().typescript() {
-            if PREC_OF_IN > min_prec && !self.input.had_line_break_before_cur() && is!("as") {
-                let span = span!(left.span().lo());
-                let expr = left;
-                let node = if peeked_is!("const") {
-                    bump!(); // as
-                    let _ = cur!(false);
-                    bump!(); // const
-                    Box::new(Expr::TsConstAssertion(TsConstAssertion { span, expr }))
-                } else {
-                    let type_ann = self.next_then_parse_ts_type()?;
-                    Box::new(Expr::TsAs(TsAsExpr {
-                        span,
-                        expr,
-                        type_ann,
-                    }))
-                };
-
-                return self.parse_bin_op_recursively(node, min_prec);
+        // Operators with the same precedence are handled by this loop instead of
+        // recursion, so that long chains like `a + b + c + ...` don't overflow
+        // the stack.
+        loop {
+            if self.input.syntax().typescript() {
+                if PREC_OF_IN > min_prec && !self.input.had_line_break_before_cur() && is!("as") {
+                    let span = span!(left.span().lo());
+                    let expr = left;
+                    let node = if peeked_is!("const") {
+                        bump!(); // as
+                        let _ = cur!(false);
+                        bump!(); // const
+                        Box::new(Expr::TsConstAssertion(TsConstAssertion { span, expr }))
+                    } else {
+                        let type_ann = self.next_then_parse_ts_type()?;
+                        Box::new(Expr::TsAs(TsAsExpr {
+                            span,
+                            expr,
+                            type_ann,
+                        }))
+                    };
+
+                    left = node;
+                    continue;
+                }
             }
-        }
 
-        let ctx = self.ctx();
-        let op = match {
-            // Return left on eof
-            match cur!(false) {
-                Ok(cur) => cur,
-                Err(..) => return Ok(left),
-            }
-        } {
-            &Word(Word::Keyword(Keyword::In)) if ctx.include_in_expr => op!("in"),
-            &Word(Word::Keyword(Keyword::InstanceOf)) => op!("instanceof"),
-            &Token::BinOp(op) => op.into(),
-            _ => {
+            let ctx = self.ctx();
+            let op = match {
+                // Return left on eof
+                match cur!(false) {
+                    Ok(cur) => cur,
+                    Err(..) => return Ok(left),
+                }
+            } {
+                &Word(Word::Keyword(Keyword::In)) if ctx.include_in_expr => op!("in"),
+                &Word(Word::Keyword(Keyword::InstanceOf)) => op!("instanceof"),
+                &Token::BinOp(op) => op.into(),
+                _ => {
+                    return Ok(left);
+                }
+            };
+
+            if op.precedence() <= min_prec {
+                trace!(
+                    "returning {:?} without parsing {:?} because min_prec={}, prec={}",
+                    left,
+                    op,
+                    min_prec,
+                    op.precedence()
+                );
+
                 return Ok(left);
             }
-        };
-
-        if op.precedence() <= min_prec {
+            if op == op!("**") {
+                self.ensure_es_version(
+                    self.input.cur_span(),
+                    EsVersion::Es2016,
+                    "exponentiation operator",
+                )?;
+            }
+            bump!();
             trace!(
-                "returning {:?} without parsing {:?} because min_prec={}, prec={}",
-                left,
+                "parsing binary op {:?} min_prec={}, prec={}",
                 op,
                 min_prec,
                 op.precedence()
             );
 
-            return Ok(left);
-        }
-        bump!();
-        trace!(
-            "parsing binary op {:?} min_prec={}, prec={}",
-            op,
-            min_prec,
-            op.precedence()
-        );
-
-        match *left {
-            // This is invalid syntax.
-            Expr::Unary { .. } if op == op!("**") => {
-                // Correct implementation would be returning Ok(left) and
-                // returning "unexpected token '**'" on next.
-                // But it's not useful error message.
-
-                syntax_error!(SyntaxError::UnaryInExp {
-                    // FIXME: Use display
-                    left: format!("{:?}", left),
-                    left_span: left.span(),
-                })
+            match *left {
+                // This is invalid syntax.
+                Expr::Unary { .. } if op == op!("**") => {
+                    // Correct implementation would be returning Ok(left) and
+                    // returning "unexpected token '**'" on next.
+                    // But it's not useful error message.
+
+                    syntax_error!(SyntaxError::UnaryInExp {
+                        // FIXME: Use display
+                        left: format!("{:?}", left),
+                        left_span: left.span(),
+                    })
+                }
+                _ => {}
             }
-            _ => {}
-        }
-
-        let right = {
-            let left_of_right = self.parse_unary_expr()?;
-            self.parse_bin_op_recursively(
-                left_of_right,
-                if op == op!("**") {
-                    // exponential operator is right associative
-                    op.precedence() - 1
-                } else {
-                    op.precedence()
-                },
-            )?
-        };
 
-        let node = Box::new(Expr::Bin(BinExpr {
-            span: span!(left.span().lo()),
-            op,
-            left,
-            right,
-        }));
+            let right = self.nested(|p| {
+                let left_of_right = p.parse_unary_expr()?;
+                p.parse_bin_op_recursively(
+                    left_of_right,
+                    if op == op!("**") {
+                        // exponential operator is right associative
+                        op.precedence() - 1
+                    } else {
+                        op.precedence()
+                    },
+                )
+            })?;
+
+            let node = Box::new(Expr::Bin(BinExpr {
+                span: span!(left.span().lo()),
+                op,
+                left,
+                right,
+            }));
 
-        let expr = self.parse_bin_op_recursively(node, min_prec)?;
-        Ok(expr)
+            left = node;
+        }
     }
 
     /// Parse unary expression and update expression.
@@ -133,7 +206,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         if !self.input.syntax().jsx() && self.input.syntax().typescript() && eat!('<') {
             if eat!("const") {
                 expect!('>');
-                let expr = self.parse_unary_expr()?;
+                let expr = self.nested(|p| p.parse_unary_expr())?;
                 return Ok(Box::new(Expr::TsConstAssertion(TsConstAssertion {
                     span: span!(start),
                     expr,
@@ -151,7 +224,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 op!("--")
             };
 
-            let arg = self.parse_unary_expr()?;
+            let arg = self.nested(|p| p.parse_unary_expr())?;
             if !arg.is_valid_simple_assignment_target(self.ctx().strict) {
                 // This is early ReferenceError
                 syntax_error!(arg.span(), SyntaxError::NotSimpleAssign)
@@ -177,7 +250,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 tok!('!') => op!("!"),
                 _ => unreachable!(),
             };
-            let arg = self.parse_unary_expr()?;
+            let arg = self.nested(|p| p.parse_unary_expr())?;
             let span = Span::new(start, arg.span().hi(), Default::default());
             return Ok(Box::new(Expr::Unary(UnaryExpr { span, op, arg })));
         }
@@ -186,6 +259,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
             return self.parse_await_expr();
         }
 
+        if self.input.syntax().throw_expressions() && eat!("throw") {
+            let arg = self.nested(|p| p.parse_unary_expr())?;
+            return Ok(Box::new(Expr::Throw(ThrowExpr {
+                span: span!(start),
+                arg,
+            })));
+        }
+
         // UpdateExpression
         let expr = self.parse_lhs_expr()?;
         return_if_arrow!(expr);
@@ -228,7 +309,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             syntax_error!(SyntaxError::AwaitStar);
         }
 
-        let arg = self.parse_unary_expr()?;
+        let arg = self.nested(|p| p.parse_unary_expr())?;
         Ok(Box::new(Expr::Await(AwaitExpr {
             span: span!(start),
             arg,
@@ -239,6 +320,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::EsConfig;
     use swc_common::DUMMY_SP as span;
 
     fn bin(s: &'static str) -> Box<Expr> {
@@ -275,4 +357,52 @@ mod tests {
             }))
         );
     }
+
+    fn pipeline(s: &'static str, pipeline_proposal: PipelineProposal) -> Box<Expr> {
+        test_parser(
+            s,
+            Syntax::Es(EsConfig {
+                pipeline_operator: true,
+                pipeline_proposal,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_expr().map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+            },
+        )
+    }
+
+    #[test]
+    fn pipeline_lowest_prec() {
+        assert_eq_ignore_span!(
+            pipeline("a + b |> f |> g", PipelineProposal::Minimal),
+            Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!("|>"),
+                left: Box::new(Expr::Bin(BinExpr {
+                    span,
+                    op: op!("|>"),
+                    left: bin("a + b"),
+                    right: bin("f"),
+                })),
+                right: bin("g"),
+            }))
+        );
+    }
+
+    #[test]
+    fn pipeline_fsharp_arrow_body() {
+        assert_eq_ignore_span!(
+            pipeline("x |> y => y + 1 |> f", PipelineProposal::FSharp),
+            Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!("|>"),
+                left: pipeline("x |> y => y + 1", PipelineProposal::FSharp),
+                right: bin("f"),
+            }))
+        );
+    }
 }
@@ -308,7 +308,8 @@ fn max_integer() {
         expr("1.7976931348623157e+308"),
         box Expr::Lit(Lit::Num(Number {
             span,
-            value: 1.7976931348623157e+308
+            value: 1.7976931348623157e+308,
+            raw: Some("1.7976931348623157e+308".into()),
         }))
     )
 }
@@ -366,7 +367,8 @@ fn issue_328() {
                 expr: box Expr::Lit(Lit::Str(Str {
                     span,
                     value: "test".into(),
-                    has_escape: false
+                    has_escape: false,
+                    raw: Some("'test'".into()),
                 }))
             }],
             type_args: Default::default(),
@@ -400,6 +402,7 @@ hehe.";"#,
             span,
             value: "okokhehe.".into(),
             has_escape: true,
+            raw: Some("\"ok\\\nok\\\nhehe.\"".into()),
         }))
     );
 }
@@ -415,3 +418,79 @@ fn issue_380() {
 }",
     );
 }
+
+fn parse_with_max_depth(src: String, max_depth: usize) -> Result<Module, ::testing::StdErr> {
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(swc_common::FileName::Anon, src);
+        let session = Session { handler: &handler };
+
+        Parser::new(session, syntax(), (&*fm).into(), None)
+            .with_max_depth(max_depth)
+            .parse_module()
+            .map_err(|mut e| {
+                e.emit();
+                ()
+            })
+    })
+}
+
+#[test]
+fn max_depth_parens() {
+    let src = format!("{}a{}", "(".repeat(20), ")".repeat(20));
+
+    parse_with_max_depth(src.clone(), 64).unwrap();
+    let err = parse_with_max_depth(src, 16).expect_err("should fail");
+    assert!(err.contains("Maximum nesting depth exceeded"), "{}", err);
+}
+
+#[test]
+fn max_depth_arrays_and_blocks() {
+    let arrays = format!("x = {}1{};", "[".repeat(20), "]".repeat(20));
+    let blocks = format!("{}{}", "{".repeat(20), "}".repeat(20));
+
+    for src in vec![arrays, blocks] {
+        let err = parse_with_max_depth(src, 16).expect_err("should fail");
+        assert!(err.contains("Maximum nesting depth exceeded"), "{}", err);
+    }
+}
+
+#[test]
+fn long_binary_chain() {
+    let src = format!("x = a{};", " + a".repeat(3_000));
+
+    parse_with_max_depth(src, 16).unwrap();
+}
+
+#[test]
+fn long_flat_chains() {
+    let else_if = format!("if (a) {{}}{}", " else if (a) { b(); }".repeat(100));
+    let cond = format!("x = a{};", " ? a : a".repeat(100));
+    let assign = format!("{}b;", "a = ".repeat(100));
+
+    for src in vec![else_if, cond, assign] {
+        parse_with_max_depth(src, DEFAULT_MAX_DEPTH).unwrap();
+    }
+}
+
+#[test]
+fn max_depth_recursive_productions() {
+    let n = 100_000;
+    let srcs = vec![
+        format!("{}a;", "!".repeat(n)),
+        format!("{}a;", "a = ".repeat(n)),
+        format!("{}a;", "a ? a : ".repeat(n)),
+        format!("{}a;", "a ** ".repeat(n)),
+        format!("{}a;", "new ".repeat(n)),
+        format!("{}a;", "if (a) ".repeat(n)),
+        format!("{}a;", "if (a) a; else ".repeat(n)),
+        format!("{}a;", "a: ".repeat(n)),
+        format!("{}a;", "while (a) ".repeat(n)),
+        format!("{}a;", "for (;;) ".repeat(n)),
+        format!("{}a;", "x => ".repeat(n)),
+    ];
+
+    for src in srcs {
+        let err = parse_with_max_depth(src, DEFAULT_MAX_DEPTH).expect_err("should fail");
+        assert!(err.contains("Maximum nesting depth exceeded"), "{}", err);
+    }
+}
@@ -0,0 +1,321 @@
+//! Incremental reparsing.
+//!
+//! Only top-level items touched by an edit are re-lexed and re-parsed. Other
+//! items are reused from the previous module, with their spans shifted.
+use super::*;
+use crate::SourceFileInput;
+use swc_common::{comments::Comment, Fold, FoldWith, SourceFile, Spanned};
+
+/// A text edit, described in terms of the old source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Range of the old source file which is replaced.
+    pub span: Span,
+    /// Length of the inserted text, in bytes.
+    pub new_len: u32,
+}
+
+impl<'a> Parser<'a, Lexer<'a, SourceFileInput<'a>>> {
+    /// Reparses a module after `edit` is applied to `old_fm`.
+    ///
+    /// `old` should be the result of parsing `old_fm` with the same `syntax`,
+    /// and `new_fm` should be the content of `old_fm` with `edit` applied.
+    ///
+    /// `comments` should contain the comments of `old`. Comments of reused
+    /// items are moved along with them, so the result and the comments are
+    /// identical to parsing `new_fm` from scratch. If parsing fails, only
+    /// comments of re-parsed items are kept.
+    pub fn reparse_module(
+        session: Session<'a>,
+        syntax: Syntax,
+        comments: Option<&'a Comments>,
+        old: Module,
+        old_fm: &SourceFile,
+        new_fm: &'a SourceFile,
+        edit: TextEdit,
+    ) -> PResult<'a, Module> {
+        let Module {
+            span: module_span,
+            body: old_body,
+            shebang,
+        } = old;
+
+        let edit_lo = edit.span.lo();
+        let edit_hi = edit.span.hi();
+        // Offset of unchanged text before the edit.
+        let base = i64::from(new_fm.start_pos.0) - i64::from(old_fm.start_pos.0);
+        // Offset of unchanged text after the edit.
+        let delta = base + i64::from(edit.new_len) - i64::from((edit_hi - edit_lo).0);
+
+        // An item right before the edit can be affected as well, e.g. `a` in
+        //
+        // `a` + `\n(b)`
+        let start_idx = old_body
+            .iter()
+            .position(|item| item_span(item).hi() >= edit_lo)
+            .unwrap_or(old_body.len())
+            .saturating_sub(1);
+
+        let old_comments = comments.map(Comments::take_all);
+
+        // Shebang and directives are handled by a full parse.
+        if start_idx == 0 {
+            return Parser::new(session, syntax, SourceFileInput::from(new_fm), comments)
+                .parse_module();
+        }
+
+        let mut old_body = old_body.into_iter();
+        let mut body: Vec<ModuleItem> = old_body
+            .by_ref()
+            .take(start_idx)
+            .map(|item| item.fold_with(&mut ShiftSpans { delta: base }))
+            .collect();
+        let mut old_rest = old_body.peekable();
+
+        let old_start = item_span(old_rest.peek().expect("start_idx is in bounds")).lo();
+        let start = shift(old_start, base);
+        let end = shift(edit_hi, delta);
+
+        let mut input = SourceFileInput::from(new_fm);
+        input.reset_to(start);
+        let mut p = Parser::new(session, syntax, input, comments);
+        let ctx = Context {
+            module: true,
+            strict: true,
+            in_async: p.input.syntax().allow_await_outside_function(),
+            ..p.ctx()
+        };
+        p.set_ctx(ctx);
+
+        let mut reused = None;
+        let mut parsed = false;
+        loop {
+            if p.input.cur().is_none() {
+                break;
+            }
+
+            let pos = p.input.cur_pos();
+            if pos >= end {
+                // Skip items which are replaced by re-parsed ones.
+                while old_rest
+                    .peek()
+                    .map(|item| {
+                        let lo = item_span(item).lo();
+                        lo < edit_hi || shift(lo, delta) < pos
+                    })
+                    .unwrap_or(false)
+                {
+                    old_rest.next();
+                }
+
+                let old_lo = old_rest.peek().map(|item| item_span(item).lo());
+                if old_lo.map(|lo| shift(lo, delta) == pos).unwrap_or(false) {
+                    body.extend(old_rest.map(|item| item.fold_with(&mut ShiftSpans { delta })));
+                    reused = old_lo;
+                    break;
+                }
+            }
+
+            body.push(p.parse_stmt_like(true, true)?);
+            parsed = true;
+        }
+
+        if let (Some(comments), Some(old_comments)) = (comments, old_comments) {
+            // Comments of re-parsed items are collected by the lexer.
+            comments.extend_with(old_comments, |pos, cmts| {
+                let offset = if pos <= old_start {
+                    base
+                } else if reused.map(|lo| pos >= lo).unwrap_or(false) {
+                    delta
+                } else {
+                    return None;
+                };
+                let cmts = cmts
+                    .into_iter()
+                    .map(|cmt| Comment {
+                        span: ShiftSpans { delta: offset }.fold(cmt.span),
+                        ..cmt
+                    })
+                    .collect();
+                Some((shift(pos, offset), cmts))
+            });
+        }
+
+        let hi = if reused.is_some() {
+            shift(module_span.hi(), delta)
+        } else if parsed {
+            p.input.last_pos()
+        } else {
+            body.last().expect("start_idx > 0").span().hi()
+        };
+
+        Ok(Module {
+            span: Span::new(shift(module_span.lo(), base), hi, Default::default()),
+            body,
+            shebang,
+        })
+    }
+}
+
+/// Span of `item`, including decorators.
+fn item_span(item: &ModuleItem) -> Span {
+    let span = item.span();
+
+    let decorators = match *item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(ref c)))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Class(ref c),
+            ..
+        })) => &c.class.decorators,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Class(ref c),
+            ..
+        })) => &c.class.decorators,
+        _ => return span,
+    };
+
+    match decorators.first() {
+        Some(d) if d.span.lo() < span.lo() => span.with_lo(d.span.lo()),
+        _ => span,
+    }
+}
+
+fn shift(pos: BytePos, delta: i64) -> BytePos {
+    BytePos((i64::from(pos.0) + delta) as u32)
+}
+
+struct ShiftSpans {
+    delta: i64,
+}
+
+impl Fold<Span> for ShiftSpans {
+    fn fold(&mut self, span: Span) -> Span {
+        if span.is_dummy() {
+            return span;
+        }
+
+        Span::new(
+            shift(span.lo(), self.delta),
+            shift(span.hi(), self.delta),
+            span.ctxt(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::FileName;
+
+    fn check(src: &str, lo: u32, hi: u32, insert: &str) {
+        let new_src = format!("{}{}{}", &src[..lo as usize], insert, &src[hi as usize..]);
+
+        ::testing::run_test(false, |cm, handler| {
+            let session = Session { handler: &handler };
+            let syntax = Syntax::Es(Default::default());
+
+            let old_fm = cm.new_source_file(FileName::Real("old.js".into()), src.into());
+            let new_fm = cm.new_source_file(FileName::Real("new.js".into()), new_src.clone());
+
+            let parse = |fm: &SourceFile, comments: &Comments| {
+                Parser::new(session, syntax, SourceFileInput::from(fm), Some(comments))
+                    .parse_module()
+                    .map_err(|mut e| {
+                        e.emit();
+                        ()
+                    })
+            };
+
+            let comments = Comments::default();
+            let old = parse(&*old_fm, &comments)?;
+            let expected_comments = Comments::default();
+            let expected = parse(&*new_fm, &expected_comments)?;
+
+            let edit = TextEdit {
+                span: Span::new(
+                    old_fm.start_pos + BytePos(lo),
+                    old_fm.start_pos + BytePos(hi),
+                    Default::default(),
+                ),
+                new_len: insert.len() as u32,
+            };
+            let actual = Parser::reparse_module(
+                session,
+                syntax,
+                Some(&comments),
+                old,
+                &old_fm,
+                &new_fm,
+                edit,
+            )
+            .map_err(|mut e| {
+                e.emit();
+                ()
+            })?;
+
+            assert_eq!(actual, expected);
+            for pos in new_fm.start_pos.0..=new_fm.end_pos.0 {
+                let pos = BytePos(pos);
+                assert_eq!(
+                    comments.leading_comments(pos).map(|c| (*c).clone()),
+                    expected_comments
+                        .leading_comments(pos)
+                        .map(|c| (*c).clone()),
+                    "leading comments at {:?}",
+                    pos
+                );
+                assert_eq!(
+                    comments.trailing_comments(pos).map(|c| (*c).clone()),
+                    expected_comments
+                        .trailing_comments(pos)
+                        .map(|c| (*c).clone()),
+                    "trailing comments at {:?}",
+                    pos
+                );
+            }
+
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn insert_in_middle() {
+        check("var a = 1;\nfoo(a);\nbar();\nbaz();", 15, 15, "a, ");
+    }
+
+    #[test]
+    fn remove_stmt() {
+        check("var a = 1;\nfoo(a);\nbar();\nbaz();", 18, 25, "");
+    }
+
+    #[test]
+    fn insert_stmt() {
+        check("var a = 1;\nfoo(a);\nbar();", 18, 18, "if (a) { b() }\n");
+    }
+
+    #[test]
+    fn asi_with_previous() {
+        check("var a = 1;\nfoo();\nb\nc();", 20, 20, "+ d\n");
+    }
+
+    #[test]
+    fn edit_at_end() {
+        check("var a = 1;\nfoo(a);\nbar();", 25, 25, "\nbaz();");
+    }
+
+    #[test]
+    fn edit_first_stmt() {
+        check("var a = 1;\nfoo(a);", 8, 9, "2");
+    }
+
+    #[test]
+    fn comments_of_reused_items() {
+        check(
+            "// a\nvar a = 1;\nvar b = 2; // b\n/* c */ foo(a);\nbar(); // d\n/* e */ baz();",
+            45,
+            45,
+            ", b",
+        );
+    }
+}
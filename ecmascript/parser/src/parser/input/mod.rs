@@ -6,6 +6,7 @@ use crate::{
 };
 use lexer::TokenContexts;
 use std::mem;
+use swc_atoms::JsWord;
 use swc_common::{BytePos, Span, DUMMY_SP};
 
 pub trait Tokens: Clone + Iterator<Item = TokenAndSpan> {
@@ -17,6 +18,11 @@ pub trait Tokens: Clone + Iterator<Item = TokenAndSpan> {
     fn token_context(&self) -> &lexer::TokenContexts;
     fn token_context_mut(&mut self) -> &mut lexer::TokenContexts;
     fn set_token_context(&mut self, _c: lexer::TokenContexts);
+
+    /// Returns the source text of `span`, if the source is available.
+    fn raw(&mut self, _span: Span) -> Option<JsWord> {
+        None
+    }
 }
 
 #[derive(Clone)]
@@ -133,6 +139,10 @@ impl<I: Tokens> Tokens for Capturing<I> {
     fn set_token_context(&mut self, c: TokenContexts) {
         self.inner.set_token_context(c)
     }
+
+    fn raw(&mut self, span: Span) -> Option<JsWord> {
+        self.inner.raw(span)
+    }
 }
 
 /// This struct is responsible for managing current token and peeked token.
@@ -175,6 +185,34 @@ impl<I: Tokens> Buffer<I> {
         prev.map(|it| it.token)
     }
 
+    /// Skips tokens until the `}` matching the current token, which should be
+    /// `{`.
+    ///
+    /// Returns false on eof.
+    pub fn skip_braces(&mut self) -> bool {
+        debug_assert!(self.is(&tok!('{')));
+
+        let mut depth = 0usize;
+        loop {
+            match self.cur() {
+                Some(&tok!('{')) | Some(&tok!("${")) => depth += 1,
+                Some(&tok!('}')) => depth -= 1,
+                Some(_) => {}
+                None => return false,
+            }
+            self.bump();
+
+            if depth == 0 {
+                return true;
+            }
+        }
+    }
+
+    /// Source text of `span`.
+    pub fn raw(&mut self, span: Span) -> Option<JsWord> {
+        self.iter.raw(span)
+    }
+
     pub fn cur_debug(&self) -> Option<&Token> {
         self.cur.as_ref().map(|it| &it.token)
     }
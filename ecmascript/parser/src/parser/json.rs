@@ -0,0 +1,376 @@
+//! Strict JSON mode.
+//!
+//! Tokens are produced by the ecmascript lexer, and the source text of tokens
+//! and the text between them is checked against the JSON grammar.
+use super::*;
+use swc_common::Spanned;
+
+#[parser]
+impl<'a, I: Input> Parser<'a, Lexer<'a, I>> {
+    /// Parses a JSON document.
+    ///
+    /// Comments, trailing commas, single-quoted strings and other extensions
+    /// of ecmascript are rejected. Negative numbers are returned as an unary
+    /// minus expression.
+    pub fn parse_json(&mut self) -> PResult<'a, Box<Expr>> {
+        let start = self.input().cur_pos();
+        let first = cur_pos!();
+        self.check_json_gap(start, first)?;
+
+        let value = self.parse_json_value()?;
+
+        if self.input.cur().is_some() {
+            unexpected!()
+        }
+        let last = self.input.last_pos();
+        let end = self.input().cur_pos();
+        self.check_json_gap(last, end)?;
+
+        Ok(value)
+    }
+
+    fn parse_json_value(&mut self) -> PResult<'a, Box<Expr>> {
+        self.nested(|p| p.parse_json_value_inner())
+    }
+
+    fn parse_json_value_inner(&mut self) -> PResult<'a, Box<Expr>> {
+        self.check_json_gap_before_cur()?;
+        let start = cur_pos!();
+
+        match *cur!(true)? {
+            tok!('[') => {
+                bump!();
+
+                let mut elems = vec![];
+                self.check_json_gap_before_cur()?;
+                if !eat!(']') {
+                    loop {
+                        elems.push(Some(ExprOrSpread {
+                            spread: None,
+                            expr: self.parse_json_value()?,
+                        }));
+
+                        self.check_json_gap_before_cur()?;
+                        if eat!(']') {
+                            break;
+                        }
+                        expect!(',');
+                        self.check_json_gap_before_cur()?;
+                        if is!(']') {
+                            syntax_error!(self.input.prev_span(), SyntaxError::JsonTrailingComma)
+                        }
+                    }
+                }
+
+                Ok(Box::new(Expr::Array(ArrayLit {
+                    span: span!(start),
+                    elems,
+                })))
+            }
+
+            tok!('{') => {
+                bump!();
+
+                let mut props = vec![];
+                self.check_json_gap_before_cur()?;
+                if !eat!('}') {
+                    loop {
+                        let key = match *cur!(true)? {
+                            Token::Str { .. } => match self.parse_json_str()? {
+                                Lit::Str(s) => s,
+                                _ => unreachable!(),
+                            },
+                            _ => unexpected!(),
+                        };
+                        self.check_json_gap_before_cur()?;
+                        expect!(':');
+                        let value = self.parse_json_value()?;
+
+                        props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
+                            KeyValueProp {
+                                key: PropName::Str(key),
+                                value,
+                            },
+                        ))));
+
+                        self.check_json_gap_before_cur()?;
+                        if eat!('}') {
+                            break;
+                        }
+                        expect!(',');
+                        self.check_json_gap_before_cur()?;
+                        if is!('}') {
+                            syntax_error!(self.input.prev_span(), SyntaxError::JsonTrailingComma)
+                        }
+                    }
+                }
+
+                Ok(Box::new(Expr::Object(ObjectLit {
+                    span: span!(start),
+                    props,
+                })))
+            }
+
+            tok!('-') => {
+                bump!();
+
+                // `-` should be followed by a number without any whitespace.
+                if cur_pos!() != self.input.last_pos() {
+                    syntax_error!(self.input.cur_span(), SyntaxError::JsonInvalidNum)
+                }
+                let arg = match *cur!(true)? {
+                    Token::Num(..) => self.parse_json_num()?,
+                    _ => unexpected!(),
+                };
+
+                Ok(Box::new(Expr::Unary(UnaryExpr {
+                    span: span!(start),
+                    op: op!(unary, "-"),
+                    arg: Box::new(Expr::Lit(arg)),
+                })))
+            }
+
+            Token::Num(..) => Ok(Box::new(Expr::Lit(self.parse_json_num()?))),
+            Token::Str { .. } => Ok(Box::new(Expr::Lit(self.parse_json_str()?))),
+            tok!("null") | tok!("true") | tok!("false") => {
+                Ok(Box::new(Expr::Lit(self.parse_lit()?)))
+            }
+
+            _ => unexpected!(),
+        }
+    }
+
+    fn parse_json_str(&mut self) -> PResult<'a, Lit> {
+        let lit = self.parse_lit()?;
+        let valid = match lit {
+            Lit::Str(Str { raw: Some(ref raw), .. }) => is_json_str(raw),
+            _ => true,
+        };
+        if !valid {
+            syntax_error!(lit.span(), SyntaxError::JsonInvalidStr)
+        }
+
+        Ok(lit)
+    }
+
+    fn parse_json_num(&mut self) -> PResult<'a, Lit> {
+        let lit = self.parse_lit()?;
+        let valid = match lit {
+            Lit::Num(Number { raw: Some(ref raw), .. }) => is_json_num(raw),
+            _ => true,
+        };
+        if !valid {
+            syntax_error!(lit.span(), SyntaxError::JsonInvalidNum)
+        }
+
+        Ok(lit)
+    }
+
+    fn check_json_gap_before_cur(&mut self) -> PResult<'a, ()> {
+        // The gap before the first token is checked by `parse_json`.
+        if self.input.prev_span().is_dummy() {
+            return Ok(());
+        }
+
+        let lo = self.input.last_pos();
+        let hi = cur_pos!();
+        self.check_json_gap(lo, hi)
+    }
+
+    /// Ensures that only JSON whitespace is between `lo` and `hi`.
+    fn check_json_gap(&mut self, lo: BytePos, hi: BytePos) -> PResult<'a, ()> {
+        if lo >= hi {
+            return Ok(());
+        }
+
+        let span = Span::new(lo, hi, Default::default());
+        let gap = match self.input.raw(span) {
+            Some(gap) => gap,
+            None => return Ok(()),
+        };
+        if gap.contains("//") || gap.contains("/*") {
+            syntax_error!(span, SyntaxError::JsonComment)
+        }
+        if !gap.chars().all(|c| c == ' ' || c == '\t' || c == '\n' || c == '\r') {
+            syntax_error!(span, SyntaxError::JsonWhitespace)
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks the source text of a string literal.
+fn is_json_str(raw: &str) -> bool {
+    if raw.len() < 2 || !raw.starts_with('"') || !raw.ends_with('"') {
+        return false;
+    }
+
+    let mut chars = raw[1..raw.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('"') | Some('\\') | Some('/') | Some('b') | Some('f') | Some('n')
+                | Some('r') | Some('t') => {}
+                Some('u') => {
+                    for _ in 0..4 {
+                        match chars.next() {
+                            Some(c) if c.is_ascii_hexdigit() => {}
+                            _ => return false,
+                        }
+                    }
+                }
+                _ => return false,
+            },
+            '\u{0}'..='\u{1f}' => return false,
+            _ => {}
+        }
+    }
+
+    true
+}
+
+/// Checks the source text of a numeric literal.
+fn is_json_num(raw: &str) -> bool {
+    let bytes = raw.as_bytes();
+    let mut i = 0;
+
+    let digits = |i: &mut usize| {
+        let start = *i;
+        while *i < bytes.len() && bytes[*i].is_ascii_digit() {
+            *i += 1;
+        }
+        *i - start
+    };
+
+    // Integer part without leading zeros
+    match bytes.first() {
+        Some(b'0') => i += 1,
+        Some(b'1'..=b'9') => {
+            digits(&mut i);
+        }
+        _ => return false,
+    }
+
+    if bytes.get(i) == Some(&b'.') {
+        i += 1;
+        if digits(&mut i) == 0 {
+            return false;
+        }
+    }
+
+    if bytes.get(i) == Some(&b'e') || bytes.get(i) == Some(&b'E') {
+        i += 1;
+        if bytes.get(i) == Some(&b'+') || bytes.get(i) == Some(&b'-') {
+            i += 1;
+        }
+        if digits(&mut i) == 0 {
+            return false;
+        }
+    }
+
+    i == bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::FileName;
+
+    fn json(src: &'static str) -> Result<Box<Expr>, ::testing::StdErr> {
+        ::testing::run_test(false, |cm, handler| {
+            let session = Session { handler: &handler };
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+
+            Parser::new(session, Syntax::default(), (&*fm).into(), None)
+                .parse_json()
+                .map_err(|mut e| {
+                    e.emit();
+                })
+        })
+    }
+
+    fn err(src: &'static str, msg: &str) {
+        let err = json(src).expect_err("should fail");
+        assert!(err.contains(msg), "{}", err);
+    }
+
+    #[test]
+    fn valid() {
+        json(r#"{"a": [1, -2.5e+3, 0.5, "b\né", true, false, null], "c": {}}"#).unwrap();
+        json(" \r\n\t[]\n").unwrap();
+        json("\"\"").unwrap();
+    }
+
+    #[test]
+    fn values() {
+        let props = match *json(r#"{"a": -1}"#).unwrap() {
+            Expr::Object(ObjectLit { props, .. }) => props,
+            v => panic!("expected an object, got {:?}", v),
+        };
+        assert_eq!(props.len(), 1);
+
+        match props[0] {
+            PropOrSpread::Prop(ref prop) => match **prop {
+                Prop::KeyValue(KeyValueProp {
+                    key: PropName::Str(ref key),
+                    ref value,
+                }) => {
+                    assert_eq!(&*key.value, "a");
+                    match **value {
+                        Expr::Unary(UnaryExpr {
+                            op: op!(unary, "-"),
+                            ..
+                        }) => {}
+                        ref v => panic!("expected a negative number, got {:?}", v),
+                    }
+                }
+                ref p => panic!("expected a key-value property, got {:?}", p),
+            },
+            ref p => panic!("expected a property, got {:?}", p),
+        }
+    }
+
+    #[test]
+    fn comments() {
+        err("// a\n{}", "Comments are not allowed in JSON");
+        err("[1, /* a */ 2]", "Comments are not allowed in JSON");
+        err("{} // a", "Comments are not allowed in JSON");
+    }
+
+    #[test]
+    fn trailing_commas() {
+        err("[1, 2,]", "Trailing commas are not allowed in JSON");
+        err(r#"{"a": 1,}"#, "Trailing commas are not allowed in JSON");
+        json("[1, , 2]").expect_err("should fail");
+    }
+
+    #[test]
+    fn keys() {
+        json("{a: 1}").expect_err("should fail");
+        json("{'a': 1}").expect_err("should fail");
+        json("{1: 1}").expect_err("should fail");
+    }
+
+    #[test]
+    fn strings() {
+        err("'a'", "JSON strings must be double-quoted");
+        err(r#""\x41""#, "JSON strings must be double-quoted");
+        err(r#""\u{41}""#, "JSON strings must be double-quoted");
+    }
+
+    #[test]
+    fn numbers() {
+        for src in &["01", ".5", "5.", "0x10", "1e", "+1", "- 1", "Infinity", "NaN"] {
+            json(src).expect_err(src);
+        }
+    }
+
+    #[test]
+    fn not_json() {
+        json("1 2").expect_err("should fail");
+        json("undefined").expect_err("should fail");
+        json("`a`").expect_err("should fail");
+        json("\u{a0}1").expect_err("should fail");
+        json("").expect_err("should fail");
+    }
+}
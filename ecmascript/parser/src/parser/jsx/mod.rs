@@ -281,10 +281,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
                                 break 'contents;
                             }
 
-                            children.push(p.parse_jsx_element_at(start).map(|e| match e {
+                            let element = p.nested(|p| p.parse_jsx_element_at(start))?;
+                            children.push(match element {
                                 Either::Left(e) => JSXElementChild::from(e),
                                 Either::Right(e) => JSXElementChild::from(Box::new(e)),
-                            })?);
+                            });
                         }
                         Token::JSXText { .. } => {
                             children.push(p.parse_jsx_text().map(JSXElementChild::from)?)
@@ -78,6 +78,7 @@ fn escape_in_attr() {
                         span,
                         value: "w < w".into(),
                         has_escape: false,
+                        raw: Some("\"w &lt; w\"".into()),
                     }))),
                 })],
                 name: JSXElementName::Ident(Ident::new("div".into(), span)),
@@ -0,0 +1,214 @@
+//! Parsing of function bodies skipped by [Parser::with_lazy_bodies].
+use super::*;
+use crate::SourceFileInput;
+use swc_common::SourceFile;
+
+impl<'a> Parser<'a, Lexer<'a, SourceFileInput<'a>>> {
+    /// Parses a body skipped by a parser in lazy mode.
+    ///
+    /// `fm` should be the file which contained `body` and `syntax` should be
+    /// the syntax used to parse it. Functions nested in `body` are parsed
+    /// eagerly.
+    pub fn parse_lazy_body(
+        session: Session<'a>,
+        syntax: Syntax,
+        comments: Option<&'a Comments>,
+        fm: &'a SourceFile,
+        body: &LazyBody,
+    ) -> PResult<'a, BlockStmt> {
+        let mut input = SourceFileInput::from(fm);
+        input.reset_to(body.span.lo());
+
+        let mut p = Parser::new(session, syntax, input, comments);
+        let ctx = Context {
+            module: body.in_module,
+            strict: body.strict,
+            in_async: body.is_async,
+            in_generator: body.is_generator,
+            in_function: true,
+            in_method: body.in_method,
+            in_class_prop: body.in_class_prop,
+            ..p.ctx()
+        };
+        p.set_ctx(ctx);
+
+        p.parse_block(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EsConfig;
+    use swc_common::FileName;
+
+    fn parse(src: &str, lazy: bool) -> Result<Vec<Option<BlockStmt>>, ::testing::StdErr> {
+        ::testing::run_test(false, |cm, handler| {
+            let session = Session { handler: &handler };
+            let syntax = Syntax::default();
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+
+            let m = Parser::new(session, syntax, SourceFileInput::from(&*fm), None)
+                .with_lazy_bodies(lazy)
+                .parse_module()
+                .map_err(|mut e| {
+                    e.emit();
+                })?;
+
+            m.body
+                .into_iter()
+                .filter_map(|item| match item {
+                    ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { function, .. }))) => {
+                        Some(function.body)
+                    }
+                    _ => None,
+                })
+                .map(|body| match body {
+                    Some(BlockStmt { ref stmts, .. }) if lazy => {
+                        let lazy = match stmts[..] {
+                            [Stmt::Lazy(ref lazy)] => lazy,
+                            _ => panic!("expected a lazy body, got {:?}", stmts),
+                        };
+                        Parser::parse_lazy_body(session, syntax, None, &fm, lazy)
+                            .map(Some)
+                            .map_err(|mut e| {
+                                e.emit();
+                            })
+                    }
+                    _ => Ok(body),
+                })
+                .collect()
+        })
+    }
+
+    fn check(src: &str) {
+        assert_eq!(parse(src, true).unwrap(), parse(src, false).unwrap());
+    }
+
+    #[test]
+    fn simple() {
+        check("import a from 'a'; function foo(x) { if (x) { return { a: `${x}` }; } }");
+    }
+
+    #[test]
+    fn braces_in_regex_and_templates() {
+        check("function foo() { return /}/.test(`${{}}}` + '}'); } function bar() {}");
+    }
+
+    #[test]
+    fn async_and_generator() {
+        check("async function foo() { await a; } function* bar() { yield 1; }");
+    }
+
+    fn arrow_body(e: &Expr) -> BlockStmt {
+        match *e {
+            Expr::Arrow(ArrowExpr {
+                body: BlockStmtOrExpr::BlockStmt(ref body),
+                ..
+            }) => body.clone(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn methods_and_arrows() {
+        let src = "class A extends B {
+    constructor() { super(); this.a = 1; }
+    get b() { return super.x(); }
+    c = () => { super.y(); };
+}
+const f = async () => { await foo({}); };
+const o = { m() { return () => { super.z(); }; } };";
+
+        let bodies = |lazy: bool| {
+            ::testing::run_test(false, |cm, handler| {
+                let session = Session { handler: &handler };
+                let syntax = Syntax::Es(EsConfig {
+                    class_props: true,
+                    ..Default::default()
+                });
+                let fm = cm.new_source_file(FileName::Anon, src.into());
+
+                let m = Parser::new(session, syntax, (&*fm).into(), None)
+                    .with_lazy_bodies(lazy)
+                    .parse_module()
+                    .map_err(|mut e| {
+                        e.emit();
+                    })?;
+
+                let mut bodies = vec![];
+                match m.body[0] {
+                    ModuleItem::Stmt(Stmt::Decl(Decl::Class(ClassDecl { ref class, .. }))) => {
+                        for member in &class.body {
+                            match *member {
+                                ClassMember::Constructor(Constructor { ref body, .. }) => {
+                                    bodies.push(body.clone().unwrap())
+                                }
+                                ClassMember::Method(ClassMethod { ref function, .. }) => {
+                                    bodies.push(function.body.clone().unwrap())
+                                }
+                                ClassMember::ClassProp(ClassProp {
+                                    value: Some(ref value),
+                                    ..
+                                }) => bodies.push(arrow_body(value)),
+                                _ => unreachable!(),
+                            }
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+                for item in &m.body[1..] {
+                    match *item {
+                        ModuleItem::Stmt(Stmt::Decl(Decl::Var(ref var))) => {
+                            match var.decls[0].init.as_ref().map(|e| &**e) {
+                                Some(&Expr::Object(ObjectLit { ref props, .. })) => {
+                                    match props[0] {
+                                        PropOrSpread::Prop(ref prop) => match **prop {
+                                            Prop::Method(ref method) => {
+                                                bodies.push(method.function.body.clone().unwrap())
+                                            }
+                                            _ => unreachable!(),
+                                        },
+                                        _ => unreachable!(),
+                                    }
+                                }
+                                Some(e) => bodies.push(arrow_body(e)),
+                                None => unreachable!(),
+                            }
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+
+                if !lazy {
+                    return Ok(bodies);
+                }
+                bodies
+                    .into_iter()
+                    .map(|body| {
+                        let lazy = match body.stmts[..] {
+                            [Stmt::Lazy(ref lazy)] => lazy,
+                            _ => panic!("expected a lazy body, got {:?}", body.stmts),
+                        };
+                        Parser::parse_lazy_body(session, syntax, None, &fm, lazy).map_err(
+                            |mut e| {
+                                e.emit();
+                            },
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap()
+        };
+
+        let lazy = bodies(true);
+        assert_eq!(lazy.len(), 5);
+        assert_eq!(lazy, bodies(false));
+    }
+
+    #[test]
+    fn unterminated() {
+        let err = parse("function foo() { if (a) {", true).expect_err("should fail");
+        assert!(err.contains("Unexpected eof"), "{}", err);
+    }
+}
@@ -1,12 +1,14 @@
 #![allow(dead_code, unused_variables)]
 #![deny(non_snake_case)]
+#[cfg(feature = "fold")]
+pub use self::incremental::TextEdit;
 pub use self::input::{Capturing, Tokens, TokensInput};
 use self::{input::Buffer, util::ParseObject};
 use crate::{
     error::SyntaxError,
     parser_macros::parser,
     token::{Token, Word},
-    Context, Session, Syntax,
+    Context, DecoratorsVersion, EsVersion, PipelineProposal, Session, Syntax,
 };
 use ast::*;
 use lexer::Lexer;
@@ -17,12 +19,18 @@ use swc_common::{comments::Comments, errors::DiagnosticBuilder, input::Input, By
 #[macro_use]
 mod macros;
 mod class_and_fn;
+mod dts;
 mod expr;
 mod ident;
+#[cfg(feature = "fold")]
+mod incremental;
 pub mod input;
+mod json;
 mod jsx;
+mod lazy;
 mod object;
 mod pat;
+mod regexp;
 mod stmt;
 mod typescript;
 mod util;
@@ -30,12 +38,19 @@ mod util;
 /// When error occurs, error is emitted and parser returns Err(()).
 pub type PResult<'a, T> = Result<T, DiagnosticBuilder<'a>>;
 
+/// Default value of the maximum nesting depth of statements, expressions and
+/// types.
+pub const DEFAULT_MAX_DEPTH: usize = 512;
+
 /// EcmaScript parser.
 #[derive(Clone)]
 pub struct Parser<'a, I: Tokens> {
     session: Session<'a>,
     state: State,
     input: Buffer<I>,
+    max_depth: usize,
+    /// Skip function bodies?
+    lazy_bodies: bool,
 }
 
 #[derive(Clone, Default)]
@@ -43,6 +58,8 @@ struct State {
     labels: Vec<JsWord>,
     /// Start position of an assignment expression.
     potential_arrow_start: Option<BytePos>,
+    /// Current nesting depth. See [Parser::nested].
+    depth: usize,
 }
 
 impl<'a, I: Input> Parser<'a, Lexer<'a, I>> {
@@ -63,12 +80,38 @@ impl<'a, I: Tokens> Parser<'a, I> {
             session,
             input: Buffer::new(input),
             state: Default::default(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            lazy_bodies: false,
         }
     }
 
+    /// Sets the maximum nesting depth of statements, expressions and types.
+    ///
+    /// Every recursive production counts, including chains like `else if`,
+    /// `a ? b : c ? d : e`, `a = b = c` and `!!a`. Operators of the same
+    /// precedence like `a + b + c` are parsed in a loop and don't count.
+    ///
+    /// Parsing deeper input fails with a syntax error instead of overflowing
+    /// the stack.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Skips bodies of functions, methods and arrow functions.
+    ///
+    /// Skipped bodies are only lexed to find the matching brace, and are
+    /// stored as [LazyBody]. They can be parsed later with
+    /// [Parser::parse_lazy_body].
+    pub fn with_lazy_bodies(mut self, lazy_bodies: bool) -> Self {
+        self.lazy_bodies = lazy_bodies;
+        self
+    }
+
     pub fn parse_script(&mut self) -> PResult<'a, Script> {
         let ctx = Context {
             module: false,
+            in_async: self.input.syntax().allow_await_outside_function(),
             ..self.ctx()
         };
         self.set_ctx(ctx);
@@ -77,7 +120,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         let shebang = self.parse_shebang()?;
 
-        self.parse_block_body(true, true, None).map(|body| Script {
+        let body = self.parse_block_body(true, true, None)?;
+
+        Ok(Script {
             span: span!(start),
             body,
             shebang,
@@ -89,6 +134,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let ctx = Context {
             module: true,
             strict: true,
+            in_async: self.input.syntax().allow_await_outside_function(),
             ..self.ctx()
         };
         // Module code is always in strict mode
@@ -97,7 +143,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let start = cur_pos!();
         let shebang = self.parse_shebang()?;
 
-        self.parse_block_body(true, true, None).map(|body| Module {
+        let body = self.parse_block_body(true, true, None)?;
+
+        Ok(Module {
             span: span!(start),
             body,
             shebang,
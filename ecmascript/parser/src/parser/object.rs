@@ -46,18 +46,26 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             let v = match *cur!(true)? {
                 Token::Str { .. } => match bump!() {
-                    Token::Str { value, has_escape } => PropName::Str(Str {
-                        span: span!(start),
-                        value,
-                        has_escape,
-                    }),
+                    Token::Str { value, has_escape } => {
+                        let span = span!(start);
+                        PropName::Str(Str {
+                            span,
+                            value,
+                            has_escape,
+                            raw: p.input.raw(span),
+                        })
+                    }
                     _ => unreachable!(),
                 },
                 Token::Num(_) => match bump!() {
-                    Token::Num(value) => PropName::Num(Number {
-                        span: span!(start),
-                        value,
-                    }),
+                    Token::Num(value) => {
+                        let span = span!(start);
+                        PropName::Num(Number {
+                            span,
+                            value,
+                            raw: p.input.raw(span),
+                        })
+                    }
                     _ => unreachable!(),
                 },
                 Word(..) => match bump!() {
@@ -71,6 +79,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                         .parse_assignment_expr()
                         .map(PropName::Computed)?;
                     expect!(']');
+                    p.ensure_es_version(span!(start), EsVersion::Es2015, "computed property name")?;
                     expr
                 }
                 _ => unexpected!(),
@@ -97,6 +106,7 @@ impl<'a, I: Tokens> ParseObject<'a, (Box<Expr>)> for Parser<'a, I> {
         if eat!("...") {
             // spread elemnent
             let dot3_token = span!(start);
+            self.ensure_es_version(dot3_token, EsVersion::Es2018, "object rest/spread")?;
 
             let expr = self.include_in_expr(true).parse_assignment_expr()?;
 
@@ -105,10 +115,11 @@ impl<'a, I: Tokens> ParseObject<'a, (Box<Expr>)> for Parser<'a, I> {
 
         if eat!('*') {
             let span_of_gen = span!(start);
+            self.ensure_es_version(span_of_gen, EsVersion::Es2015, "generator")?;
 
             let name = self.parse_prop_name()?;
             return self
-                .parse_fn_args_body(
+                .parse_method_args_body(
                     // no decorator in an object literal
                     vec![],
                     start,
@@ -139,9 +150,10 @@ impl<'a, I: Tokens> ParseObject<'a, (Box<Expr>)> for Parser<'a, I> {
         }
 
         // Handle `a(){}` (and async(){} / get(){} / set(){})
-        if (self.input.syntax().typescript() && is!('<')) || is!('(') {
+        if (self.input.syntax().types() && is!('<')) || is!('(') {
+            self.ensure_es_version(span!(start), EsVersion::Es2015, "method definition")?;
             return self
-                .parse_fn_args_body(
+                .parse_method_args_body(
                     // no decorator in an object literal
                     vec![],
                     start,
@@ -165,6 +177,7 @@ impl<'a, I: Tokens> ParseObject<'a, (Box<Expr>)> for Parser<'a, I> {
             if is_reserved_word {
                 syntax_error!(ident.span, SyntaxError::ReservedWordInObjShorthandOrPat);
             }
+            self.ensure_es_version(ident.span, EsVersion::Es2015, "shorthand property")?;
 
             if eat!('=') {
                 let value = self.include_in_expr(true).parse_assignment_expr()?;
@@ -182,11 +195,19 @@ impl<'a, I: Tokens> ParseObject<'a, (Box<Expr>)> for Parser<'a, I> {
 
         match ident.sym {
             js_word!("get") | js_word!("set") | js_word!("async") => {
+                let is_generator = ident.sym == js_word!("async") && eat!('*');
+                if is_generator {
+                    self.ensure_es_version(span!(start), EsVersion::Es2018, "async generator")?;
+                } else if ident.sym == js_word!("async") {
+                    self.ensure_es_version(ident.span, EsVersion::Es2017, "async function")?;
+                } else {
+                    self.ensure_es_version(ident.span, EsVersion::Es5, "getter/setter")?;
+                }
                 let key = self.parse_prop_name()?;
 
                 return match ident.sym {
                     js_word!("get") => self
-                        .parse_fn_args_body(
+                        .parse_method_args_body(
                             // no decorator in an object literal
                             vec![],
                             start,
@@ -202,7 +223,7 @@ impl<'a, I: Tokens> ParseObject<'a, (Box<Expr>)> for Parser<'a, I> {
                             })))
                         }),
                     js_word!("set") => self
-                        .parse_fn_args_body(
+                        .parse_method_args_body(
                             // no decorator in an object literal
                             vec![],
                             start,
@@ -220,13 +241,13 @@ impl<'a, I: Tokens> ParseObject<'a, (Box<Expr>)> for Parser<'a, I> {
                             })))
                         }),
                     js_word!("async") => self
-                        .parse_fn_args_body(
+                        .parse_method_args_body(
                             // no decorator in an object literal
                             vec![],
                             start,
                             Parser::parse_unique_formal_params,
                             true,
-                            false,
+                            is_generator,
                         )
                         .map(|function| {
                             PropOrSpread::Prop(Box::new(Prop::Method(MethodProp { key, function })))
@@ -277,6 +298,7 @@ impl<'a, I: Tokens> ParseObject<'a, Pat> for Parser<'a, I> {
         if eat!("...") {
             // spread elemnent
             let dot3_token = span!(start);
+            self.ensure_es_version(dot3_token, EsVersion::Es2018, "object rest/spread")?;
 
             let arg = Box::new(self.parse_binding_pat_or_ident()?);
 
@@ -7,7 +7,7 @@ use swc_common::Spanned;
 #[parser]
 impl<'a, I: Tokens> Parser<'a, I> {
     pub(super) fn parse_opt_binding_ident(&mut self) -> PResult<'a, (Option<Ident>)> {
-        if is!(BindingIdent) || (self.input.syntax().typescript() && is!("this")) {
+        if is!(BindingIdent) || (self.input.syntax().types() && is!("this")) {
             self.parse_binding_ident().map(Some)
         } else {
             Ok(None)
@@ -36,10 +36,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     pub(super) fn parse_binding_pat_or_ident(&mut self) -> PResult<'a, Pat> {
+        if is_one_of!('[', '{') {
+            self.ensure_es_version(self.input.cur_span(), EsVersion::Es2015, "destructuring")?;
+        }
+
         match *cur!(true)? {
             tok!("yield") | Word(..) => self.parse_binding_ident().map(Pat::from),
-            tok!('[') => self.parse_array_binding_pat(),
-            tok!('{') => self.parse_object(),
+            tok!('[') => self.nested(|p| p.parse_array_binding_pat()),
+            tok!('{') => self.nested(|p| p.parse_object()),
             // tok!('(') => {
             //     bump!();
             //     let pat = self.parse_binding_pat_or_ident()?;
@@ -56,6 +60,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let left = self.parse_binding_pat_or_ident()?;
 
         if eat!('=') {
+            self.ensure_es_version(self.input.prev_span(), EsVersion::Es2015, "default value")?;
             let right = self.include_in_expr(true).parse_assignment_expr()?;
             return Ok(Pat::Assign(AssignPat {
                 span: span!(start),
@@ -91,6 +96,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             if eat!("...") {
                 let dot3_token = span!(start);
+                self.ensure_es_version(dot3_token, EsVersion::Es2015, "rest element")?;
 
                 let pat = self.parse_binding_pat_or_ident()?;
                 let pat = Pat::Rest(RestPat {
@@ -122,7 +128,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let start = cur_pos!();
 
         let mut pat = self.parse_binding_element()?;
-        if self.input.syntax().typescript() {
+        if self.input.syntax().types() {
             if eat!('?') {
                 match pat {
                     Pat::Ident(Ident {
@@ -159,6 +165,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
         if eat!('=') {
+            self.ensure_es_version(self.input.prev_span(), EsVersion::Es2015, "default value")?;
             let right = self.parse_assignment_expr()?;
             Ok(Pat::Assign(AssignPat {
                 span: span!(start),
@@ -190,9 +197,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             if eat!("...") {
                 let dot3_token = span!(start);
+                self.ensure_es_version(dot3_token, EsVersion::Es2015, "rest element")?;
 
                 let pat = self.parse_binding_pat_or_ident()?;
-                let type_ann = if self.input.syntax().typescript() && is!(':') {
+                let type_ann = if self.input.syntax().types() && is!(':') {
                     let cur_pos = cur_pos!();
                     Some(self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?)
                 } else {
@@ -263,9 +271,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             if eat!("...") {
                 let dot3_token = span!(start);
+                self.ensure_es_version(dot3_token, EsVersion::Es2015, "rest element")?;
 
                 let pat = self.parse_binding_pat_or_ident()?;
-                let type_ann = if self.input.syntax().typescript() && is!(':') {
+                let type_ann = if self.input.syntax().types() && is!(':') {
                     let cur_pos = cur_pos!();
                     Some(self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?)
                 } else {
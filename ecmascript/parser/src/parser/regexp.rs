@@ -0,0 +1,825 @@
+//! Validation of regular expression literals.
+//!
+//! See https://tc39.github.io/ecma262/#sec-patterns
+//!
+//! Without the `u` flag, the extensions from Annex B are allowed.
+use super::*;
+use crate::lexer::util::CharExt;
+
+impl<'a, I: Tokens> Parser<'a, I> {
+    /// Validates pattern and flags of a regular expression literal.
+    pub(super) fn validate_regex(&self, exp: &Str, flags: Option<&Str>) -> PResult<'a, ()> {
+        let mut unicode = false;
+
+        if let Some(flags) = flags {
+            let mut seen = String::new();
+            for (i, c) in flags.value.char_indices() {
+                let span = if flags.has_escape {
+                    // We can't map the index to source text.
+                    flags.span
+                } else {
+                    sub_span(flags.span, i, i + c.len_utf8())
+                };
+
+                match c {
+                    'g' | 'i' | 'm' | 's' | 'u' | 'y' => {}
+                    _ => syntax_error!(self, span, SyntaxError::InvalidRegexFlag { flag: c }),
+                }
+                if seen.contains(c) {
+                    syntax_error!(self, span, SyntaxError::DuplicateRegexFlag { flag: c })
+                }
+                seen.push(c);
+
+                match c {
+                    'u' | 'y' => self.ensure_es_version(
+                        span,
+                        EsVersion::Es2015,
+                        if c == 'u' {
+                            "regular expression flag `u`"
+                        } else {
+                            "regular expression flag `y`"
+                        },
+                    )?,
+                    's' => self.ensure_es_version(
+                        span,
+                        EsVersion::Es2018,
+                        "regular expression flag `s`",
+                    )?,
+                    _ => {}
+                }
+            }
+            unicode = seen.contains('u');
+        }
+
+        match Validator::new(&exp.value, unicode).validate() {
+            Ok(()) => Ok(()),
+            Err((start, end, err)) => syntax_error!(self, sub_span(exp.span, start, end), err),
+        }
+    }
+}
+
+/// Creates a span for `start..end` bytes of the text of `span`.
+fn sub_span(span: Span, start: usize, end: usize) -> Span {
+    Span::new(
+        span.lo() + BytePos(start as u32),
+        span.lo() + BytePos(end as u32),
+        span.ctxt(),
+    )
+}
+
+/// Byte range of the pattern and the error.
+type VResult<T> = Result<T, (usize, usize, SyntaxError)>;
+
+struct Validator<'s> {
+    src: &'s str,
+    pos: usize,
+    unicode: bool,
+    /// Number of capturing groups in the whole pattern.
+    group_count: u32,
+    /// Names of all named groups in the pattern.
+    group_names: Vec<&'s str>,
+    /// Names of named groups we've visited.
+    seen_names: Vec<&'s str>,
+}
+
+impl<'s> Validator<'s> {
+    fn new(src: &'s str, unicode: bool) -> Self {
+        let mut v = Validator {
+            src,
+            pos: 0,
+            unicode,
+            group_count: 0,
+            group_names: vec![],
+            seen_names: vec![],
+        };
+        v.scan_groups();
+        v
+    }
+
+    fn validate(mut self) -> VResult<()> {
+        self.disjunction()?;
+
+        match self.cur() {
+            None => Ok(()),
+            Some(')') => self.err(self.pos, self.pos + 1, SyntaxError::RegexUnmatchedParen),
+            Some(_) => unreachable!("disjunction stops only at `)`"),
+        }
+    }
+
+    /// Backreferences can refer to groups declared after them, so we count
+    /// groups before validating the pattern.
+    fn scan_groups(&mut self) {
+        let mut chars = self.src.char_indices();
+        let mut in_class = false;
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '(' if !in_class => {
+                    let rest = &self.src[i + 1..];
+                    if !rest.starts_with('?') {
+                        self.group_count += 1;
+                    } else if rest.starts_with("?<")
+                        && !rest.starts_with("?<=")
+                        && !rest.starts_with("?<!")
+                    {
+                        self.group_count += 1;
+                        if let Some(end) = rest.find('>') {
+                            self.group_names.push(&rest[2..end]);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn cur(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn peek(&self) -> Option<char> {
+        let mut chars = self.src[self.pos..].chars();
+        chars.next();
+        chars.next()
+    }
+
+    fn is(&self, s: &str) -> bool {
+        self.src[self.pos..].starts_with(s)
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.cur() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.cur() == Some(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn err<T>(&self, start: usize, end: usize, err: SyntaxError) -> VResult<T> {
+        Err((start, end, err))
+    }
+
+    /// Error for an escape sequence starting at `start`.
+    fn invalid_escape<T>(&self, start: usize) -> VResult<T> {
+        let end = self.pos + self.cur().map(|c| c.len_utf8()).unwrap_or(0);
+        self.err(start, end, SyntaxError::RegexInvalidEscape)
+    }
+
+    fn disjunction(&mut self) -> VResult<()> {
+        loop {
+            self.alternative()?;
+            if !self.eat('|') {
+                return Ok(());
+            }
+        }
+    }
+
+    fn alternative(&mut self) -> VResult<()> {
+        while let Some(c) = self.cur() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            self.term()?;
+        }
+
+        Ok(())
+    }
+
+    fn term(&mut self) -> VResult<()> {
+        let start = self.pos;
+
+        if self.eat('^') || self.eat('$') {
+            return Ok(());
+        }
+        if self.is("\\b") || self.is("\\B") {
+            self.pos += 2;
+            return Ok(());
+        }
+
+        if self.is("(?=") || self.is("(?!") {
+            self.pos += 3;
+            self.finish_group(start)?;
+            // Annex B allows quantified lookaheads.
+            if !self.unicode {
+                self.quantifier()?;
+            }
+            return Ok(());
+        }
+
+        if self.is("(?<=") || self.is("(?<!") {
+            self.pos += 4;
+            return self.finish_group(start);
+        }
+
+        self.atom()?;
+        self.quantifier()
+    }
+
+    fn atom(&mut self) -> VResult<()> {
+        let start = self.pos;
+
+        match self.cur() {
+            Some('(') => self.group(),
+            Some('[') => self.class(),
+            Some('\\') => self.atom_escape(),
+            Some('*') | Some('+') | Some('?') => {
+                self.err(start, start + 1, SyntaxError::RegexNothingToRepeat)
+            }
+            Some('{') => {
+                if self.unicode {
+                    return self.err(start, start + 1, SyntaxError::RegexLoneQuantifierBrackets);
+                }
+                if self.brace_quantifier().is_some() {
+                    return self.err(start, self.pos, SyntaxError::RegexNothingToRepeat);
+                }
+                self.bump();
+                Ok(())
+            }
+            Some('}') | Some(']') if self.unicode => {
+                self.err(start, start + 1, SyntaxError::RegexLoneQuantifierBrackets)
+            }
+            _ => {
+                self.bump();
+                Ok(())
+            }
+        }
+    }
+
+    /// `(`, `(?:` or `(?<name>`
+    fn group(&mut self) -> VResult<()> {
+        let start = self.pos;
+        self.bump();
+
+        if self.eat('?') {
+            if self.eat(':') {
+            } else if self.eat('<') {
+                let name_start = self.pos;
+                let name = self.group_name()?;
+                if self.seen_names.contains(&name) {
+                    return self.err(
+                        name_start,
+                        name_start + name.len(),
+                        SyntaxError::RegexDuplicateGroupName { name: name.into() },
+                    );
+                }
+                self.seen_names.push(name);
+            } else {
+                return self.invalid_group(start);
+            }
+        }
+
+        self.finish_group(start)
+    }
+
+    fn invalid_group<T>(&self, start: usize) -> VResult<T> {
+        let end = self.pos + self.cur().map(|c| c.len_utf8()).unwrap_or(0);
+        self.err(start, end, SyntaxError::RegexInvalidGroup)
+    }
+
+    /// Parses body of a group and closing `)`.
+    fn finish_group(&mut self, start: usize) -> VResult<()> {
+        self.disjunction()?;
+        if !self.eat(')') {
+            return self.err(start, start + 1, SyntaxError::RegexUnterminatedGroup);
+        }
+        Ok(())
+    }
+
+    /// Parses `name>`.
+    fn group_name(&mut self) -> VResult<&'s str> {
+        let start = self.pos;
+        let mut first = true;
+
+        while let Some(c) = self.cur() {
+            if c == '>' {
+                break;
+            }
+            let valid = if first {
+                c.is_ident_start()
+            } else {
+                c.is_ident_part()
+            };
+            if !valid {
+                break;
+            }
+            first = false;
+            self.bump();
+        }
+
+        let end = self.pos;
+        if start == end || !self.eat('>') {
+            let err_end = self.pos + self.cur().map(|c| c.len_utf8()).unwrap_or(0);
+            return self.err(start, err_end, SyntaxError::RegexInvalidGroupName);
+        }
+
+        Ok(&self.src[start..end])
+    }
+
+    fn quantifier(&mut self) -> VResult<()> {
+        let start = self.pos;
+
+        match self.cur() {
+            Some('*') | Some('+') | Some('?') => self.bump(),
+            Some('{') => match self.brace_quantifier() {
+                Some((min, Some(max))) if max < min => {
+                    return self.err(start, self.pos, SyntaxError::RegexQuantifierOutOfOrder);
+                }
+                Some(..) => {}
+                None => {
+                    if self.unicode {
+                        return self.err(start, start + 1, SyntaxError::RegexLoneQuantifierBrackets);
+                    }
+                    // `{` is a literal character.
+                    return Ok(());
+                }
+            },
+            _ => return Ok(()),
+        }
+
+        // Lazy
+        self.eat('?');
+
+        Ok(())
+    }
+
+    /// Parses `{n}`, `{n,}` or `{n,m}`. Position is not changed if the input
+    /// is not a quantifier.
+    fn brace_quantifier(&mut self) -> Option<(u64, Option<u64>)> {
+        let start = self.pos;
+
+        let res = (|| {
+            if !self.eat('{') {
+                return None;
+            }
+            let min = self.decimal()?;
+            let max = if self.eat(',') {
+                if self.cur() == Some('}') {
+                    None
+                } else {
+                    Some(self.decimal()?)
+                }
+            } else {
+                Some(min)
+            };
+            if !self.eat('}') {
+                return None;
+            }
+            Some((min, max))
+        })();
+
+        if res.is_none() {
+            self.pos = start;
+        }
+        res
+    }
+
+    fn decimal(&mut self) -> Option<u64> {
+        let start = self.pos;
+        let mut value: u64 = 0;
+        while let Some(d) = self.cur().and_then(|c| c.to_digit(10)) {
+            value = value.saturating_mul(10).saturating_add(u64::from(d));
+            self.bump();
+        }
+
+        if self.pos == start {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    fn class(&mut self) -> VResult<()> {
+        let start = self.pos;
+        self.bump();
+        self.eat('^');
+
+        loop {
+            match self.cur() {
+                None => return self.err(start, start + 1, SyntaxError::RegexUnterminatedCharClass),
+                Some(']') => {
+                    self.bump();
+                    return Ok(());
+                }
+                _ => {}
+            }
+
+            let atom_start = self.pos;
+            let left = self.class_atom()?;
+
+            if self.cur() == Some('-') && self.peek().is_some() && self.peek() != Some(']') {
+                self.bump();
+                let right = self.class_atom()?;
+
+                match (left, right) {
+                    (Some(l), Some(r)) => {
+                        if l > r {
+                            return self.err(
+                                atom_start,
+                                self.pos,
+                                SyntaxError::RegexRangeOutOfOrder,
+                            );
+                        }
+                    }
+                    _ => {
+                        // Annex B: `[\d-x]` matches `-` literally.
+                        if self.unicode {
+                            return self.err(
+                                atom_start,
+                                self.pos,
+                                SyntaxError::RegexInvalidCharClass,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns code point of the atom, or `None` for classes like `\d`.
+    fn class_atom(&mut self) -> VResult<Option<u32>> {
+        let c = match self.cur() {
+            Some('\\') => return self.class_escape(),
+            Some(c) => c,
+            None => unreachable!(),
+        };
+        self.bump();
+        Ok(Some(c as u32))
+    }
+
+    fn class_escape(&mut self) -> VResult<Option<u32>> {
+        let start = self.pos;
+        self.bump();
+
+        match self.cur() {
+            None => self.err(start, self.pos, SyntaxError::RegexEscapeAtEnd),
+            Some('b') => {
+                self.bump();
+                Ok(Some(8))
+            }
+            Some('-') if self.unicode => {
+                self.bump();
+                Ok(Some('-' as u32))
+            }
+            Some('d') | Some('D') | Some('s') | Some('S') | Some('w') | Some('W') => {
+                self.bump();
+                Ok(None)
+            }
+            Some('p') | Some('P') if self.unicode => {
+                self.property_escape()?;
+                Ok(None)
+            }
+            Some('1'..='9') => {
+                if self.unicode {
+                    return self.invalid_escape(start);
+                }
+                Ok(Some(self.legacy_octal()))
+            }
+            Some('c') if !self.unicode => {
+                // Annex B allows digits and `_` in a class.
+                match self.peek() {
+                    Some(c) if c.is_ascii_alphanumeric() || c == '_' => {
+                        self.bump();
+                        self.bump();
+                        Ok(Some(c as u32 % 32))
+                    }
+                    _ => Ok(Some('\\' as u32)),
+                }
+            }
+            Some(_) => self.char_escape(start).map(Some),
+        }
+    }
+
+    fn atom_escape(&mut self) -> VResult<()> {
+        let start = self.pos;
+        self.bump();
+
+        match self.cur() {
+            None => self.err(start, self.pos, SyntaxError::RegexEscapeAtEnd),
+            Some('1'..='9') => {
+                let value = self.decimal().unwrap_or(0);
+                if value > u64::from(self.group_count) {
+                    if self.unicode {
+                        return self.err(start, self.pos, SyntaxError::RegexInvalidEscape);
+                    }
+                    // Annex B: legacy octal escape or identity escape.
+                    self.pos = start + 1;
+                    self.legacy_octal();
+                }
+                Ok(())
+            }
+            Some('k') => {
+                self.bump();
+                if self.unicode || !self.group_names.is_empty() {
+                    if !self.eat('<') {
+                        return self.err(start, self.pos, SyntaxError::RegexInvalidNamedReference);
+                    }
+                    let name = self.group_name()?;
+                    if !self.group_names.contains(&name) {
+                        return self.err(start, self.pos, SyntaxError::RegexInvalidNamedReference);
+                    }
+                }
+                Ok(())
+            }
+            Some('d') | Some('D') | Some('s') | Some('S') | Some('w') | Some('W') => {
+                self.bump();
+                Ok(())
+            }
+            Some('p') | Some('P') if self.unicode => self.property_escape(),
+            Some('c') if !self.unicode => {
+                match self.peek() {
+                    Some(c) if c.is_ascii_alphabetic() => {
+                        self.bump();
+                        self.bump();
+                    }
+                    // Annex B: `\c` is matched literally.
+                    _ => {}
+                }
+                Ok(())
+            }
+            Some(_) => self.char_escape(start).map(|_| ()),
+        }
+    }
+
+    /// Parses an escape sequence which represents a character, and returns its
+    /// code point.
+    ///
+    /// Current character is the one after the backslash at `start`.
+    fn char_escape(&mut self, start: usize) -> VResult<u32> {
+        let c = match self.cur() {
+            Some(c) => c,
+            None => return self.err(start, self.pos, SyntaxError::RegexEscapeAtEnd),
+        };
+
+        let value = match c {
+            'f' => 0x0c,
+            'n' => 0x0a,
+            'r' => 0x0d,
+            't' => 0x09,
+            'v' => 0x0b,
+            'c' => match self.peek() {
+                Some(l) if l.is_ascii_alphabetic() => {
+                    self.bump();
+                    self.bump();
+                    return Ok(l as u32 % 32);
+                }
+                _ => return self.invalid_escape(start),
+            },
+            '0' => {
+                self.bump();
+                if self.cur().map(|c| c.is_digit(10)).unwrap_or(false) {
+                    if self.unicode {
+                        return self.invalid_escape(start);
+                    }
+                    self.pos = start + 1;
+                    return Ok(self.legacy_octal());
+                }
+                return Ok(0);
+            }
+            'x' => {
+                self.bump();
+                match self.hex(2) {
+                    Some(v) => return Ok(v),
+                    None if self.unicode => return self.invalid_escape(start),
+                    // Annex B: identity escape.
+                    None => return Ok('x' as u32),
+                }
+            }
+            'u' => {
+                self.bump();
+                return match self.unicode_escape() {
+                    Some(v) => Ok(v),
+                    None if self.unicode => self.invalid_escape(start),
+                    None => Ok('u' as u32),
+                };
+            }
+            '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}'
+            | '|' | '/' => c as u32,
+            _ => {
+                if self.unicode {
+                    return self.invalid_escape(start);
+                }
+                c as u32
+            }
+        };
+        self.bump();
+
+        Ok(value)
+    }
+
+    /// Parses `XXXX` or `{X...}` (only with the `u` flag) after `\u`.
+    fn unicode_escape(&mut self) -> Option<u32> {
+        let start = self.pos;
+
+        if self.unicode && self.eat('{') {
+            let mut value: u32 = 0;
+            let mut digits = 0;
+            while let Some(d) = self.cur().and_then(|c| c.to_digit(16)) {
+                value = value.saturating_mul(16).saturating_add(d);
+                digits += 1;
+                self.bump();
+            }
+            if digits == 0 || value > 0x10ffff || !self.eat('}') {
+                self.pos = start;
+                return None;
+            }
+            return Some(value);
+        }
+
+        let lead = self.hex(4)?;
+        if self.unicode && lead >= 0xd800 && lead <= 0xdbff && self.is("\\u") {
+            // Surrogate pair
+            let before_trail = self.pos;
+            self.pos += 2;
+            match self.hex(4) {
+                Some(trail) if trail >= 0xdc00 && trail <= 0xdfff => {
+                    return Some((lead - 0xd800) * 0x400 + (trail - 0xdc00) + 0x10000);
+                }
+                _ => self.pos = before_trail,
+            }
+        }
+
+        Some(lead)
+    }
+
+    /// Parses exactly `len` hex digits. Position is not changed on failure.
+    fn hex(&mut self, len: usize) -> Option<u32> {
+        let start = self.pos;
+        let mut value = 0;
+        for _ in 0..len {
+            match self.cur().and_then(|c| c.to_digit(16)) {
+                Some(d) => {
+                    value = value * 16 + d;
+                    self.bump();
+                }
+                None => {
+                    self.pos = start;
+                    return None;
+                }
+            }
+        }
+        Some(value)
+    }
+
+    /// Annex B: up to three octal digits. A non-octal digit is an identity
+    /// escape.
+    fn legacy_octal(&mut self) -> u32 {
+        let first = match self.cur().and_then(|c| c.to_digit(8)) {
+            Some(d) => d,
+            None => {
+                let c = self.cur().unwrap_or('\\');
+                self.bump();
+                return c as u32;
+            }
+        };
+        self.bump();
+
+        let mut value = first;
+        for i in 0..2 {
+            // `\377` is the largest one.
+            if i == 1 && first > 3 {
+                break;
+            }
+            match self.cur().and_then(|c| c.to_digit(8)) {
+                Some(d) => {
+                    value = value * 8 + d;
+                    self.bump();
+                }
+                None => break,
+            }
+        }
+        value
+    }
+
+    /// Parses `p{Name}` or `P{Name=Value}`.
+    fn property_escape(&mut self) -> VResult<()> {
+        let start = self.pos - 1;
+        self.bump();
+
+        if !self.eat('{') {
+            return self.invalid_escape(start);
+        }
+
+        let name_start = self.pos;
+        while let Some(c) = self.cur() {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '=' {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        if self.pos == name_start || !self.eat('}') {
+            return self.err(start, self.pos, SyntaxError::RegexInvalidPropertyName);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(src: &str, unicode: bool) -> Result<(), (usize, usize, SyntaxError)> {
+        Validator::new(src, unicode).validate()
+    }
+
+    fn err(src: &str, unicode: bool) -> (usize, usize, SyntaxError) {
+        check(src, unicode).expect_err("should fail")
+    }
+
+    #[test]
+    fn valid() {
+        for src in &[
+            "a|b",
+            "(a)(?:b)(?=c)(?!d)(?<=e)(?<!f)",
+            "a*b+c?d{1}e{1,}f{1,2}g*?",
+            "[a-z\\d_-]",
+            "[^\\]]",
+            "\\u{1F600}",
+            "(?<year>\\d{4})-\\k<year>",
+            "\\p{Script=Greek}",
+            "(a)\\1",
+            "\\uD83D\\uDE00",
+            "[\\uD83D\\uDE00-\\u{1F64F}]",
+        ] {
+            check(src, true).unwrap_or_else(|e| panic!("{}: {:?}", src, e));
+        }
+    }
+
+    #[test]
+    fn annex_b() {
+        for src in &["a{", "a{1", "}", "]", "\\a", "\\1", "[\\d-z]", "(?=a)*", "\\c1", "\\k"] {
+            check(src, false).unwrap_or_else(|e| panic!("{}: {:?}", src, e));
+        }
+    }
+
+    #[test]
+    fn groups() {
+        assert_eq!(err("(a", false), (0, 1, SyntaxError::RegexUnterminatedGroup));
+        assert_eq!(err("a)", false), (1, 2, SyntaxError::RegexUnmatchedParen));
+        assert_eq!(err("(?a)", false), (0, 3, SyntaxError::RegexInvalidGroup));
+        assert_eq!(
+            err("(?<a>x)(?<a>y)", false),
+            (
+                10,
+                11,
+                SyntaxError::RegexDuplicateGroupName { name: "a".into() }
+            )
+        );
+        assert_eq!(
+            err("(?<a>x)\\k<b>", false),
+            (7, 12, SyntaxError::RegexInvalidNamedReference)
+        );
+        assert_eq!(err("(?<=a)*", false), (6, 7, SyntaxError::RegexNothingToRepeat));
+    }
+
+    #[test]
+    fn unicode_escapes() {
+        assert_eq!(err("\\a", true), (0, 2, SyntaxError::RegexInvalidEscape));
+        assert_eq!(err("\\1", true), (0, 2, SyntaxError::RegexInvalidEscape));
+        assert_eq!(err("\\u{110000}", true), (0, 3, SyntaxError::RegexInvalidEscape));
+        assert_eq!(err("[\\d-z]", true), (1, 5, SyntaxError::RegexInvalidCharClass));
+        assert_eq!(err("a{", true), (1, 2, SyntaxError::RegexLoneQuantifierBrackets));
+    }
+
+    #[test]
+    fn quantifiers() {
+        assert_eq!(err("*", false), (0, 1, SyntaxError::RegexNothingToRepeat));
+        assert_eq!(err("a**", false), (2, 3, SyntaxError::RegexNothingToRepeat));
+        assert_eq!(err("a{2,1}", false), (1, 6, SyntaxError::RegexQuantifierOutOfOrder));
+        assert_eq!(err("[z-a]", false), (1, 4, SyntaxError::RegexRangeOutOfOrder));
+    }
+
+    #[test]
+    fn flags() {
+        for &(src, msg) in &[
+            ("/a/gg", "Duplicate regular expression flag 'g'"),
+            ("/a/x", "Invalid regular expression flag 'x'"),
+            ("/(a/u", "Unterminated group"),
+        ] {
+            let err = ::with_test_sess(src, |sess, input| {
+                Parser::new(sess, Syntax::default(), input, None)
+                    .parse_module()
+                    .map_err(|mut e| {
+                        e.emit();
+                        ()
+                    })
+            })
+            .expect_err("should fail");
+            assert!(err.contains(msg), "expected `{}`, got\n{}", msg, err);
+        }
+    }
+}
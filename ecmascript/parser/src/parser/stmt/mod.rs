@@ -64,19 +64,32 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// Parse a statement, declaration or module item.
-    fn parse_stmt_like<Type>(&mut self, include_decl: bool, top_level: bool) -> PResult<'a, Type>
+    pub(super) fn parse_stmt_like<Type>(
+        &mut self,
+        include_decl: bool,
+        top_level: bool,
+    ) -> PResult<'a, Type>
     where
         Self: StmtLikeParser<'a, Type>,
         Type: IsDirective + From<Stmt>,
     {
+        if self.input.syntax().dts() && !self.ctx().in_declare {
+            return self.parse_dts_top_level_item(include_decl, top_level);
+        }
+
         let decorators = self.parse_decorators(true)?;
 
-        if is_one_of!("import", "export") {
+        // `import.meta` starts an expression statement.
+        if is!("export") || (is!("import") && !peeked_is!('.')) {
             return self.handle_import_export(top_level, decorators);
         }
 
-        self.parse_stmt_internal(include_decl, top_level, decorators)
-            .map(From::from)
+        let stmt = self.nested(|p| p.parse_stmt_internal(include_decl, top_level, decorators))?;
+        if self.in_dts_ambient() {
+            self.check_dts_stmt(&stmt)?;
+        }
+
+        Ok(stmt.into())
     }
 
     /// `parseStatementContent`
@@ -231,7 +244,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         };
         match *expr {
             Expr::Ident(ref ident) => {
-                if self.input.syntax().typescript() {
+                if self.input.syntax().types() {
                     if let Some(decl) = self.parse_ts_expr_stmt(decorators, ident.clone())? {
                         return Ok(Stmt::Decl(decl));
                     }
@@ -297,7 +310,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }))
         });
 
-        if !self.ctx().in_function {
+        if !self.ctx().in_function && !self.input.syntax().allow_return_outside_function() {
             syntax_error!(span!(start), SyntaxError::ReturnNotAllowed)
         } else {
             stmt
@@ -426,6 +439,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
             expect!(')');
             Ok(Some(pat))
         } else {
+            self.ensure_es_version(
+                self.input.prev_span(),
+                EsVersion::Es2019,
+                "optional catch binding",
+            )?;
             Ok(None)
         }
     }
@@ -438,6 +456,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
             tok!("var") => VarDeclKind::Var,
             _ => unreachable!(),
         };
+        if kind != VarDeclKind::Var {
+            self.ensure_es_version(
+                self.input.prev_span(),
+                EsVersion::Es2015,
+                "let/const declaration",
+            )?;
+        }
         let should_include_in = kind != VarDeclKind::Var || !for_loop;
 
         let mut decls = vec![];
@@ -460,6 +485,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
         if !for_loop {
             expect!(';');
         }
+        if self.in_dts_ambient() {
+            self.check_dts_var_decls(kind, &decls)?;
+        }
 
         Ok(VarDecl {
             span: span!(start),
@@ -484,7 +512,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         };
 
         // Typescript extension
-        if self.input.syntax().typescript() && is!(':') {
+        if self.input.syntax().types() && is!(':') {
             let type_annotation = self.try_parse_ts_type_ann()?;
             match name {
                 Pat::Array(ArrayPat {
@@ -597,7 +625,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         expect!('{');
 
-        let stmts = self.parse_block_body(allow_directives, false, Some(&tok!('}')))?;
+        let stmts =
+            self.nested(|p| p.parse_block_body(allow_directives, false, Some(&tok!('}'))))?;
 
         let span = span!(start);
         Ok(BlockStmt { span, stmts })
@@ -643,6 +672,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         assert_and_bump!("for");
         let await_token = if eat!("await") {
+            self.ensure_es_version(span!(start), EsVersion::Es2018, "async iteration")?;
             Some(span!(start))
         } else {
             None
@@ -732,6 +762,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     fn parse_for_each_head(&mut self, left: VarDeclOrPat) -> PResult<'a, ForHead> {
         let of = bump!() == tok!("of");
         if of {
+            self.ensure_es_version(self.input.prev_span(), EsVersion::Es2015, "for-of loop")?;
             let right = self.include_in_expr(true).parse_assignment_expr()?;
             Ok(ForHead::ForOf { left, right })
         } else {
@@ -819,7 +850,7 @@ impl<'a, I: Tokens> StmtLikeParser<'a, Stmt> for Parser<'a, I> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::EsConfig;
+    use crate::{DecoratorsVersion, EsConfig, EsVersion, FlowConfig, PipelineProposal, TsConfig};
     use swc_common::DUMMY_SP as span;
 
     fn stmt(s: &'static str) -> Stmt {
@@ -1363,4 +1394,601 @@ export default function waitUntil(callback, options = {}) {
             },
         );
     }
+
+    fn parse_flow(s: &'static str) -> Module {
+        test_parser(s, Syntax::Flow(Default::default()), |p| {
+            p.parse_module().map_err(|mut e| {
+                e.emit();
+                ()
+            })
+        })
+    }
+
+    fn keyword_kind(ty: &TsType) -> Option<TsKeywordTypeKind> {
+        match *ty {
+            TsType::TsKeywordType(TsKeywordType { kind, .. }) => Some(kind),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn flow_type_annotations() {
+        let m = parse_flow(
+            "type A<+T: Object, -U> = {| a: ?T, +b: Array<*> |};
+opaque type B: string = string;
+declare var c: mixed;
+declare function d(e: number): void;
+function f<T>(g: T, h?: string): T { return (g: any); }
+const i = (j.k: A<number>);",
+        );
+        let decls: Vec<&Decl> = m
+            .body
+            .iter()
+            .filter_map(|item| match *item {
+                ModuleItem::Stmt(Stmt::Decl(ref decl)) => Some(decl),
+                _ => None,
+            })
+            .collect();
+
+        let a = match *decls[0] {
+            Decl::TsTypeAlias(ref a) => a,
+            ref decl => panic!("expected a type alias, got {:?}", decl),
+        };
+        let params = &a.type_params.as_ref().unwrap().params;
+        assert_eq!(params[0].variance, Some(Variance::Covariant));
+        assert!(params[0].constraint.is_some());
+        assert_eq!(params[1].variance, Some(Variance::Contravariant));
+
+        // Exact object types are type literals.
+        let members = match *a.type_ann {
+            TsType::TsTypeLit(ref lit) => &lit.members,
+            ref ty => panic!("expected a type literal, got {:?}", ty),
+        };
+        assert_eq!(members.len(), 2);
+        let (a_prop, b_prop) = match (&members[0], &members[1]) {
+            (
+                &TsTypeElement::TsPropertySignature(ref a),
+                &TsTypeElement::TsPropertySignature(ref b),
+            ) => (a, b),
+            members => panic!("expected property signatures, got {:?}", members),
+        };
+        assert_eq!(a_prop.variance, None);
+        assert_eq!(b_prop.variance, Some(Variance::Covariant));
+
+        // `?T` is `T | null | undefined`.
+        match *a_prop.type_ann.as_ref().unwrap().type_ann {
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(ref u)) => {
+                let kinds: Vec<_> = u.types.iter().map(|ty| keyword_kind(ty)).collect();
+                assert_eq!(
+                    kinds,
+                    vec![
+                        None,
+                        Some(TsKeywordTypeKind::TsNullKeyword),
+                        Some(TsKeywordTypeKind::TsUndefinedKeyword),
+                    ]
+                );
+            }
+            ref ty => panic!("expected a union type, got {:?}", ty),
+        }
+
+        match *decls[1] {
+            Decl::TsTypeAlias(ref b) => assert_eq!(
+                keyword_kind(b.super_type.as_ref().unwrap()),
+                Some(TsKeywordTypeKind::TsStringKeyword)
+            ),
+            ref decl => panic!("expected a type alias, got {:?}", decl),
+        }
+
+        // `mixed` is `unknown`.
+        match *decls[2] {
+            Decl::Var(VarDecl {
+                declare: true,
+                ref decls,
+                ..
+            }) => match decls[0].name {
+                Pat::Ident(ref c) => assert_eq!(
+                    keyword_kind(&c.type_ann.as_ref().unwrap().type_ann),
+                    Some(TsKeywordTypeKind::TsUnknownKeyword)
+                ),
+                ref pat => panic!("expected an identifier, got {:?}", pat),
+            },
+            ref decl => panic!("expected a var declaration, got {:?}", decl),
+        }
+    }
+
+    #[test]
+    fn flow_variance_on_method() {
+        ::with_test_sess("type A = { +m(): void };", |sess, input| {
+            Parser::new(sess, Syntax::Flow(Default::default()), input, None)
+                .parse_module()
+                .map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+        })
+        .expect_err("should fail");
+    }
+
+    #[test]
+    fn flow_import_export_type() {
+        let m = parse_flow(
+            "import type { A } from 'a';
+import typeof B from 'b';
+import { type C, D } from 'c';
+export type { A };
+export type E = number;
+export opaque type F = string;",
+        );
+        let type_only: Vec<_> = m
+            .body
+            .iter()
+            .map(|item| match *item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ref i)) => Some(i.type_only),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(ref e)) => Some(e.type_only),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            type_only,
+            vec![Some(true), Some(true), Some(false), Some(true), None, None]
+        );
+
+        for item in &m.body[4..] {
+            match *item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsTypeAlias(..),
+                    ..
+                })) => {}
+                ref item => panic!("expected an exported type alias, got {:?}", item),
+            }
+        }
+    }
+
+    fn parse_with_target(s: &'static str, target: EsVersion) -> Result<Module, ::testing::StdErr> {
+        ::with_test_sess(s, |sess, input| {
+            let syntax = Syntax::Es(EsConfig {
+                target,
+                ..Default::default()
+            });
+            Parser::new(sess, syntax, input, None)
+                .parse_module()
+                .map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+        })
+    }
+
+    fn assert_requires(s: &'static str, target: EsVersion, msg: &str) {
+        let err = parse_with_target(s, target).expect_err("should fail");
+        assert!(err.contains(msg), "expected `{}`, got\n{}", msg, err);
+    }
+
+    #[test]
+    fn es_version_object_spread() {
+        assert_requires(
+            "var a = { ...b };",
+            EsVersion::Es2017,
+            "object rest/spread requires es2018",
+        );
+        assert_requires(
+            "var { ...a } = b;",
+            EsVersion::Es2017,
+            "object rest/spread requires es2018",
+        );
+        parse_with_target("var a = { ...b };", EsVersion::Es2018).unwrap();
+    }
+
+    #[test]
+    fn es_version_es2015() {
+        assert_requires("a => a", EsVersion::Es5, "arrow function requires es2015");
+        assert_requires(
+            "let a = 1;",
+            EsVersion::Es5,
+            "let/const declaration requires es2015",
+        );
+        assert_requires("class A {}", EsVersion::Es5, "class requires es2015");
+        assert_requires("`a`", EsVersion::Es5, "template literal requires es2015");
+        assert_requires(
+            "for (a of b);",
+            EsVersion::Es5,
+            "for-of loop requires es2015",
+        );
+        parse_with_target(
+            "var a = function () { return { get b() {} }; };",
+            EsVersion::Es5,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn es_version_misc() {
+        assert_requires(
+            "a ** b",
+            EsVersion::Es2015,
+            "exponentiation operator requires es2016",
+        );
+        assert_requires(
+            "async function a() {}",
+            EsVersion::Es2016,
+            "async function requires es2017",
+        );
+        assert_requires(
+            "try {} catch {}",
+            EsVersion::Es2018,
+            "optional catch binding requires es2019",
+        );
+        parse_with_target("try {} catch {}", EsVersion::Es2019).unwrap();
+    }
+
+    #[test]
+    fn es_version_async_generator() {
+        for src in &[
+            "async function* a() {}",
+            "a = async function* () {};",
+            "a = { async *b() {} };",
+            "class A { async *b() {} }",
+        ] {
+            assert_requires(*src, EsVersion::Es2017, "async generator requires es2018");
+            parse_with_target(*src, EsVersion::Es2018).unwrap();
+        }
+    }
+
+    #[test]
+    fn es_version_literals() {
+        for src in &["0b1;", "0o1;"] {
+            assert_requires(
+                *src,
+                EsVersion::Es5,
+                "binary and octal literal requires es2015",
+            );
+        }
+        parse_with_target("0x1;", EsVersion::Es3).unwrap();
+        assert_requires(
+            "/a/u;",
+            EsVersion::Es5,
+            "regular expression flag `u` requires es2015",
+        );
+        assert_requires(
+            "/a/y;",
+            EsVersion::Es5,
+            "regular expression flag `y` requires es2015",
+        );
+        assert_requires(
+            "/a/s;",
+            EsVersion::Es2017,
+            "regular expression flag `s` requires es2018",
+        );
+        parse_with_target("0b1; 0o1; /a/suy;", EsVersion::Es2018).unwrap();
+    }
+
+    #[test]
+    fn es_version_es2020() {
+        assert_requires(
+            "import.meta.url;",
+            EsVersion::Es2019,
+            "import.meta requires es2020",
+        );
+        parse_with_target("a(import.meta.url);", EsVersion::Es2020).unwrap();
+
+        let syntax = |target| {
+            Syntax::Typescript(TsConfig {
+                target,
+                ..Default::default()
+            })
+        };
+        let err = parse_with_syntax("a?.b;", syntax(EsVersion::Es2019)).expect_err("should fail");
+        assert!(
+            err.contains("optional chaining requires es2020"),
+            "unexpected error:\n{}",
+            err
+        );
+        parse_with_syntax("a?.b;", syntax(EsVersion::Es2020)).unwrap();
+    }
+
+    #[test]
+    fn import_meta_in_script() {
+        let err = ::with_test_sess("import.meta;", |sess, input| {
+            Parser::new(sess, Syntax::default(), input, None)
+                .parse_script()
+                .map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+        })
+        .expect_err("should fail");
+        assert!(err.contains("'import.meta' cannot be used outside of module code"));
+    }
+
+    #[test]
+    fn es_version_typescript_and_flow() {
+        let msg = "object rest/spread requires es2018";
+        for &syntax in &[
+            Syntax::Typescript(TsConfig {
+                target: EsVersion::Es2017,
+                ..Default::default()
+            }),
+            Syntax::Flow(FlowConfig {
+                target: EsVersion::Es2017,
+                ..Default::default()
+            }),
+        ] {
+            let err = parse_with_syntax("var a = { ...b };", syntax).expect_err("should fail");
+            assert!(err.contains(msg), "expected `{}`, got\n{}", msg, err);
+        }
+    }
+
+    #[test]
+    fn es_version_default_is_latest() {
+        parse_with_target(
+            "async function a() { for await (const b of c); try {} catch {} return { ...b } }",
+            Default::default(),
+        )
+        .unwrap();
+    }
+
+    fn parse_with_syntax(s: &'static str, syntax: Syntax) -> Result<Module, ::testing::StdErr> {
+        ::with_test_sess(s, |sess, input| {
+            Parser::new(sess, syntax, input, None)
+                .parse_module()
+                .map_err(|mut e| {
+                    e.emit();
+                    ()
+                })
+        })
+    }
+
+    #[test]
+    fn allow_return_outside_function() {
+        let err = parse_with_syntax("return 1;", Default::default()).expect_err("should fail");
+        assert!(err.contains("Return statement is not allowed here"));
+
+        parse_with_syntax(
+            "return 1;",
+            Syntax::Es(EsConfig {
+                allow_return_outside_function: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        parse_with_syntax(
+            "if (a) return;",
+            Syntax::Typescript(TsConfig {
+                allow_return_outside_function: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn allow_await_outside_function() {
+        parse_with_syntax("await a;", Default::default()).expect_err("should fail");
+
+        let syntax = Syntax::Es(EsConfig {
+            allow_await_outside_function: true,
+            ..Default::default()
+        });
+        let m = parse_with_syntax("await a; { await b; }", syntax).unwrap();
+        match m.body[0] {
+            ModuleItem::Stmt(Stmt::Expr(ref e)) => match **e {
+                Expr::Await(..) => {}
+                ref e => panic!("expected an await expression, got {:?}", e),
+            },
+            ref item => panic!("expected an expression statement, got {:?}", item),
+        }
+        // Await is not allowed in non-async functions.
+        parse_with_syntax("function a() { await b; }", syntax).expect_err("should fail");
+    }
+
+    #[test]
+    fn allow_super_outside_method() {
+        let syntax = Syntax::Es(EsConfig {
+            allow_super_outside_method: false,
+            ..Default::default()
+        });
+
+        let msg = "'super' is only allowed in object methods and classes";
+        for src in &[
+            "super.a();",
+            "super();",
+            "class A { a() { function b() { super.a(); } } }",
+            "({ a: super.a })",
+        ] {
+            let err = parse_with_syntax(src, syntax).expect_err("should fail");
+            assert!(err.contains(msg), "{}: {}", src, err);
+
+            parse_with_syntax(src, Default::default()).unwrap();
+        }
+
+        for src in &[
+            "class A extends B { constructor() { super(); } }",
+            "class A extends B { a() { return () => super.a(); } }",
+            "class A extends B { static get a() { return super.a; } }",
+            "({ a() { return super.a; }, set b(v) { super.b = v; } })",
+        ] {
+            parse_with_syntax(src, syntax).unwrap();
+        }
+    }
+
+    #[test]
+    fn allow_import_export_everywhere() {
+        fn parse(syntax: Syntax) -> Result<ModuleItem, ::testing::StdErr> {
+            ::with_test_sess("import a from 'a';", |sess, input| {
+                Parser::new(sess, syntax, input, None)
+                    .parse_stmt_like(true, false)
+                    .map_err(|mut e| {
+                        e.emit();
+                        ()
+                    })
+            })
+        }
+
+        let err = parse(Default::default()).expect_err("should fail");
+        assert!(err.contains("'import', and 'export' are not permitted here"));
+
+        parse(Syntax::Es(EsConfig {
+            allow_import_export_everywhere: true,
+            ..Default::default()
+        }))
+        .unwrap();
+    }
+
+    fn decorators(decorators_version: DecoratorsVersion) -> Syntax {
+        Syntax::Es(EsConfig {
+            decorators: true,
+            class_props: true,
+            class_private_props: true,
+            class_private_methods: true,
+            decorators_version,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn decorators_after_export() {
+        let v201911 = decorators(DecoratorsVersion::V201911);
+        let legacy_before_export = Syntax::Es(EsConfig {
+            decorators: true,
+            decorators_before_export: true,
+            ..Default::default()
+        });
+
+        for src in &[
+            "export @dec class A {}",
+            "export default @dec class {}",
+            "@dec export class A {}",
+            "@dec export default class {}",
+        ] {
+            parse_with_syntax(src, v201911).unwrap();
+        }
+
+        let err = parse_with_syntax("export @a class A {}", legacy_before_export)
+            .expect_err("should fail");
+        assert!(err.contains("Decorators must be placed *before* the 'export' keyword"));
+        let err = parse_with_syntax("@a export @b class A {}", v201911).expect_err("should fail");
+        assert!(err.contains("not in both locations"));
+
+        let legacy = decorators(DecoratorsVersion::Legacy);
+        parse_with_syntax("export @dec class A {}", legacy).unwrap();
+        parse_with_syntax("@dec export class A {}", legacy).expect_err("should fail");
+    }
+
+    #[test]
+    fn decorators_on_private_members() {
+        for src in &[
+            "class A { @dec #a = 1; }",
+            "class A { @dec #b() {} }",
+            "class A { @dec *#c() {} }",
+            "class A { @dec accessor #d; }",
+        ] {
+            let err = parse_with_syntax(src, decorators(DecoratorsVersion::Legacy))
+                .expect_err("should fail");
+            assert!(err.contains("Decorators cannot be used to decorate private class members"));
+
+            parse_with_syntax(src, decorators(DecoratorsVersion::V201911)).unwrap();
+        }
+
+        parse_with_syntax("class A { #a = 1; }", decorators(DecoratorsVersion::Legacy)).unwrap();
+    }
+
+    #[test]
+    fn accessor_keyword() {
+        let src = "class A { @dec accessor a = 1; static accessor #b; accessor; accessor = 2; \
+                   accessor() {} }";
+
+        let m = parse_with_syntax(src, decorators(DecoratorsVersion::V201911)).unwrap();
+        let class = match m.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(ClassDecl { ref class, .. }))) => class,
+            ref item => panic!("expected a class declaration, got {:?}", item),
+        };
+        let accessors = class
+            .body
+            .iter()
+            .map(|member| match *member {
+                ClassMember::ClassProp(ref p) => p.is_accessor,
+                ClassMember::PrivateProp(ref p) => p.is_accessor,
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(accessors, vec![true, true, false, false, false]);
+
+        // `accessor` is a plain property name in legacy mode.
+        parse_with_syntax(
+            "class A { accessor a = 1; }",
+            decorators(DecoratorsVersion::Legacy),
+        )
+        .expect_err("should fail");
+    }
+
+    #[test]
+    fn pipeline_operator() {
+        let minimal = Syntax::Es(EsConfig {
+            pipeline_operator: true,
+            ..Default::default()
+        });
+        let fsharp = Syntax::Es(EsConfig {
+            pipeline_operator: true,
+            pipeline_proposal: PipelineProposal::FSharp,
+            ..Default::default()
+        });
+
+        parse_with_syntax("x |> (y => y + 1) |> f;", minimal).unwrap();
+        let err = parse_with_syntax("x |> y => y + 1;", minimal).expect_err("should fail");
+        assert!(err.contains("arrow function in pipeline body must be parenthesized"));
+
+        parse_with_syntax("x |> y => y + 1 |> f;", fsharp).unwrap();
+        parse_with_syntax("async function f() { x |> await; }", fsharp).unwrap();
+        parse_with_syntax("async function f() { x |> await; }", minimal).expect_err("should fail");
+
+        parse_with_syntax("x |> f;", Syntax::default()).expect_err("should fail");
+    }
+
+    #[test]
+    fn partial_application() {
+        let syntax = Syntax::Es(EsConfig {
+            partial_application: true,
+            ..Default::default()
+        });
+
+        parse_with_syntax("f(?, x, ?); o.m(1, ?);", syntax).unwrap();
+        let err = parse_with_syntax("new F(?);", syntax).expect_err("should fail");
+        assert!(err.contains("Partial application is not allowed in new expressions"));
+
+        parse_with_syntax("f(?);", Syntax::default()).expect_err("should fail");
+    }
+
+    #[test]
+    fn do_expressions() {
+        let syntax = Syntax::Es(EsConfig {
+            do_expressions: true,
+            ..Default::default()
+        });
+
+        let src = "let x = do { if (a) { 1 } else { 2 } }; do {} while (a);";
+        let m = parse_with_syntax(src, syntax).unwrap();
+        match m.body[1] {
+            ModuleItem::Stmt(Stmt::DoWhile(..)) => {}
+            ref item => panic!("expected a do-while statement, got {:?}", item),
+        }
+
+        parse_with_syntax("let x = do { 1 };", Syntax::default()).expect_err("should fail");
+    }
+
+    #[test]
+    fn throw_expressions() {
+        let syntax = Syntax::Es(EsConfig {
+            throw_expressions: true,
+            ..Default::default()
+        });
+
+        let m = parse_with_syntax("throw a; const x = y || throw new Error();", syntax).unwrap();
+        match m.body[0] {
+            ModuleItem::Stmt(Stmt::Throw(..)) => {}
+            ref item => panic!("expected a throw statement, got {:?}", item),
+        }
+        parse_with_syntax("function f(x = throw new Error()) {}", syntax).unwrap();
+
+        parse_with_syntax("const x = y || throw a;", Syntax::default()).expect_err("should fail");
+    }
 }
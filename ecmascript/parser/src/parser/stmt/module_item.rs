@@ -24,20 +24,26 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
 
+        self.ensure_es_version(span!(start), EsVersion::Es2015, "module syntax")?;
+
         // Handle import 'mod.js'
         let str_start = cur_pos!();
         match cur!(false) {
             Ok(&Token::Str { .. }) => match bump!() {
                 Token::Str { value, has_escape } => {
+                    let str_span = span!(str_start);
+                    let raw = self.input.raw(str_span);
                     expect!(';');
                     return Ok(ModuleDecl::Import(ImportDecl {
                         span: span!(start),
                         src: Str {
-                            span: span!(str_start),
+                            span: str_span,
                             value,
                             has_escape,
+                            raw,
                         },
                         specifiers: vec![],
+                        type_only: false,
                     }))
                     .map(ModuleItem::from);
                 }
@@ -46,6 +52,15 @@ impl<'a, I: Tokens> Parser<'a, I> {
             _ => {}
         }
 
+        // Flow: `import type { A } from 'mod'`, `import typeof B from 'mod'`
+        let type_only = self.input.syntax().flow()
+            && is_one_of!("type", "typeof")
+            && (peeked_is!('{') || peeked_is!('*') || peeked_is!(BindingIdent))
+            && !peeked_is!("from");
+        if type_only {
+            bump!();
+        }
+
         let mut specifiers = vec![];
 
         if is!(BindingIdent) {
@@ -94,6 +109,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             span: span!(start),
             specifiers,
             src,
+            type_only,
         }))
         .map(ModuleItem::from)
     }
@@ -101,6 +117,18 @@ impl<'a, I: Tokens> Parser<'a, I> {
     /// Parse `foo`, `foo2 as bar` in `import { foo, foo2 as bar }`
     fn parse_import_specifier(&mut self) -> PResult<'a, ImportSpecifier> {
         let start = cur_pos!();
+
+        // Flow: `import { type A } from 'mod'`
+        //
+        // Those are removed by the strip pass if they are only used as types.
+        if self.input.syntax().flow()
+            && is_one_of!("type", "typeof")
+            && peeked_is!(IdentName)
+            && !peeked_is!("as")
+        {
+            bump!();
+        }
+
         match cur!(false) {
             Ok(&Word(..)) => {
                 let orig_name = self.parse_ident_name()?;
@@ -146,9 +174,38 @@ impl<'a, I: Tokens> Parser<'a, I> {
         self.with_ctx(ctx).parse_binding_ident()
     }
 
-    fn parse_export(&mut self, decorators: Vec<Decorator>) -> PResult<'a, ModuleDecl> {
+    /// Parses decorators of `export @dec class` and `export default @dec class`.
+    fn parse_decorators_after_export(
+        &mut self,
+        decorators: &mut Vec<Decorator>,
+    ) -> PResult<'a, ()> {
+        if !is!('@') {
+            return Ok(());
+        }
+
+        let start = cur_pos!();
+        let after = self.parse_decorators(false)?;
+        if after.is_empty() {
+            return Ok(());
+        }
+
+        if !decorators.is_empty() {
+            syntax_error!(span!(start), SyntaxError::DecoratorsBeforeAndAfterExport)
+        }
+        if self.input.syntax().decorators_before_export()
+            && self.input.syntax().decorators_version() == DecoratorsVersion::Legacy
+        {
+            syntax_error!(span!(start), SyntaxError::DecoratorAfterExport)
+        }
+
+        *decorators = after;
+        Ok(())
+    }
+
+    fn parse_export(&mut self, mut decorators: Vec<Decorator>) -> PResult<'a, ModuleDecl> {
         let start = cur_pos!();
         assert_and_bump!("export");
+        self.ensure_es_version(span!(start), EsVersion::Es2015, "module syntax")?;
 
         // "export declare" is equivalent to just "export".
         let declare = self.input.syntax().typescript() && eat!("declare");
@@ -163,7 +220,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
 
-        if self.input.syntax().typescript() && is!(IdentName) {
+        // Flow: `export type { A }`
+        let type_only = self.input.syntax().flow() && is!("type") && peeked_is!('{');
+        if type_only {
+            bump!();
+        }
+
+        if self.input.syntax().types() && is!(IdentName) {
             let sym = match *cur!(true)? {
                 Token::Word(ref w) => w.clone().into(),
                 _ => unreachable!(),
@@ -189,6 +252,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 // `export = x;`
                 let expr = self.parse_expr()?;
                 expect!(';');
+                if self.in_dts_ambient() {
+                    self.check_dts_export_assignment(&expr)?;
+                }
                 return Ok(TsExportAssignment {
                     span: span!(start),
                     expr,
@@ -226,6 +292,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 if !self.input.syntax().export_namespace_from() {
                     syntax_error!(span!(start), SyntaxError::ExportNamespaceFrom)
                 }
+                self.ensure_es_version(span!(start), EsVersion::Es2020, "export * as ns")?;
                 let _ = cur!(false);
 
                 let name = self.parse_ident_name()?;
@@ -240,6 +307,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let mut export_default = None;
 
         if export_ns.is_none() && eat!("default") {
+            self.parse_decorators_after_export(&mut decorators)?;
+
             if self.input.syntax().typescript() {
                 if is!("abstract") && peeked_is!("class") {
                     let start = cur_pos!();
@@ -284,6 +353,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
             } else {
                 let expr = self.include_in_expr(true).parse_assignment_expr()?;
                 expect!(';');
+                if self.in_dts_ambient() {
+                    self.check_dts_export_assignment(&expr)?;
+                }
                 return Ok(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
                     span: span!(start),
                     expr,
@@ -291,6 +363,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
 
+        self.parse_decorators_after_export(&mut decorators)?;
+
         let decl = if is!("class") {
             self.parse_class_decl(decorators)?
         } else if is!("async")
@@ -335,6 +409,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                         span: span!(start),
                         specifiers: vec![s],
                         src,
+                        type_only: false,
                     }));
                 }
             }
@@ -359,6 +434,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                             exported: default,
                         })],
                         src,
+                        type_only: false,
                     }));
                 }
             }
@@ -420,6 +496,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 span: span!(start),
                 specifiers,
                 src,
+                type_only,
             }));
         };
 
@@ -454,11 +531,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
         match *cur!(true)? {
             Token::Str { .. } => match bump!() {
                 Token::Str { value, has_escape } => {
+                    let span = span!(start);
+                    let raw = self.input.raw(span);
                     expect!(';');
                     Ok(Str {
                         value,
                         has_escape,
-                        span: span!(start),
+                        span,
+                        raw,
                     })
                 }
                 _ => unreachable!(),
@@ -484,7 +564,7 @@ impl<'a, I: Tokens> StmtLikeParser<'a, ModuleItem> for Parser<'a, I> {
         top_level: bool,
         decorators: Vec<Decorator>,
     ) -> PResult<'a, ModuleItem> {
-        if !top_level {
+        if !top_level && !self.input.syntax().allow_import_export_everywhere() {
             syntax_error!(SyntaxError::NonTopLevelImportExport);
         }
 
@@ -7,7 +7,7 @@ use swc_common::Spanned;
 impl<'a, I: Tokens> Parser<'a, I> {
     /// `tsNextTokenCanFollowModifier`
     fn ts_next_token_can_follow_modifier(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // Note: TypeScript's implementation is much more complicated because
         // more things are considered modifiers there.
@@ -55,10 +55,12 @@ impl<'a, I: Tokens> Parser<'a, I> {
     /// `tsIsListTerminator`
     #[inline(always)]
     fn is_ts_list_terminator(&mut self, kind: ParsingContext) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         Ok(match kind {
-            ParsingContext::EnumMembers | ParsingContext::TypeMembers => is!('}'),
+            ParsingContext::EnumMembers => is!('}'),
+            // Flow: exact object types are terminated by `|}`
+            ParsingContext::TypeMembers => is!('}') || (self.input.syntax().flow() && is!('|')),
             ParsingContext::HeritageClauseElement => is!('{'),
             ParsingContext::TupleElementTypes => is!(']'),
             ParsingContext::TypeParametersOrArguments => is!('>'),
@@ -74,7 +76,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnMut(&mut Self) -> PResult<'a, T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let mut buf = vec![];
         while !self.is_ts_list_terminator(kind)? {
@@ -94,7 +96,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnMut(&mut Self) -> PResult<'a, T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let mut buf = vec![];
 
@@ -131,7 +133,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnMut(&mut Self) -> PResult<'a, T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if !skip_first_token {
             if bracket {
@@ -154,7 +156,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseEntityName`
     fn parse_ts_entity_name(&mut self, allow_reserved_words: bool) -> PResult<'a, TsEntityName> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let mut entity = TsEntityName::Ident(self.parse_ident_name()?);
         while eat!('.') {
@@ -172,7 +174,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeReference`
     fn parse_ts_type_ref(&mut self) -> PResult<'a, TsTypeRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         let type_name = self.parse_ts_entity_name(/* allow_reserved_words */ false)?;
@@ -190,7 +192,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseThisTypePredicate`
     fn parse_ts_this_type_predicate(&mut self, lhs: TsThisType) -> PResult<'a, TsTypePredicate> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         assert_and_bump!("is");
         let start = cur_pos!();
@@ -211,7 +213,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseThisTypeNode`
     fn parse_ts_this_type_node(&mut self) -> PResult<'a, TsThisType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         expect!("this");
 
@@ -222,7 +224,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeQuery`
     fn parse_ts_type_query(&mut self) -> PResult<'a, TsTypeQuery> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         expect!("typeof");
@@ -238,22 +240,43 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeParameter`
     fn parse_ts_type_param(&mut self) -> PResult<'a, TsTypeParam> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
-
+        let variance = self.parse_flow_variance();
         let name = self.parse_ident_name()?;
-        let constraint = self.eat_then_parse_ts_type(&tok!("extends"))?;
+        let constraint = if self.input.syntax().flow() {
+            // Flow: `<T: Bound>`
+            self.eat_then_parse_ts_type(&tok!(':'))?
+        } else {
+            self.eat_then_parse_ts_type(&tok!("extends"))?
+        };
         let default = self.eat_then_parse_ts_type(&tok!('='))?;
 
         Ok(TsTypeParam {
             span: span!(start),
             name,
+            variance,
             constraint,
             default,
         })
     }
 
+    /// Flow: `+` or `-` before a type parameter or a property.
+    fn parse_flow_variance(&mut self) -> Option<Variance> {
+        if !self.input.syntax().flow() {
+            return None;
+        }
+
+        if eat!('+') {
+            Some(Variance::Covariant)
+        } else if eat!('-') {
+            Some(Variance::Contravariant)
+        } else {
+            None
+        }
+    }
+
     /// `tsParseTypeParameter`
     pub(super) fn parse_ts_type_params(&mut self) -> PResult<'a, TsTypeParamDecl> {
         let start = cur_pos!();
@@ -282,7 +305,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         &mut self,
         return_token: &'static Token,
     ) -> PResult<'a, TsTypeAnn> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.in_type().parse_with(|p| {
             let start = cur_pos!();
@@ -329,7 +352,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     fn parse_ts_type_predicate_prefix(&mut self) -> PResult<'a, Option<Ident>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let id = self.parse_ident_name()?;
 
@@ -346,7 +369,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnOnce(&mut Self) -> PResult<'a, Option<bool>>,
     {
-        if !self.input.syntax().typescript() {
+        if !self.input.syntax().types() {
             return Ok(false);
         }
         let mut cloned = self.clone();
@@ -369,7 +392,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnOnce(&mut Self) -> PResult<'a, Option<T>>,
     {
-        if !self.input.syntax().typescript() {
+        if !self.input.syntax().types() {
             return None;
         }
         let mut cloned = self.clone();
@@ -392,7 +415,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         eat_colon: bool,
         start: BytePos,
     ) -> PResult<'a, TsTypeAnn> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.in_type().parse_with(|p| {
             if eat_colon {
@@ -424,7 +447,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsExpectThenParseType`
     fn expect_then_parse_ts_type(&mut self, token: &'static Token) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.in_type().parse_with(|p| {
             if !p.input.eat(token) {
@@ -437,7 +460,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsNextThenParseType`
     pub(super) fn next_then_parse_ts_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.in_type().parse_with(|p| {
             bump!();
@@ -499,7 +522,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseModuleBlock`
     fn parse_ts_module_block(&mut self) -> PResult<'a, TsModuleBlock> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         expect!('{');
@@ -519,7 +542,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseModuleOrNamespaceDeclaration`
     fn parse_ts_module_or_ns_decl(&mut self, start: BytePos) -> PResult<'a, TsModuleDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let id = self.parse_ident_name()?;
         let body: TsNamespaceBody = if eat!('.') {
@@ -554,7 +577,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         &mut self,
         start: BytePos,
     ) -> PResult<'a, TsModuleDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let (global, id) = if is!("global") {
             let id = self.parse_ident_name()?;
@@ -592,14 +615,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
     ///
     /// `tsParseType`
     fn parse_ts_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // Need to set `state.inType` so that we don't parse JSX in a type context.
         debug_assert!(self.ctx().in_type);
 
         let start = cur_pos!();
 
-        let ty = self.parse_ts_non_conditional_type()?;
+        let ty = self.nested(|p| p.parse_ts_non_conditional_type())?;
         if self.input.had_line_break_before_cur() || !eat!("extends") {
             return Ok(ty);
         }
@@ -626,7 +649,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseNonConditionalType`
     fn parse_ts_non_conditional_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if self.is_ts_start_of_fn_type()? {
             return self
@@ -646,7 +669,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     fn is_ts_start_of_fn_type(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if is!('<') {
             return Ok(true);
@@ -665,7 +688,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         // `tsParseType`.
         let type_ann = self.in_type().parse_with(|p| p.parse_ts_type())?;
         expect!('>');
-        let expr = self.parse_unary_expr()?;
+        let expr = self.nested(|p| p.parse_unary_expr())?;
         return Ok(TsTypeAssertion {
             span: span!(start),
             type_ann,
@@ -675,7 +698,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseHeritageClause`
     pub(super) fn parse_ts_heritage_clause(&mut self) -> PResult<'a, Vec<TsExprWithTypeArgs>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.parse_ts_delimited_list(ParsingContext::HeritageClauseElement, |p| {
             p.parse_expr_with_type_args()
@@ -684,7 +707,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseExpressionWithTypeArguments`
     fn parse_expr_with_type_args(&mut self) -> PResult<'a, TsExprWithTypeArgs> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         // Note: TS uses parseLeftHandSideExpressionOrHigher,
@@ -705,7 +728,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
     /// `tsParseInterfaceDeclaration`
     pub(super) fn parse_ts_interface_decl(&mut self) -> PResult<'a, TsInterfaceDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
 
@@ -738,7 +761,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeAliasDeclaration`
     fn parse_ts_type_alias_decl(&mut self, start: BytePos) -> PResult<'a, TsTypeAliasDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let id = self.parse_ident_name()?;
         let type_params = self.try_parse_ts_type_params()?;
@@ -750,6 +773,39 @@ impl<'a, I: Tokens> Parser<'a, I> {
             id,
             type_params,
             type_ann,
+            super_type: None,
+        })
+    }
+
+    /// Flow: `opaque type A: Super = T;`
+    ///
+    /// Inside a declare context, the right hand side may be omitted, in which
+    /// case the alias is typed as `unknown`.
+    fn parse_flow_opaque_type_alias_decl(
+        &mut self,
+        start: BytePos,
+    ) -> PResult<'a, TsTypeAliasDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_ts_type_params()?;
+        let super_type = self.eat_then_parse_ts_type(&tok!(':'))?;
+        let type_ann = if self.ctx().in_declare && !is!('=') {
+            Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: id.span,
+                kind: TsKeywordTypeKind::TsUnknownKeyword,
+            }))
+        } else {
+            self.expect_then_parse_ts_type(&tok!('='))?
+        };
+        expect!(';');
+        Ok(TsTypeAliasDecl {
+            declare: self.ctx().in_declare,
+            span: span!(start),
+            id,
+            type_params,
+            type_ann,
+            super_type,
         })
     }
 
@@ -759,7 +815,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         start: BytePos,
         is_export: bool,
     ) -> PResult<'a, TsImportEqualsDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let id = self.parse_ident_name()?;
         expect!('=');
@@ -777,14 +833,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsIsExternalModuleReference`
     fn is_ts_external_module_ref(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         Ok(is!("require") && peeked_is!('('))
     }
 
     /// `tsParseModuleReference`
     fn parse_ts_module_ref(&mut self) -> PResult<'a, TsModuleRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if self.is_ts_external_module_ref()? {
             self.parse_ts_external_module_ref().map(From::from)
@@ -796,7 +852,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseExternalModuleReference`
     fn parse_ts_external_module_ref(&mut self) -> PResult<'a, TsExternalModuleRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         expect!("require");
@@ -820,7 +876,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnOnce(&mut Self) -> PResult<'a, T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let mut cloned = self.clone();
         op(&mut cloned)
@@ -828,7 +884,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsIsUnambiguouslyStartOfFunctionType`
     fn is_ts_unambiguously_start_of_fn_type(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         assert_and_bump!('(');
         if is_one_of!(')', "...") {
@@ -856,7 +912,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsSkipParameterStart`
     fn skip_ts_parameter_start(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if is_one_of!(IdentRef, "this") {
             bump!();
@@ -883,9 +939,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeMemberSemicolon`
     fn parse_ts_type_member_semicolon(&mut self) -> PResult<'a, ()> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if !eat!(',') {
+            // Flow allows omitting separator before `|}`.
+            if self.input.syntax().flow() && is!('|') {
+                return Ok(());
+            }
             expect!(';');
         }
 
@@ -897,7 +957,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         &mut self,
         kind: SignatureParsingMode,
     ) -> PResult<'a, Either<TsCallSignatureDecl, TsConstructSignatureDecl>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
 
@@ -940,7 +1000,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsIsUnambiguouslyIndexSignature`
     fn is_ts_unambiguously_index_signature(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // Note: babel's comment is wrong
         assert_and_bump!('['); // Skip '['
@@ -977,6 +1037,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         Ok(Some(TsIndexSignature {
             span: span!(start),
             readonly,
+            variance: None,
             params,
             type_ann,
         }))
@@ -988,7 +1049,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         start: BytePos,
         readonly: bool,
     ) -> PResult<'a, Either<TsPropertySignature, TsMethodSignature>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // ----- inlined self.parsePropertyName(node);
         let (computed, key) = if eat!('[') {
@@ -1050,6 +1111,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 span: span!(start),
                 computed,
                 readonly,
+                variance: None,
                 key,
                 optional,
                 init: None,
@@ -1062,7 +1124,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeMember`
     fn parse_ts_type_member(&mut self) -> PResult<'a, TsTypeElement> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         fn into_type_elem(
             e: Either<TsCallSignatureDecl, TsConstructSignatureDecl>,
@@ -1084,23 +1146,28 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
         // Instead of fullStart, we create a node here.
         let start = cur_pos!();
+        let variance = self.parse_flow_variance();
         let readonly = self.parse_ts_modifier(&["readonly"])?.is_some();
 
         let idx = self.try_parse_ts_index_signature(start, readonly)?;
         if let Some(idx) = idx {
-            return Ok(idx.into());
+            return Ok(TsIndexSignature { variance, ..idx }.into());
         }
 
-        self.parse_ts_property_or_method_signature(start, readonly)
-            .map(|e| match e {
-                Either::Left(e) => e.into(),
-                Either::Right(e) => e.into(),
-            })
+        match self.parse_ts_property_or_method_signature(start, readonly)? {
+            Either::Left(e) => Ok(TsPropertySignature { variance, ..e }.into()),
+            Either::Right(e) => {
+                if variance.is_some() {
+                    syntax_error!(self, e.span, SyntaxError::FlowVarianceOnMethod)
+                }
+                Ok(e.into())
+            }
+        }
     }
 
     /// `tsIsStartOfConstructSignature`
     fn is_ts_start_of_construct_signature(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         bump!();
 
@@ -1109,7 +1176,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeLiteral`
     fn parse_ts_type_lit(&mut self) -> PResult<'a, TsTypeLit> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         let members = self.parse_ts_object_type_members()?;
@@ -1121,18 +1188,23 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseObjectTypeMembers`
     fn parse_ts_object_type_members(&mut self) -> PResult<'a, Vec<TsTypeElement>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         expect!('{');
+        // Flow: `{| a: T |}`
+        let exact = self.input.syntax().flow() && eat!('|');
         let members =
             self.parse_ts_list(ParsingContext::TypeMembers, |p| p.parse_ts_type_member())?;
+        if exact {
+            expect!('|');
+        }
         expect!('}');
         Ok(members)
     }
 
     /// `tsIsStartOfMappedType`
     fn is_ts_start_of_mapped_type(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         bump!();
         if eat!('+') || eat!('-') {
@@ -1155,7 +1227,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseMappedTypeParameter`
     fn parse_ts_mapped_type_param(&mut self) -> PResult<'a, TsTypeParam> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         let name = self.parse_ident_name()?;
@@ -1164,6 +1236,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         Ok(TsTypeParam {
             span: span!(start),
             name,
+            variance: None,
             constraint,
             default: None,
         })
@@ -1171,7 +1244,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseMappedType`
     fn parse_ts_mapped_type(&mut self) -> PResult<'a, TsMappedType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         expect!('{');
@@ -1220,7 +1293,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTupleType`
     fn parse_ts_tuple_type(&mut self) -> PResult<'a, TsTupleType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         let elem_types = self.parse_ts_bracketed_list(
@@ -1261,7 +1334,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTupleElementType`
     fn parse_ts_tuple_element_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // parses `...TsType[]`
         let start = cur_pos!();
@@ -1289,7 +1362,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseParenthesizedType`
     fn parse_ts_parenthesized_type(&mut self) -> PResult<'a, TsParenthesizedType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         expect!('(');
@@ -1306,7 +1379,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         &mut self,
         is_fn_type: bool,
     ) -> PResult<'a, TsFnOrConstructorType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         if !is_fn_type {
@@ -1339,7 +1412,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseLiteralTypeNode`
     fn parse_ts_lit_type_node(&mut self) -> PResult<'a, TsLitType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
 
@@ -1358,7 +1431,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseBindingListForSignature`
     fn parse_ts_binding_list_for_signature(&mut self) -> PResult<'a, Vec<TsFnParam>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let pats = self.parse_formal_params()?;
         let mut list = vec![];
@@ -1413,7 +1486,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseNonArrayType`
     fn parse_ts_non_array_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
 
@@ -1443,6 +1516,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     Some(TsKeywordTypeKind::TsUnknownKeyword)
                 } else if is!("undefined") {
                     Some(TsKeywordTypeKind::TsUndefinedKeyword)
+                } else if self.input.syntax().flow() && is!("mixed") {
+                    Some(TsKeywordTypeKind::TsUnknownKeyword)
                 } else {
                     None
                 };
@@ -1482,6 +1557,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     Lit::Num(num) => TsLit::Number(Number {
                         span: num.span,
                         value: -num.value,
+                        raw: None,
                     }),
                     _ => unreachable!(),
                 };
@@ -1517,6 +1593,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
             tok!('[') => {
                 return self.parse_ts_tuple_type().map(TsType::from).map(Box::new);
             }
+            // Flow: existential type
+            tok!('*') if self.input.syntax().flow() => {
+                bump!();
+                return Ok(Box::new(TsType::TsKeywordType(TsKeywordType {
+                    span: span!(start),
+                    kind: TsKeywordTypeKind::TsAnyKeyword,
+                })));
+            }
             tok!('(') => {
                 return self
                     .parse_ts_parenthesized_type()
@@ -1533,7 +1617,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseArrayTypeOrHigher`
     fn parse_ts_array_type_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let mut ty = self.parse_ts_non_array_type()?;
 
@@ -1559,7 +1643,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeOperator`
     fn parse_ts_type_operator(&mut self, op: TsTypeOperatorOp) -> PResult<'a, TsTypeOperator> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         match op {
@@ -1568,7 +1652,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             TsTypeOperatorOp::ReadOnly => expect!("readonly"),
         }
 
-        let type_ann = self.parse_ts_type_operator_or_higher()?;
+        let type_ann = self.nested(|p| p.parse_ts_type_operator_or_higher())?;
         Ok(TsTypeOperator {
             span: span!(start),
             op,
@@ -1578,7 +1662,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseInferType`
     fn parse_ts_infer_type(&mut self) -> PResult<'a, TsInferType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         expect!("infer");
@@ -1586,6 +1670,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let type_param = TsTypeParam {
             span: type_param_name.span(),
             name: type_param_name,
+            variance: None,
             constraint: None,
             default: None,
         };
@@ -1597,7 +1682,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeOperatorOrHigher`
     fn parse_ts_type_operator_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let operator = if is!("keyof") {
             Some(TsTypeOperatorOp::KeyOf)
@@ -1617,6 +1702,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
             None => {
                 if is!("infer") {
                     self.parse_ts_infer_type().map(TsType::from).map(Box::new)
+                } else if self.input.syntax().flow() && is!('?') {
+                    self.parse_flow_maybe_type()
                 } else {
                     self.parse_ts_array_type_or_higher()
                 }
@@ -1624,6 +1711,35 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
     }
 
+    /// Flow: `?T`, which is represented as `T | null | undefined`.
+    fn parse_flow_maybe_type(&mut self) -> PResult<'a, Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!();
+        assert_and_bump!('?');
+
+        let ty = self.parse_ts_type_operator_or_higher()?;
+        let span = span!(start);
+
+        Ok(Box::new(
+            TsUnionType {
+                span,
+                types: vec![
+                    ty,
+                    Box::new(TsType::TsKeywordType(TsKeywordType {
+                        span,
+                        kind: TsKeywordTypeKind::TsNullKeyword,
+                    })),
+                    Box::new(TsType::TsKeywordType(TsKeywordType {
+                        span,
+                        kind: TsKeywordTypeKind::TsUndefinedKeyword,
+                    })),
+                ],
+            }
+            .into(),
+        ))
+    }
+
     /// `tsParseExpressionStatement`
     pub(super) fn parse_ts_expr_stmt(
         &mut self,
@@ -1734,6 +1850,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 return p.parse_var_stmt(false).map(From::from).map(Some);
             }
 
+            // Flow: `declare export function foo(): void;`
+            if p.input.syntax().flow() && eat!("export") {
+                return p.try_parse_ts_declare(start, decorators);
+            }
+
             if is!("global") {
                 return p
                     .parse_ts_ambient_external_module_decl(start)
@@ -1777,7 +1898,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         next: bool,
     ) -> PResult<'a, Option<Decl>> {
         match value {
-            js_word!("abstract") => {
+            js_word!("abstract") if self.input.syntax().typescript() => {
                 if next || is!("class") {
                     if next {
                         bump!();
@@ -1798,7 +1919,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }
             }
 
-            js_word!("enum") => {
+            js_word!("enum") if self.input.syntax().typescript() => {
                 if next || is!(IdentRef) {
                     if next {
                         bump!();
@@ -1843,7 +1964,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }
             }
 
-            js_word!("namespace") => {
+            js_word!("namespace") if self.input.syntax().typescript() => {
                 if next || is!(IdentRef) {
                     if next {
                         bump!();
@@ -1867,6 +1988,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }
             }
 
+            js_word!("opaque") if self.input.syntax().flow() => {
+                if next {
+                    bump!();
+                }
+                if is!("type") && peeked_is!(IdentRef) {
+                    assert_and_bump!("type");
+                    return self
+                        .parse_flow_opaque_type_alias_decl(start)
+                        .map(From::from)
+                        .map(Some);
+                }
+            }
+
             _ => {}
         }
 
@@ -1919,7 +2053,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeArguments`
     pub(super) fn parse_ts_type_args(&mut self) -> PResult<'a, TsTypeParamInstantiation> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!();
         let params = self.in_type().parse_with(|p| {
@@ -1945,7 +2079,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseIntersectionTypeOrHigher`
     fn parse_ts_intersection_type_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.parse_ts_union_or_intersection_type(
             UnionOrIntersection::Intersection,
@@ -1956,7 +2090,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseUnionTypeOrHigher`
     fn parse_ts_union_type_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.parse_ts_union_or_intersection_type(
             UnionOrIntersection::Union,
@@ -1975,16 +2109,22 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnMut(&mut Self) -> PResult<'a, Box<TsType>>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
+
+        // Flow: `|}` closes an exact object type.
+        let is_operator = |p: &mut Self| {
+            p.input.is(operator) && !(p.input.syntax().flow() && p.input.peeked_is(&tok!('}')))
+        };
 
         self.input.eat(operator);
 
         let start = cur_pos!();
         let ty = parse_constituent_type(self)?;
-        if self.input.is(&operator) {
+        if is_operator(self) {
             let mut types = vec![ty];
 
-            while self.input.eat(operator) {
+            while is_operator(self) {
+                bump!();
                 types.push(parse_constituent_type(self)?);
             }
 
@@ -2012,7 +2152,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnOnce(&mut Self) -> PResult<'a, T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let cloned = self.input.token_context().clone();
         self.input
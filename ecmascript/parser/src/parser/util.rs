@@ -98,6 +98,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     pub(super) fn include_in_expr<'w>(&'w mut self, include_in_expr: bool) -> WithCtx<'w, 'a, I> {
         let ctx = Context {
             include_in_expr,
+            in_pipeline_body: self.ctx().in_pipeline_body && !include_in_expr,
             ..self.ctx()
         };
         self.with_ctx(ctx)
@@ -111,6 +112,25 @@ impl<'a, I: Tokens> Parser<'a, I> {
         f(self)
     }
 
+    /// Parse with given closure, one level deeper.
+    ///
+    /// Used for every recursive production, so that deeply nested input results
+    /// in an error instead of a stack overflow.
+    pub(super) fn nested<F, Ret>(&mut self, f: F) -> PResult<'a, Ret>
+    where
+        F: FnOnce(&mut Self) -> PResult<'a, Ret>,
+    {
+        if self.state.depth >= self.max_depth {
+            let span = self.input.cur_span();
+            syntax_error!(self, span, SyntaxError::TooDeep)
+        }
+
+        self.state.depth += 1;
+        let res = f(self);
+        self.state.depth -= 1;
+        res
+    }
+
     /// Creates a span from `start` to current pos.
     pub(super) fn span(&mut self, start: BytePos) -> Span {
         let end = last_pos!(self);
@@ -127,6 +147,34 @@ impl<'a, I: Tokens> Parser<'a, I> {
     pub(super) fn syntax(&self) -> Syntax {
         self.input.syntax()
     }
+
+    /// Returns an error if `syntax` is newer than the target version.
+    pub(super) fn ensure_es_version(
+        &self,
+        span: Span,
+        version: EsVersion,
+        syntax: &'static str,
+    ) -> PResult<'a, ()> {
+        if self.syntax().target() < version {
+            syntax_error!(
+                self,
+                span,
+                SyntaxError::RequiresEsVersion { syntax, version }
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Returns an error if `super` is not allowed in the current context.
+    pub(super) fn ensure_super_allowed(&self, span: Span) -> PResult<'a, ()> {
+        let ctx = self.ctx();
+        if !ctx.in_method && !ctx.in_class_prop && !self.syntax().allow_super_outside_method() {
+            syntax_error!(self, span, SyntaxError::SuperOutsideMethod)
+        }
+
+        Ok(())
+    }
 }
 pub trait ParseObject<'a, Obj> {
     type Prop;
@@ -223,6 +271,8 @@ pub(super) trait ExprExt {
             }
 
             Expr::TsConstAssertion(..) => false,
+
+            Expr::ArgPlaceholder(..) | Expr::Do(..) | Expr::Throw(..) => false,
         }
     }
 }
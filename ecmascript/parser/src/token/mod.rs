@@ -195,6 +195,9 @@ pub enum BinOpToken {
     LogicalOr,
     /// `&&`
     LogicalAnd,
+
+    /// `|>`
+    Pipeline,
 }
 
 impl BinOpToken {
@@ -531,6 +534,7 @@ impl From<BinOpToken> for BinaryOp {
             BinOpToken::LogicalOr => LogicalOr,
             BinOpToken::LogicalAnd => LogicalAnd,
             BinOpToken::Exp => Exp,
+            BinOpToken::Pipeline => Pipeline,
         }
     }
 }
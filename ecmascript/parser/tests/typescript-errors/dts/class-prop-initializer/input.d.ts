@@ -0,0 +1 @@
+declare class A { a = 1; }
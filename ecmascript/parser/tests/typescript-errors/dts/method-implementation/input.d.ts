@@ -0,0 +1 @@
+export declare class A { m(): void {} }
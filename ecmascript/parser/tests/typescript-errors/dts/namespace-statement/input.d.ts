@@ -0,0 +1 @@
+declare namespace A { foo(); }
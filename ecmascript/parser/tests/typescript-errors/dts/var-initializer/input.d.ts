@@ -0,0 +1 @@
+declare let a = 1;
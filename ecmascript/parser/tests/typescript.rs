@@ -159,6 +159,7 @@ where
                 Session { handler: &handler },
                 Syntax::Typescript(TsConfig {
                     tsx: fname.contains("tsx"),
+                    dts: fname.ends_with(".d.ts"),
                     dynamic_import: true,
                     decorators: true,
                     ..Default::default()
@@ -101,6 +101,7 @@ where
                                             }
                                             .into()],
                                             src: None,
+                                            type_only: false,
                                         },
                                     )) {
                                         Ok(t) => t,
@@ -582,7 +583,7 @@ impl Classes {
                         box Expr::Lit(Lit::Str(quote_str!(i.span, i.sym.clone())))
                     }
                     PropName::Str(ref s) => box Expr::Lit(Lit::Str(s.clone())),
-                    PropName::Num(n) => box Expr::Lit(Lit::Num(n)),
+                    PropName::Num(ref n) => box Expr::Lit(Lit::Num(n.clone())),
                     PropName::Computed(ref expr) => expr.clone(),
                 },
             })
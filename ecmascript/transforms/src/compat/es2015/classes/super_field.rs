@@ -123,6 +123,7 @@ impl<'a> Fold<Expr> for SuperCalleeFolder<'a> {
                         box Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: 1.0.into(),
+                            raw: None,
                         })),
                     )
                 }
@@ -205,6 +206,7 @@ impl<'a> SuperCalleeFolder<'a> {
                 span,
                 value: value.clone(),
                 has_escape: false,
+                raw: None,
             })),
             ref expr => expr.clone(),
         }
@@ -280,6 +282,7 @@ impl<'a> SuperCalleeFolder<'a> {
                 span,
                 value: value.clone(),
                 has_escape: false,
+                raw: None,
             })),
             ref e => e.clone(),
         };
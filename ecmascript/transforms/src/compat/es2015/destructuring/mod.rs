@@ -172,6 +172,7 @@ impl Fold<Vec<VarDeclarator>> for AssignFolder {
                                     args: vec![Lit::Num(Number {
                                         value: i as f64,
                                         span: dot3_token,
+                                        raw: None,
                                     })
                                     .as_arg()],
                                     type_args: Default::default(),
@@ -742,6 +743,7 @@ fn make_cond_expr(tmp: Ident, def_value: Box<Expr>) -> Expr {
                 arg: box Expr::Lit(Lit::Num(Number {
                     span: DUMMY_SP,
                     value: 0.0,
+                    raw: None,
                 })),
             }),
         }),
@@ -800,6 +802,12 @@ fn can_be_null(e: &Expr) -> bool {
         | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. })
         | Expr::TsConstAssertion(TsConstAssertion { ref expr, .. }) => can_be_null(expr),
         Expr::TsOptChain(ref e) => can_be_null(&e.expr),
+
+        Expr::Do(..) => true,
+        // Never produces a value
+        Expr::Throw(..) => false,
+
+        Expr::ArgPlaceholder(..) => unreachable!("destructuring partial application"),
     }
 }
 
@@ -0,0 +1,71 @@
+use crate::pass::Pass;
+use ast::*;
+use swc_common::Fold;
+
+/// `@babel/plugin-transform-literals`
+///
+/// Removes the raw text of binary / octal numeric literals and of strings with
+/// unicode code point escapes, so they are printed from their values.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// var b = 0b11;
+/// var o = 0o7;
+/// var u = 'Hello\u{000A}\u{0009}!';
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var b = 3;
+/// var o = 7;
+/// var u = 'Hello\n\t!';
+/// ```
+pub fn literals() -> impl Pass {
+    Literals
+}
+
+struct Literals;
+
+impl Fold<Number> for Literals {
+    fn fold(&mut self, n: Number) -> Number {
+        match n.raw.as_ref().and_then(|raw| raw.get(..2)) {
+            Some("0b") | Some("0B") | Some("0o") | Some("0O") => Number { raw: None, ..n },
+            _ => n,
+        }
+    }
+}
+
+impl Fold<Str> for Literals {
+    fn fold(&mut self, s: Str) -> Str {
+        if !s.raw.as_ref().map_or(false, |raw| raw.contains("\\u{")) {
+            return s;
+        }
+
+        Str { raw: None, ..s }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| literals(),
+        binary_and_octal,
+        "var b = 0b11; var o = 0O7; var h = 0xFF;",
+        "var b = 3; var o = 7; var h = 0xFF;"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| literals(),
+        unicode_escape,
+        r#"var u = 'Hello\u{000A}\u{0009}!'; var v = 'ab';"#,
+        r#"var u = 'Hello\n\t!'; var v = 'ab';"#
+    );
+}
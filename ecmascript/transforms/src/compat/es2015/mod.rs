@@ -2,8 +2,9 @@ pub use self::{
     arrow::arrow, block_scoped_fn::BlockScopedFns, block_scoping::block_scoping, classes::Classes,
     computed_props::computed_properties, destructuring::destructuring,
     duplicate_keys::duplicate_keys, for_of::for_of, function_name::function_name,
-    instanceof::InstanceOf, parameters::parameters, shorthand_property::Shorthand, spread::spread,
-    sticky_regex::StickyRegex, template_literal::TemplateLiteral, typeof_symbol::TypeOfSymbol,
+    instanceof::InstanceOf, literals::literals, parameters::parameters,
+    shorthand_property::Shorthand, spread::spread, sticky_regex::StickyRegex,
+    template_literal::TemplateLiteral, typeof_symbol::TypeOfSymbol,
 };
 use crate::pass::Pass;
 use ast::{Expr, Module};
@@ -18,6 +19,7 @@ mod duplicate_keys;
 mod for_of;
 mod function_name;
 mod instanceof;
+mod literals;
 mod parameters;
 mod shorthand_property;
 mod spread;
@@ -47,6 +49,7 @@ pub fn es2015() -> impl Pass {
         spread(),
         function_name(),
         exprs(),
+        literals(),
         parameters(),
         for_of(),
         computed_properties(),
@@ -303,6 +303,7 @@ impl Fold<ModuleDecl> for RestFolder {
                     span,
                     specifiers,
                     src: None,
+                    type_only: false,
                 };
 
                 let mut var_decl = var_decl.fold_with(self);
@@ -722,11 +723,12 @@ impl RestFolder {
                                 PropName::Str(Str {
                                     ref value, span, ..
                                 }) => box Expr::Ident(quote_ident!(span, value.clone())),
-                                PropName::Num(Number { span, value }) => {
+                                PropName::Num(Number { span, value, .. }) => {
                                     box Expr::Lit(Lit::Str(Str {
                                         span,
                                         value: format!("{}", value).into(),
                                         has_escape: false,
+                                        raw: None,
                                     }))
                                 }
                                 PropName::Computed(ref expr) => expr.clone(),
@@ -826,13 +828,15 @@ fn excluded_props(props: &[ObjectPatProp]) -> Vec<Option<ExprOrSpread>> {
                     span: ident.span,
                     value: ident.sym.clone(),
                     has_escape: false,
+                    raw: None,
                 })
                 .as_arg(),
                 PropName::Str(s) => Lit::Str(s.clone()).as_arg(),
-                PropName::Num(Number { span, value }) => Lit::Str(Str {
+                PropName::Num(Number { span, value, .. }) => Lit::Str(Str {
                     span: *span,
                     value: format!("{}", value).into(),
                     has_escape: false,
+                    raw: None,
                 })
                 .as_arg(),
                 PropName::Computed(expr) => expr.clone().as_arg(),
@@ -841,6 +845,7 @@ fn excluded_props(props: &[ObjectPatProp]) -> Vec<Option<ExprOrSpread>> {
                 span: key.span,
                 value: key.sym.clone(),
                 has_escape: false,
+                raw: None,
             })
             .as_arg(),
             ObjectPatProp::Rest(..) => unreachable!("invalid syntax (multiple rest element)"),
@@ -0,0 +1,8 @@
+pub use self::numeric_separator::numeric_separator;
+use crate::pass::Pass;
+
+mod numeric_separator;
+
+pub fn es2021() -> impl Pass {
+    numeric_separator()
+}
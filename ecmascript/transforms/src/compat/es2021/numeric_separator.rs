@@ -0,0 +1,74 @@
+use crate::pass::Pass;
+use ast::*;
+use swc_common::Fold;
+
+/// `@babel/plugin-proposal-numeric-separator`
+///
+/// Removes numeric separators from the raw text of numeric literals, so they
+/// are never printed.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// let budget = 1_000_000_000_000;
+/// let mask = 0b1010_0001;
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// let budget = 1000000000000;
+/// let mask = 0b10100001;
+/// ```
+pub fn numeric_separator() -> impl Pass {
+    NumericSeparator
+}
+
+struct NumericSeparator;
+
+impl Fold<Number> for NumericSeparator {
+    fn fold(&mut self, n: Number) -> Number {
+        if !n.raw.as_ref().map_or(false, |raw| raw.contains('_')) {
+            return n;
+        }
+
+        Number {
+            raw: n.raw.map(|raw| raw.replace('_', "").into()),
+            ..n
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::DUMMY_SP;
+
+    fn lower(raw: &str) -> Option<String> {
+        let n = NumericSeparator.fold(Number {
+            span: DUMMY_SP,
+            value: 0.0,
+            raw: Some(raw.into()),
+        });
+        n.raw.map(|raw| raw.to_string())
+    }
+
+    #[test]
+    fn decimal() {
+        assert_eq!(lower("1_000_000"), Some("1000000".into()));
+        assert_eq!(lower("1_0.2_5e1_0"), Some("10.25e10".into()));
+    }
+
+    #[test]
+    fn radix() {
+        assert_eq!(lower("0xF_F"), Some("0xFF".into()));
+        assert_eq!(lower("0b1010_0001"), Some("0b10100001".into()));
+    }
+
+    #[test]
+    fn no_separator() {
+        assert_eq!(lower("1000"), Some("1000".into()));
+    }
+}
@@ -35,6 +35,7 @@ impl Fold<MemberExpr> for MemberExprLit {
                             span: $span,
                             value: $sym,
                             has_escape: false,
+                            raw: None,
                         })),
                         ..e
                     };
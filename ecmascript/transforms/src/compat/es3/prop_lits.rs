@@ -45,6 +45,7 @@ impl Fold<PropName> for PropertyLiteral {
                         span,
                         value: sym,
                         has_escape: false,
+                        raw: None,
                     });
                 } else {
                     PropName::Ident(Ident::new(sym, span))
@@ -57,6 +58,7 @@ impl Fold<PropName> for PropertyLiteral {
                         span,
                         value: sym,
                         has_escape: false,
+                        raw: None,
                     });
                 } else {
                     PropName::Ident(Ident { span, sym, ..i })
@@ -1,9 +1,14 @@
 //! New-generation javascript to old-javascript compiler.
 
-pub use self::{es2015::es2015, es2016::es2016, es2017::es2017, es2018::es2018, es3::es3};
+pub use self::{
+    es2015::es2015, es2016::es2016, es2017::es2017, es2018::es2018, es2021::es2021, es3::es3,
+    verify::verify,
+};
 
 pub mod es2015;
 pub mod es2016;
 pub mod es2017;
 pub mod es2018;
+pub mod es2021;
 pub mod es3;
+mod verify;
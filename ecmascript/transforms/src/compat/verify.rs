@@ -0,0 +1,432 @@
+use crate::{pass::Pass, util::HANDLER};
+use ast::*;
+use swc_common::{Fold, Span, Spanned, Visit, VisitWith};
+use swc_ecma_parser::EsVersion;
+
+/// Reports syntax which is not supported by `target`.
+///
+/// This is meant to be applied after compat passes, to ensure that they did
+/// not leave newer syntax in the output. Module syntax and syntax of es5 are
+/// not checked.
+pub fn verify(target: EsVersion) -> impl Pass {
+    Verify { target }
+}
+
+struct Verify {
+    target: EsVersion,
+}
+
+impl Verify {
+    fn require(&self, span: Span, version: EsVersion, syntax: &str) {
+        if self.target >= version {
+            return;
+        }
+
+        HANDLER.with(|handler| {
+            handler
+                .struct_span_err(
+                    span,
+                    &format!(
+                        "{} requires {}, but the target is {}",
+                        syntax, version, self.target
+                    ),
+                )
+                .emit()
+        });
+    }
+}
+
+impl Fold<Module> for Verify {
+    fn fold(&mut self, module: Module) -> Module {
+        module.visit_with(self);
+        module
+    }
+}
+
+impl Visit<Function> for Verify {
+    fn visit(&mut self, f: &Function) {
+        if f.is_async && f.is_generator {
+            self.require(f.span, EsVersion::Es2018, "async generator");
+        } else if f.is_async {
+            self.require(f.span, EsVersion::Es2017, "async function");
+        } else if f.is_generator {
+            self.require(f.span, EsVersion::Es2015, "generator");
+        }
+
+        f.visit_children(self);
+    }
+}
+
+impl Visit<ArrowExpr> for Verify {
+    fn visit(&mut self, f: &ArrowExpr) {
+        self.require(f.span, EsVersion::Es2015, "arrow function");
+        if f.is_async {
+            self.require(f.span, EsVersion::Es2017, "async function");
+        }
+
+        f.visit_children(self);
+    }
+}
+
+impl Visit<Class> for Verify {
+    fn visit(&mut self, c: &Class) {
+        self.require(c.span, EsVersion::Es2015, "class");
+
+        c.visit_children(self);
+    }
+}
+
+impl Visit<VarDecl> for Verify {
+    fn visit(&mut self, v: &VarDecl) {
+        match v.kind {
+            VarDeclKind::Let => self.require(v.span, EsVersion::Es2015, "let declaration"),
+            VarDeclKind::Const => self.require(v.span, EsVersion::Es2015, "const declaration"),
+            VarDeclKind::Var => {}
+        }
+
+        v.visit_children(self);
+    }
+}
+
+impl Visit<Tpl> for Verify {
+    fn visit(&mut self, t: &Tpl) {
+        self.require(t.span, EsVersion::Es2015, "template literal");
+
+        t.visit_children(self);
+    }
+}
+
+impl Visit<TaggedTpl> for Verify {
+    fn visit(&mut self, t: &TaggedTpl) {
+        self.require(t.span, EsVersion::Es2015, "template literal");
+
+        t.visit_children(self);
+    }
+}
+
+impl Visit<ForOfStmt> for Verify {
+    fn visit(&mut self, s: &ForOfStmt) {
+        match s.await_token {
+            Some(span) => self.require(span, EsVersion::Es2018, "async iteration"),
+            None => self.require(s.span, EsVersion::Es2015, "for-of loop"),
+        }
+
+        s.visit_children(self);
+    }
+}
+
+impl Visit<ExprOrSpread> for Verify {
+    fn visit(&mut self, e: &ExprOrSpread) {
+        if let Some(span) = e.spread {
+            self.require(span, EsVersion::Es2015, "spread element");
+        }
+
+        e.visit_children(self);
+    }
+}
+
+impl Visit<ObjectLit> for Verify {
+    fn visit(&mut self, o: &ObjectLit) {
+        for prop in &o.props {
+            match *prop {
+                PropOrSpread::Spread(ref s) => {
+                    self.require(s.span(), EsVersion::Es2018, "object rest/spread")
+                }
+                PropOrSpread::Prop(box Prop::Shorthand(ref i)) => {
+                    self.require(i.span, EsVersion::Es2015, "shorthand property")
+                }
+                PropOrSpread::Prop(box Prop::Method(ref m)) => {
+                    self.require(m.function.span, EsVersion::Es2015, "method definition")
+                }
+                _ => {}
+            }
+        }
+
+        o.visit_children(self);
+    }
+}
+
+impl Visit<PropName> for Verify {
+    fn visit(&mut self, n: &PropName) {
+        if let PropName::Computed(ref e) = *n {
+            self.require(e.span(), EsVersion::Es2015, "computed property name");
+        }
+
+        n.visit_children(self);
+    }
+}
+
+impl Visit<Pat> for Verify {
+    fn visit(&mut self, p: &Pat) {
+        match *p {
+            Pat::Array(..) => self.require(p.span(), EsVersion::Es2015, "destructuring"),
+            Pat::Object(ref o) => {
+                self.require(o.span, EsVersion::Es2015, "destructuring");
+                for prop in &o.props {
+                    if let ObjectPatProp::Rest(ref r) = *prop {
+                        self.require(r.dot3_token, EsVersion::Es2018, "object rest/spread");
+                    }
+                }
+            }
+            Pat::Rest(ref r) => self.require(r.dot3_token, EsVersion::Es2015, "rest element"),
+            Pat::Assign(ref a) => self.require(a.span, EsVersion::Es2015, "default value"),
+            _ => {}
+        }
+
+        p.visit_children(self);
+    }
+}
+
+impl Visit<MetaPropExpr> for Verify {
+    fn visit(&mut self, e: &MetaPropExpr) {
+        if e.meta.sym == js_word!("new") {
+            self.require(e.span(), EsVersion::Es2015, "new.target");
+        } else {
+            self.require(e.span(), EsVersion::Es2020, "import.meta");
+        }
+    }
+}
+
+impl Visit<BinExpr> for Verify {
+    fn visit(&mut self, e: &BinExpr) {
+        if e.op == op!("**") {
+            self.require(e.span, EsVersion::Es2016, "exponentiation operator");
+        }
+
+        e.visit_children(self);
+    }
+}
+
+impl Visit<AssignExpr> for Verify {
+    fn visit(&mut self, e: &AssignExpr) {
+        if e.op == op!("**=") {
+            self.require(e.span, EsVersion::Es2016, "exponentiation operator");
+        }
+
+        e.visit_children(self);
+    }
+}
+
+impl Visit<CatchClause> for Verify {
+    fn visit(&mut self, c: &CatchClause) {
+        if c.param.is_none() {
+            self.require(c.span, EsVersion::Es2019, "optional catch binding");
+        }
+
+        c.visit_children(self);
+    }
+}
+
+impl Visit<TsOptChain> for Verify {
+    fn visit(&mut self, e: &TsOptChain) {
+        self.require(e.span, EsVersion::Es2020, "optional chaining");
+
+        e.visit_children(self);
+    }
+}
+
+impl Visit<Number> for Verify {
+    fn visit(&mut self, n: &Number) {
+        // Source text of numbers is printed as-is.
+        let raw = match n.raw {
+            Some(ref raw) => raw,
+            None => return,
+        };
+        if raw.contains('_') {
+            self.require(n.span, EsVersion::Es2021, "numeric separator");
+        }
+        match raw.get(..2) {
+            Some("0b") | Some("0B") | Some("0o") | Some("0O") => {
+                self.require(n.span, EsVersion::Es2015, "binary and octal literal")
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Visit<Regex> for Verify {
+    fn visit(&mut self, r: &Regex) {
+        let flags = match r.flags {
+            Some(ref flags) => flags,
+            None => return,
+        };
+        if flags.value.contains('u') {
+            self.require(r.span, EsVersion::Es2015, "regular expression flag `u`");
+        }
+        if flags.value.contains('y') {
+            self.require(r.span, EsVersion::Es2015, "regular expression flag `y`");
+        }
+        if flags.value.contains('s') {
+            self.require(r.span, EsVersion::Es2018, "regular expression flag `s`");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::Tester;
+    use swc_ecma_parser::{EsConfig, Syntax};
+
+    fn es() -> Syntax {
+        Syntax::Es(EsConfig {
+            num_sep: true,
+            ..Default::default()
+        })
+    }
+
+    fn errors(syntax: Syntax, target: EsVersion, src: &str) -> String {
+        ::testing::run_test(false, |cm, handler| {
+            HANDLER.set(handler, || {
+                Tester {
+                    cm,
+                    handler,
+                    comments: Default::default(),
+                }
+                .apply_transform(verify(target), "input.js", syntax, src)
+            })?;
+            if handler.has_errors() {
+                return Err(());
+            }
+            Ok(())
+        })
+        .err()
+        .map(|err| err.to_string())
+        .unwrap_or_default()
+    }
+
+    fn ok(target: EsVersion, src: &str) {
+        let errors = errors(es(), target, src);
+        assert!(errors.is_empty(), "unexpected errors:\n{}", errors);
+    }
+
+    fn err(target: EsVersion, src: &str, msg: &str) {
+        let errors = errors(es(), target, src);
+        assert!(
+            errors.contains(msg),
+            "expected `{}` but got:\n{}",
+            msg,
+            errors
+        );
+    }
+
+    #[test]
+    fn es5() {
+        ok(
+            EsVersion::Es5,
+            "var a = function () { return 1; }; var b = { c: 1 };",
+        );
+        err(
+            EsVersion::Es5,
+            "var a = () => 1;",
+            "arrow function requires es2015",
+        );
+        err(
+            EsVersion::Es5,
+            "let a = 1;",
+            "let declaration requires es2015",
+        );
+        err(EsVersion::Es5, "class A {}", "class requires es2015");
+        err(
+            EsVersion::Es5,
+            "var { a } = b;",
+            "destructuring requires es2015",
+        );
+        err(EsVersion::Es5, "f(...a);", "spread element requires es2015");
+        err(
+            EsVersion::Es5,
+            "a = `b`;",
+            "template literal requires es2015",
+        );
+        err(
+            EsVersion::Es5,
+            "a = /b/y;",
+            "regular expression flag `y` requires es2015",
+        );
+    }
+
+    #[test]
+    fn not_lowered() {
+        err(
+            EsVersion::Es5,
+            "function* a() {}",
+            "generator requires es2015",
+        );
+        err(
+            EsVersion::Es5,
+            "function a() { return new.target; }",
+            "new.target requires es2015",
+        );
+        err(
+            EsVersion::Es5,
+            "a = /b/u;",
+            "regular expression flag `u` requires es2015",
+        );
+        err(
+            EsVersion::Es2017,
+            "async function* a() {}",
+            "async generator requires es2018",
+        );
+        err(
+            EsVersion::Es2017,
+            "async function a() { for await (b of c); }",
+            "async iteration requires es2018",
+        );
+        err(
+            EsVersion::Es2017,
+            "a = /b/s;",
+            "regular expression flag `s` requires es2018",
+        );
+        err(
+            EsVersion::Es2019,
+            "a = import.meta;",
+            "import.meta requires es2020",
+        );
+        ok(
+            EsVersion::Es2018,
+            "async function* a() { for await (b of c); } d = /e/su;",
+        );
+
+        let errors = errors(
+            Syntax::Typescript(Default::default()),
+            EsVersion::Es2019,
+            "a?.b;",
+        );
+        assert!(
+            errors.contains("optional chaining requires es2020"),
+            "unexpected errors:\n{}",
+            errors
+        );
+    }
+
+    #[test]
+    fn es2015() {
+        ok(EsVersion::Es2015, "let a = () => `${b}`;");
+        err(
+            EsVersion::Es2015,
+            "a ** b;",
+            "exponentiation operator requires es2016",
+        );
+        err(
+            EsVersion::Es2016,
+            "async function a() {}",
+            "async function requires es2017",
+        );
+        err(
+            EsVersion::Es2017,
+            "a = { ...b };",
+            "object rest/spread requires es2018",
+        );
+        err(
+            EsVersion::Es2018,
+            "try {} catch {}",
+            "optional catch binding requires es2019",
+        );
+        err(
+            EsVersion::Es2020,
+            "a = 1_000;",
+            "numeric separator requires es2021",
+        );
+        ok(EsVersion::Es2021, "a = 1_000;");
+    }
+}
@@ -263,6 +263,12 @@ impl Fold<Expr> for Fixer {
                 computed,
                 obj: ExprOrSuper::Expr(obj @ box Expr::Await(..)),
                 prop,
+            })
+            | Expr::Member(MemberExpr {
+                span,
+                computed,
+                obj: ExprOrSuper::Expr(obj @ box Expr::Throw(..)),
+                prop,
             }) => MemberExpr {
                 span,
                 computed,
@@ -379,7 +385,8 @@ impl Fold<Expr> for Fixer {
                     | e @ Expr::Yield(..)
                     | e @ Expr::Cond(..)
                     | e @ Expr::Assign(..)
-                    | e @ Expr::Arrow(..) => Expr::Bin(BinExpr {
+                    | e @ Expr::Arrow(..)
+                    | e @ Expr::Throw(..) => Expr::Bin(BinExpr {
                         left: box e.wrap_with_paren(),
                         ..expr
                     }),
@@ -435,6 +442,20 @@ impl Fold<Expr> for Fixer {
                 Expr::Unary(UnaryExpr { arg, ..expr })
             }
 
+            Expr::Throw(expr) => {
+                let arg = match *expr.arg {
+                    e @ Expr::Assign(..)
+                    | e @ Expr::Bin(..)
+                    | e @ Expr::Seq(..)
+                    | e @ Expr::Cond(..)
+                    | e @ Expr::Arrow(..)
+                    | e @ Expr::Yield(..) => box e.wrap_with_paren(),
+                    _ => expr.arg,
+                };
+
+                Expr::Throw(ThrowExpr { arg, ..expr })
+            }
+
             Expr::Assign(expr) => {
                 let right = match *expr.right {
                     // `foo = (bar = baz)` => foo = bar = baz
@@ -521,6 +542,9 @@ fn handle_expr_stmt(expr: Expr) -> Expr {
         // It's important for arrow pass to work properly.
         Expr::Object(..) | Expr::Class(..) | Expr::Fn(..) => expr.wrap_with_paren(),
 
+        // `do {}` and `throw` would be parsed as statements.
+        Expr::Do(..) | Expr::Throw(..) => expr.wrap_with_paren(),
+
         // ({ a } = foo)
         Expr::Assign(AssignExpr {
             span,
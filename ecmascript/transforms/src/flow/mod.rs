@@ -0,0 +1,64 @@
+use crate::{pass::Pass, typescript};
+use ast::*;
+use swc_common::{util::move_map::MoveMap, Fold, FoldWith};
+
+#[cfg(test)]
+mod tests;
+
+/// Strips flow type annotations out.
+///
+/// Flow types are parsed as typescript types, so this reuses
+/// `typescript::strip` and additionally removes type-only imports / exports
+/// and `declare` statements.
+pub fn strip() -> impl Pass {
+    chain!(StripTypeOnly, typescript::strip())
+}
+
+struct StripTypeOnly;
+
+impl StripTypeOnly {
+    fn is_declare(decl: &Decl) -> bool {
+        match *decl {
+            Decl::Class(ClassDecl { declare, .. })
+            | Decl::Fn(FnDecl { declare, .. })
+            | Decl::Var(VarDecl { declare, .. }) => declare,
+            _ => false,
+        }
+    }
+}
+
+impl Fold<Vec<ModuleItem>> for StripTypeOnly {
+    fn fold(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        let items = items.fold_children(self);
+
+        items.move_flat_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                type_only: true, ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                type_only: true,
+                ..
+            })) => None,
+
+            ModuleItem::Stmt(Stmt::Decl(ref decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { ref decl, .. }))
+                if Self::is_declare(decl) =>
+            {
+                None
+            }
+
+            _ => Some(item),
+        })
+    }
+}
+
+impl Fold<Vec<Stmt>> for StripTypeOnly {
+    fn fold(&mut self, stmts: Vec<Stmt>) -> Vec<Stmt> {
+        let stmts = stmts.fold_children(self);
+
+        stmts.move_flat_map(|stmt| match stmt {
+            Stmt::Decl(ref decl) if Self::is_declare(decl) => None,
+            _ => Some(stmt),
+        })
+    }
+}
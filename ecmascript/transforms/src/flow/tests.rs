@@ -0,0 +1,65 @@
+use super::strip;
+
+macro_rules! to {
+    ($name:ident, $from:expr, $to:expr) => {
+        test!(
+            ::swc_ecma_parser::Syntax::Flow(Default::default()),
+            |_| strip(),
+            $name,
+            $from,
+            $to
+        );
+    };
+}
+
+to!(
+    fn_params,
+    "function foo(a: number, b?: string): void {}",
+    "function foo(a, b) {}"
+);
+
+to!(
+    var_annotation,
+    "const a: ?string = null;",
+    "const a = null;"
+);
+
+to!(type_alias, "type A = {| a: number, b: string |};", "");
+
+to!(opaque_type_alias, "opaque type A: string = string;", "");
+
+to!(
+    generic_bound,
+    "function foo<T: Object>(v: T): T { return v; }",
+    "function foo(v) {
+    return v;
+}"
+);
+
+to!(
+    type_cast,
+    "const a = (b: any);
+const c = (d.e: string);",
+    "const a = b;
+const c = d.e;"
+);
+
+to!(
+    import_type,
+    "import type { A } from 'a';
+import typeof B from 'b';
+import C from 'c';",
+    "import C from 'c';"
+);
+
+to!(export_type, "type A = number; export type { A };", "");
+
+to!(
+    declare,
+    "declare var a: number;
+declare function b(): void;
+declare class C {}
+declare export function d(): void;
+e();",
+    "e();"
+);
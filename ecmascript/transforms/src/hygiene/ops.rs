@@ -30,6 +30,7 @@ impl<'a> Fold<Vec<ModuleItem>> for Operator<'a> {
                                 exported: Some($orig),
                             })],
                             src: None,
+                            type_only: false,
                         },
                     )));
                 };
@@ -126,6 +127,7 @@ impl<'a> Fold<Vec<ModuleItem>> for Operator<'a> {
                             span,
                             specifiers: renamed,
                             src: None,
+                            type_only: false,
                         },
                     )));
                 }
@@ -61,6 +61,7 @@ pub mod compat;
 mod const_modules;
 pub mod debug;
 mod fixer;
+pub mod flow;
 mod inline_globals;
 pub mod modules;
 pub mod pass;
@@ -500,6 +500,7 @@ impl Scope {
                                     right: box Expr::Lit(Lit::Num(Number {
                                         span: DUMMY_SP,
                                         value: 1.0,
+                                        raw: None,
                                     })),
                                 }),
                             })
@@ -655,6 +656,7 @@ pub(super) fn make_require_call(src: JsWord) -> Expr {
             span: DUMMY_SP,
             value: src,
             has_escape: false,
+            raw: None,
         })
         .as_arg()],
 
@@ -81,6 +81,7 @@ where
                                             }
                                             .into()],
                                             src: None,
+                                            type_only: false,
                                         },
                                     )) {
                                         Ok(t) => t,
@@ -304,6 +305,7 @@ impl ClassProperties {
                             span: i.span,
                             value: i.sym.clone(),
                             has_escape: false,
+                            raw: None,
                         })
                         .as_arg(),
                         Expr::Lit(ref lit) if !prop.computed => lit.clone().as_arg(),
@@ -7,6 +7,7 @@ use crate::{
 };
 use ast::*;
 use std::iter;
+use swc_atoms::JsWord;
 use swc_common::{util::move_map::MoveMap, Fold, FoldWith, Spanned, Visit, VisitWith, DUMMY_SP};
 
 #[cfg(test)]
@@ -52,15 +53,52 @@ mod tests;
 ///   }
 /// }
 /// ```
+///
+/// ## Accessors (2019-11)
+///
+/// ```js
+/// class C {
+///   @observe accessor value = 1;
+/// }
+/// ```
+///
+/// `accessor` fields are stored in a `WeakMap` and decorated as a getter /
+/// setter pair.
+///
+/// ## Private members (2019-11)
+///
+/// ```js
+/// class C {
+///   @dec #x = 1;
+///   @dec #y() {}
+/// }
+/// ```
+///
+/// As `_decorate` defines members by key, private methods, decorated private
+/// fields and private `accessor` fields are defined with a `Symbol` key, and
+/// `this.#x` is replaced with `this[_x]`.
+///
+/// Accessors and private members are handled only by [decorators_2019_11].
 pub fn decorators() -> impl Pass {
     Decorators {
         is_in_strict: false,
+        accessors: false,
+    }
+}
+
+/// Same as [decorators], but also lowers `accessor` fields and decorated
+/// private members of the 2019-11 proposal.
+pub fn decorators_2019_11() -> impl Pass {
+    Decorators {
+        is_in_strict: false,
+        accessors: true,
     }
 }
 
 #[derive(Clone)]
 struct Decorators {
     is_in_strict: bool,
+    accessors: bool,
 }
 
 impl Fold<Vec<ModuleItem>> for Decorators {
@@ -112,6 +150,7 @@ impl Fold<Vec<ModuleItem>> for Decorators {
                             }
                             .into()],
                             src: None,
+                            type_only: false,
                         }))
                     }))
                 }
@@ -157,7 +196,7 @@ impl Fold<Decl> for Decorators {
                 declare: false,
                 class,
             }) => {
-                if !contains_decorator(&class) {
+                if !contains_decorator(&class) && !(self.accessors && contains_accessor(&class)) {
                     return Decl::Class(ClassDecl {
                         ident,
                         declare: false,
@@ -190,7 +229,7 @@ impl Fold<Expr> for Decorators {
 
         match expr {
             Expr::Class(ClassExpr { ident, class }) => {
-                if !contains_decorator(&class) {
+                if !contains_decorator(&class) && !(self.accessors && contains_accessor(&class)) {
                     return Expr::Class(ClassExpr { ident, class });
                 }
 
@@ -214,6 +253,161 @@ impl Decorators {
         let super_class_expr = class.super_class;
         class.super_class = super_class_ident.clone().map(|i| box Expr::Ident(i));
 
+        let private_keys = if self.accessors {
+            private_keys(&class)
+        } else {
+            Default::default()
+        };
+        // `var _x = Symbol("x");` for each private member defined by `_decorate`,
+        // and `var _x = new WeakMap();` for each `accessor` field.
+        let mut storages = private_keys
+            .iter()
+            .map(|&(ref sym, ref key)| VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(key.clone()),
+                init: Some(box Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: quote_ident!("Symbol").as_callee(),
+                    args: vec![box Expr::Lit(Lit::Str(quote_str!(sym.clone()))).as_arg()],
+                    type_args: Default::default(),
+                })),
+                definite: false,
+            })
+            .collect::<Vec<_>>();
+        if !private_keys.is_empty() {
+            class.body = class.body.fold_with(&mut PrivateToSymbol {
+                keys: private_keys.clone(),
+            });
+        }
+
+        // `_x.set(this, 1)`
+        let mut instance_inits = vec![];
+        // `_x.set(A, 1)`
+        let mut static_inits = vec![];
+        let mut accessor_descriptors = vec![];
+        let mut private_props = vec![];
+
+        class.body = class.body.move_flat_map(|member| {
+            if !self.accessors {
+                return Some(member);
+            }
+
+            let (key, storage, value, is_static, decorators) = match member {
+                ClassMember::ClassProp(prop) => {
+                    if !prop.is_accessor {
+                        return Some(ClassMember::ClassProp(prop));
+                    }
+
+                    let storage = alias_ident_for(&prop.key, "_accessor");
+                    let key = if prop.computed {
+                        prop.key
+                    } else {
+                        match *prop.key {
+                            Expr::Ident(i) => box Expr::Lit(Lit::Str(Str {
+                                span: i.span,
+                                value: i.sym,
+                                has_escape: false,
+                                raw: None,
+                            })),
+                            _ => prop.key,
+                        }
+                    };
+                    (key, storage, prop.value, prop.is_static, prop.decorators)
+                }
+                ClassMember::PrivateProp(prop) => {
+                    let key = match find_key(&private_keys, &prop.key.id.sym) {
+                        Some(key) => key,
+                        None => {
+                            // Left to `class_properties`.
+                            private_props.push(ClassMember::PrivateProp(prop));
+                            return None;
+                        }
+                    };
+                    if !prop.is_accessor {
+                        return Some(ClassMember::ClassProp(ClassProp {
+                            span: prop.span,
+                            key: box Expr::Ident(key),
+                            value: prop.value,
+                            type_ann: prop.type_ann,
+                            is_static: prop.is_static,
+                            decorators: prop.decorators,
+                            computed: true,
+                            accessibility: prop.accessibility,
+                            is_abstract: prop.is_abstract,
+                            is_optional: prop.is_optional,
+                            readonly: prop.readonly,
+                            definite: prop.definite,
+                            is_accessor: false,
+                        }));
+                    }
+
+                    let storage = private_ident!(
+                        prop.key.id.span,
+                        format!("_{}_accessor", prop.key.id.sym)
+                    );
+                    (
+                        box Expr::Ident(key),
+                        storage,
+                        prop.value,
+                        prop.is_static,
+                        prop.decorators,
+                    )
+                }
+                ClassMember::PrivateMethod(method) => {
+                    let key = find_key(&private_keys, &method.key.id.sym)
+                        .expect("private methods should have a key");
+                    return Some(ClassMember::Method(ClassMethod {
+                        span: method.span,
+                        key: PropName::Computed(box Expr::Ident(key)),
+                        function: method.function,
+                        kind: method.kind,
+                        is_static: method.is_static,
+                        accessibility: method.accessibility,
+                        is_abstract: method.is_abstract,
+                        is_optional: method.is_optional,
+                    }));
+                }
+                _ => return Some(member),
+            };
+
+            storages.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(storage.clone()),
+                init: Some(box Expr::New(NewExpr {
+                    span: DUMMY_SP,
+                    callee: box Expr::Ident(quote_ident!("WeakMap")),
+                    args: Some(vec![]),
+                    type_args: Default::default(),
+                })),
+                definite: false,
+            });
+
+            let target = if is_static {
+                box Expr::Ident(ident.clone())
+            } else {
+                box Expr::This(ThisExpr { span: DUMMY_SP })
+            };
+            let init = box Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: storage.clone().member(quote_ident!("set")).as_callee(),
+                args: vec![
+                    target.as_arg(),
+                    value.unwrap_or_else(|| undefined(DUMMY_SP)).as_arg(),
+                ],
+                type_args: Default::default(),
+            });
+            if is_static {
+                static_inits.push(Stmt::Expr(init));
+            } else {
+                instance_inits.push(init);
+            }
+
+            let descriptors = make_accessor_descriptors(key, is_static, decorators, storage);
+            accessor_descriptors.extend(descriptors);
+
+            None
+        });
+
         let constructor = {
             let initialize_call = box Expr::Call(CallExpr {
                 span: DUMMY_SP,
@@ -234,7 +428,10 @@ impl Decorators {
                         ClassMember::Constructor(c) => c,
                         _ => unreachable!(),
                     };
-                    c = inject_after_super(c, vec![initialize_call]);
+                    c = inject_after_super(
+                        c,
+                        iter::once(initialize_call).chain(instance_inits).collect(),
+                    );
 
                     ClassMember::Constructor(c)
                 }
@@ -269,7 +466,10 @@ impl Decorators {
                             ]
                         } else {
                             vec![Stmt::Expr(initialize_call)]
-                        },
+                        }
+                        .into_iter()
+                        .chain(instance_inits.into_iter().map(Stmt::Expr))
+                        .collect(),
                     }),
                 }),
             }
@@ -377,18 +577,24 @@ impl Decorators {
                             span: method.key.id.span,
                             value: method.key.id.sym,
                             has_escape: false,
+                            raw: None,
                         }));
                         fold_method!(method, Some(fn_name), key_prop_value)
                     }
                     ClassMember::ClassProp(prop) => {
                         let prop_span = prop.span();
-                        let key_prop_value = match *prop.key {
-                            Expr::Ident(i) => box Expr::Lit(Lit::Str(Str {
-                                span: i.span,
-                                value: i.sym,
-                                has_escape: false,
-                            })),
-                            _ => prop.key,
+                        let key_prop_value = if prop.computed {
+                            prop.key
+                        } else {
+                            match *prop.key {
+                                Expr::Ident(i) => box Expr::Lit(Lit::Str(Str {
+                                    span: i.span,
+                                    value: i.sym,
+                                    has_escape: false,
+                                    raw: None,
+                                })),
+                                _ => prop.key,
+                            }
                         };
                         //
                         Some(
@@ -471,6 +677,7 @@ impl Decorators {
                     _ => unimplemented!("ClassMember::{:?}", member,),
                 }
             })
+            .chain(accessor_descriptors)
             .map(Some)
             .collect();
 
@@ -502,15 +709,26 @@ impl Decorators {
                                 None
                             }
                             .into_iter()
+                            .chain(if storages.is_empty() {
+                                None
+                            } else {
+                                Some(Stmt::Decl(Decl::Var(VarDecl {
+                                    span: DUMMY_SP,
+                                    kind: VarDeclKind::Var,
+                                    declare: false,
+                                    decls: storages,
+                                })))
+                            })
                             .chain(iter::once(Stmt::Decl(Decl::Class(ClassDecl {
                                 ident: ident.clone(),
                                 class: Class {
                                     decorators: Default::default(),
-                                    body: vec![constructor],
+                                    body: iter::once(constructor).chain(private_props).collect(),
                                     ..class
                                 },
                                 declare: false,
                             }))))
+                            .chain(static_inits)
                             .chain(iter::once(Stmt::Return(ReturnStmt {
                                 span: DUMMY_SP,
                                 arg: Some(box Expr::Object(ObjectLit {
@@ -546,6 +764,106 @@ impl Decorators {
     }
 }
 
+/// Creates `get` and `set` descriptors for an `accessor` field.
+///
+/// Decorators are attached to the getter, as `_decorate` merges a getter and a
+/// setter with the same key.
+fn make_accessor_descriptors(
+    key: Box<Expr>,
+    is_static: bool,
+    decorators: Vec<Decorator>,
+    storage: Ident,
+) -> Vec<ExprOrSpread> {
+    let value = private_ident!("value");
+
+    let getter = Function {
+        span: DUMMY_SP,
+        params: vec![],
+        decorators: vec![],
+        is_async: false,
+        is_generator: false,
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                // _x.get(this)
+                arg: Some(box Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: storage.clone().member(quote_ident!("get")).as_callee(),
+                    args: vec![ThisExpr { span: DUMMY_SP }.as_arg()],
+                    type_args: Default::default(),
+                })),
+            })],
+        }),
+        type_params: Default::default(),
+        return_type: Default::default(),
+    };
+    let setter = Function {
+        params: vec![Pat::Ident(value.clone())],
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            // _x.set(this, value)
+            stmts: vec![Stmt::Expr(box Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: storage.member(quote_ident!("set")).as_callee(),
+                args: vec![ThisExpr { span: DUMMY_SP }.as_arg(), value.as_arg()],
+                type_args: Default::default(),
+            }))],
+        }),
+        ..getter.clone()
+    };
+
+    let descriptor = |kind: &'static str, decorators: Vec<Decorator>, key: Box<Expr>, function| {
+        ObjectLit {
+            span: DUMMY_SP,
+            props: iter::once(PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!("kind")),
+                value: box Expr::Lit(Lit::Str(quote_str!(kind))),
+            })))
+            .chain(if is_static {
+                Some(PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!("static")),
+                    value: box Expr::Lit(Lit::Bool(Bool {
+                        value: true,
+                        span: DUMMY_SP,
+                    })),
+                })))
+            } else {
+                None
+            })
+            .chain(if decorators.is_empty() {
+                None
+            } else {
+                Some(PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!("decorators")),
+                    value: box Expr::Array(ArrayLit {
+                        span: DUMMY_SP,
+                        elems: decorators.into_iter().map(|dec| Some(dec.expr.as_arg())).collect(),
+                    }),
+                })))
+            })
+            .chain(iter::once(PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!("key")),
+                value: key,
+            }))))
+            .chain(iter::once(PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!("value")),
+                value: box Expr::Fn(FnExpr {
+                    ident: None,
+                    function,
+                }),
+            }))))
+            .collect(),
+        }
+        .as_arg()
+    };
+
+    vec![
+        descriptor("get", decorators, key.clone(), getter),
+        descriptor("set", vec![], key, setter),
+    ]
+}
+
 fn make_decorate_call(
     decorators: Vec<Decorator>,
     args: impl Iterator<Item = ExprOrSpread>,
@@ -578,6 +896,96 @@ impl Visit<Decorator> for DecoratorFinder {
     }
 }
 
+/// Returns keys for private members which are defined by `_decorate`.
+///
+/// Undecorated private fields are kept in the class.
+fn private_keys(class: &Class) -> Vec<(JsWord, Ident)> {
+    class
+        .body
+        .iter()
+        .filter_map(|member| match *member {
+            ClassMember::PrivateMethod(PrivateMethod { ref key, .. }) => Some(&key.id),
+            ClassMember::PrivateProp(PrivateProp {
+                ref key,
+                ref decorators,
+                is_accessor,
+                ..
+            }) if is_accessor || !decorators.is_empty() => Some(&key.id),
+            _ => None,
+        })
+        .map(|id| {
+            (
+                id.sym.clone(),
+                private_ident!(id.span, format!("_{}", id.sym)),
+            )
+        })
+        .collect()
+}
+
+fn find_key(keys: &[(JsWord, Ident)], sym: &JsWord) -> Option<Ident> {
+    keys.iter()
+        .find(|&&(ref s, _)| s == sym)
+        .map(|&(_, ref key)| key.clone())
+}
+
+/// Replaces `obj.#x` with `obj[_x]`.
+struct PrivateToSymbol {
+    keys: Vec<(JsWord, Ident)>,
+}
+
+impl Fold<MemberExpr> for PrivateToSymbol {
+    fn fold(&mut self, e: MemberExpr) -> MemberExpr {
+        let e = e.fold_children(self);
+
+        let key = match *e.prop {
+            Expr::PrivateName(ref n) if !e.computed => find_key(&self.keys, &n.id.sym),
+            _ => None,
+        };
+        match key {
+            Some(key) => MemberExpr {
+                prop: box Expr::Ident(key),
+                computed: true,
+                ..e
+            },
+            None => e,
+        }
+    }
+}
+
+impl Fold<Class> for PrivateToSymbol {
+    fn fold(&mut self, c: Class) -> Class {
+        // Private names declared by a nested class shadow ours.
+        let old = self.keys.clone();
+        for member in &c.body {
+            match *member {
+                ClassMember::PrivateMethod(PrivateMethod { ref key, .. })
+                | ClassMember::PrivateProp(PrivateProp { ref key, .. }) => {
+                    self.keys.retain(|&(ref s, _)| *s != key.id.sym);
+                }
+                _ => {}
+            }
+        }
+
+        let c = c.fold_children(self);
+        self.keys = old;
+        c
+    }
+}
+
+fn contains_accessor(class: &Class) -> bool {
+    class.body.iter().any(|member| match *member {
+        ClassMember::ClassProp(ClassProp {
+            is_accessor: true,
+            ..
+        })
+        | ClassMember::PrivateProp(PrivateProp {
+            is_accessor: true,
+            ..
+        }) => true,
+        _ => false,
+    })
+}
+
 fn contains_decorator<N>(node: &N) -> bool
 where
     N: VisitWith<DecoratorFinder>,
@@ -1,6 +1,6 @@
 use super::*;
 use crate::{proposals::class_properties, resolver::resolver, typescript};
-use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
+use swc_ecma_parser::{DecoratorsVersion, EsConfig, Syntax, TsConfig};
 
 fn syntax(decorators_before_export: bool) -> Syntax {
     Syntax::Es(EsConfig {
@@ -11,6 +11,17 @@ fn syntax(decorators_before_export: bool) -> Syntax {
     })
 }
 
+fn syntax_2019_11() -> Syntax {
+    Syntax::Es(EsConfig {
+        decorators: true,
+        decorators_version: DecoratorsVersion::V201911,
+        class_props: true,
+        class_private_props: true,
+        class_private_methods: true,
+        ..Default::default()
+    })
+}
+
 fn tr() -> impl Fold<Module> {
     chain!(decorators(), class_properties(),)
 }
@@ -2015,3 +2026,249 @@ let A = _decorate([], function(_initialize) {
 });
 "
 );
+
+test!(
+    syntax_2019_11(),
+    |_| decorators_2019_11(),
+    accessor_2019_11,
+    r#"
+class A {
+  @dec accessor a = 1;
+  static accessor b;
+}
+"#,
+    r#"
+let A = _decorate([], function (_initialize) {
+  var _a = new WeakMap(), _b = new WeakMap();
+
+  class A {
+    constructor() {
+      _initialize(this);
+      _a.set(this, 1);
+    }
+
+  }
+
+  _b.set(A, void 0);
+
+  return {
+    F: A,
+    d: [{
+      kind: "get",
+      decorators: [dec],
+      key: "a",
+      value: function () {
+        return _a.get(this);
+      }
+    }, {
+      kind: "set",
+      key: "a",
+      value: function (value) {
+        _a.set(this, value);
+      }
+    }, {
+      kind: "get",
+      static: true,
+      key: "b",
+      value: function () {
+        return _b.get(this);
+      }
+    }, {
+      kind: "set",
+      static: true,
+      key: "b",
+      value: function (value) {
+        _b.set(this, value);
+      }
+    }]
+  };
+});
+"#
+);
+
+test_exec!(
+    syntax_2019_11(),
+    |_| chain!(decorators_2019_11(), class_properties()),
+    accessor_2019_11_exec,
+    r#"
+const log = [];
+
+function logged(el) {
+  const get = el.descriptor.get;
+  el.descriptor.get = function () {
+    log.push(el.key);
+    return get.call(this);
+  };
+  return el;
+}
+
+class A {
+  @logged accessor a = 1;
+  static accessor b = 2;
+}
+
+const x = new A();
+expect(x.a).toBe(1);
+x.a = 3;
+expect(x.a).toBe(3);
+expect(new A().a).toBe(1);
+expect(log).toEqual(["a", "a", "a"]);
+expect(Object.keys(x)).toEqual([]);
+expect(A.b).toBe(2);
+A.b = 4;
+expect(A.b).toBe(4);
+"#
+);
+
+test!(
+    syntax_2019_11(),
+    |_| decorators_2019_11(),
+    export_decorated_class_2019_11,
+    r#"
+export @dec class A {}
+"#,
+    r#"
+export let A = _decorate([dec], function (_initialize) {
+  class A {
+    constructor() {
+      _initialize(this);
+    }
+
+  }
+
+  return {
+    F: A,
+    d: []
+  };
+});
+"#
+);
+
+test!(
+    syntax_2019_11(),
+    |_| decorators_2019_11(),
+    private_accessor_2019_11,
+    r#"
+@dec
+class A {
+  accessor #a = 1;
+}
+"#,
+    r#"
+let A = _decorate([dec], function (_initialize) {
+  var _a = Symbol("a"), _a_accessor = new WeakMap();
+
+  class A {
+    constructor() {
+      _initialize(this);
+      _a_accessor.set(this, 1);
+    }
+
+  }
+
+  return {
+    F: A,
+    d: [{
+      kind: "get",
+      key: _a,
+      value: function () {
+        return _a_accessor.get(this);
+      }
+    }, {
+      kind: "set",
+      key: _a,
+      value: function (value) {
+        _a_accessor.set(this, value);
+      }
+    }]
+  };
+});
+"#
+);
+
+test!(
+    syntax_2019_11(),
+    |_| decorators_2019_11(),
+    private_members_2019_11,
+    r#"
+class A {
+  @dec #a = 1;
+  #b = 2;
+  @dec #c() {
+    return this.#a + this.#b;
+  }
+}
+"#,
+    r#"
+let A = _decorate([], function (_initialize) {
+  var _a = Symbol("a"), _c = Symbol("c");
+
+  class A {
+    constructor() {
+      _initialize(this);
+    }
+
+    #b = 2;
+  }
+
+  return {
+    F: A,
+    d: [{
+      kind: "field",
+      decorators: [dec],
+      key: _a,
+      value() {
+        return 1;
+      }
+    }, {
+      kind: "method",
+      decorators: [dec],
+      key: _c,
+      value: function () {
+        return this[_a] + this.#b;
+      }
+    }]
+  };
+});
+"#
+);
+
+test_exec!(
+    syntax_2019_11(),
+    |_| chain!(decorators_2019_11(), class_properties()),
+    private_members_2019_11_exec,
+    r#"
+const log = [];
+
+function logged(el) {
+  const fn = el.descriptor.value;
+  el.descriptor.value = function () {
+    log.push("call");
+    return fn.apply(this, arguments);
+  };
+  return el;
+}
+
+function readonly(el) {
+  el.descriptor.writable = false;
+  return el;
+}
+
+class A {
+  @readonly #a = 1;
+  accessor #b = 2;
+  @logged #c() {
+    return this.#a + this.#b;
+  }
+  d() {
+    this.#b = 3;
+    return this.#c();
+  }
+}
+
+const x = new A();
+expect(x.d()).toBe(4);
+expect(log).toEqual(["call"]);
+expect(Object.keys(x)).toEqual([]);
+"#
+);
@@ -0,0 +1,311 @@
+use crate::{
+    pass::Pass,
+    util::{ExprFactory, HANDLER},
+};
+use ast::*;
+use swc_atoms::JsWord;
+use swc_common::{Fold, FoldWith, Span, Spanned, Visit, VisitWith};
+
+#[cfg(test)]
+mod tests;
+
+/// `@babel/plugin-proposal-do-expressions`
+///
+/// The completion value of the block is turned into a `return` statement and
+/// the block is wrapped in an immediately invoked arrow function.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// let x = do {
+///     if (a) {
+///         1
+///     } else {
+///         2
+///     }
+/// };
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// let x = (() => {
+///     if (a) {
+///         return 1;
+///     } else {
+///         return 2;
+///     }
+/// })();
+/// ```
+pub fn do_expressions() -> impl Pass {
+    DoExpressions
+}
+
+#[derive(Clone, Copy)]
+struct DoExpressions;
+
+impl Fold<Expr> for DoExpressions {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children(self);
+
+        match e {
+            Expr::Do(DoExpr { span, mut body }) => {
+                // `do { x }` => `x`
+                if body.stmts.len() == 1 {
+                    match body.stmts.pop().unwrap() {
+                        Stmt::Expr(box expr) => return expr,
+                        stmt => body.stmts.push(stmt),
+                    }
+                }
+
+                let mut v = FnBoundary::default();
+                body.visit_with(&mut v);
+                if let Some((span, msg)) = v.unsupported {
+                    HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+                }
+
+                let stmts = match body.stmts.pop() {
+                    Some(last) => {
+                        let mut stmts = body.stmts;
+                        stmts.push(completion(last));
+                        stmts
+                    }
+                    None => vec![],
+                };
+
+                let call = Expr::Call(CallExpr {
+                    span,
+                    callee: ArrowExpr {
+                        span,
+                        params: vec![],
+                        body: BlockStmtOrExpr::BlockStmt(BlockStmt {
+                            span: body.span,
+                            stmts,
+                        }),
+                        is_async: v.found_await,
+                        is_generator: false,
+                        type_params: Default::default(),
+                        return_type: Default::default(),
+                    }
+                    .as_callee(),
+                    args: vec![],
+                    type_args: Default::default(),
+                });
+
+                if v.found_await {
+                    Expr::Await(AwaitExpr {
+                        span,
+                        arg: box call,
+                    })
+                } else {
+                    call
+                }
+            }
+            _ => e,
+        }
+    }
+}
+
+/// Converts the statement providing the completion value into a `return`.
+fn completion(stmt: Stmt) -> Stmt {
+    match stmt {
+        Stmt::Expr(expr) => Stmt::Return(ReturnStmt {
+            span: expr.span(),
+            arg: Some(expr),
+        }),
+
+        Stmt::Block(block) => Stmt::Block(completion_of_block(block)),
+
+        Stmt::If(IfStmt {
+            span,
+            test,
+            cons,
+            alt,
+        }) => Stmt::If(IfStmt {
+            span,
+            test,
+            cons: box completion(*cons),
+            alt: alt.map(|alt| box completion(*alt)),
+        }),
+
+        Stmt::Labeled(LabeledStmt { span, label, body }) => Stmt::Labeled(LabeledStmt {
+            span,
+            label,
+            body: box completion(*body),
+        }),
+
+        // The completion value of a case is the one of the statement before
+        // `break`, or the last statement of the last case.
+        Stmt::Switch(SwitchStmt {
+            span,
+            discriminant,
+            cases,
+        }) => {
+            let len = cases.len();
+            let cases = cases
+                .into_iter()
+                .enumerate()
+                .map(|(i, mut case)| {
+                    let pos = match case.cons.last() {
+                        Some(&Stmt::Break(BreakStmt { label: None, .. })) => {
+                            case.cons.len().checked_sub(2)
+                        }
+                        Some(&Stmt::Break(..)) => None,
+                        Some(..) if i + 1 == len => Some(case.cons.len() - 1),
+                        _ => None,
+                    };
+                    if let Some(pos) = pos {
+                        let stmt = case.cons.remove(pos);
+                        case.cons.insert(pos, completion(stmt));
+                    }
+                    case
+                })
+                .collect();
+
+            Stmt::Switch(SwitchStmt {
+                span,
+                discriminant,
+                cases,
+            })
+        }
+
+        Stmt::Try(TryStmt {
+            span,
+            block,
+            handler,
+            finalizer,
+        }) => Stmt::Try(TryStmt {
+            span,
+            block: completion_of_block(block),
+            handler: handler.map(|handler| CatchClause {
+                body: completion_of_block(handler.body),
+                ..handler
+            }),
+            finalizer,
+        }),
+
+        // Loops and declarations complete with `undefined`.
+        _ => stmt,
+    }
+}
+
+fn completion_of_block(mut block: BlockStmt) -> BlockStmt {
+    if let Some(last) = block.stmts.pop() {
+        block.stmts.push(completion(last));
+    }
+    block
+}
+
+/// Finds expressions which behave differently when moved into a new function.
+#[derive(Default)]
+struct FnBoundary {
+    found_await: bool,
+    unsupported: Option<(Span, &'static str)>,
+    /// Labels declared in the do expression.
+    labels: Vec<JsWord>,
+    /// Number of enclosing loops in the do expression.
+    loop_depth: usize,
+    /// Number of enclosing loops and switch statements in the do expression.
+    break_depth: usize,
+}
+
+impl FnBoundary {
+    fn unsupported(&mut self, span: Span, msg: &'static str) {
+        self.unsupported = self.unsupported.or(Some((span, msg)));
+    }
+
+    fn targets_outside(&self, label: &Option<Ident>, depth: usize) -> bool {
+        match *label {
+            Some(ref label) => !self.labels.contains(&label.sym),
+            None => depth == 0,
+        }
+    }
+}
+
+const YIELD_OR_RETURN: &str = "`yield` and `return` inside do expressions are not supported";
+const JUMP_OUT: &str = "`break` and `continue` cannot jump out of do expressions";
+
+impl Visit<AwaitExpr> for FnBoundary {
+    fn visit(&mut self, e: &AwaitExpr) {
+        self.found_await = true;
+        e.visit_children(self);
+    }
+}
+
+impl Visit<YieldExpr> for FnBoundary {
+    fn visit(&mut self, e: &YieldExpr) {
+        self.unsupported(e.span, YIELD_OR_RETURN);
+        e.visit_children(self);
+    }
+}
+
+impl Visit<ReturnStmt> for FnBoundary {
+    fn visit(&mut self, s: &ReturnStmt) {
+        self.unsupported(s.span, YIELD_OR_RETURN);
+        s.visit_children(self);
+    }
+}
+
+impl Visit<BreakStmt> for FnBoundary {
+    fn visit(&mut self, s: &BreakStmt) {
+        if self.targets_outside(&s.label, self.break_depth) {
+            self.unsupported(s.span, JUMP_OUT);
+        }
+    }
+}
+
+impl Visit<ContinueStmt> for FnBoundary {
+    fn visit(&mut self, s: &ContinueStmt) {
+        if self.targets_outside(&s.label, self.loop_depth) {
+            self.unsupported(s.span, JUMP_OUT);
+        }
+    }
+}
+
+impl Visit<LabeledStmt> for FnBoundary {
+    fn visit(&mut self, s: &LabeledStmt) {
+        self.labels.push(s.label.sym.clone());
+        s.body.visit_with(self);
+        self.labels.pop();
+    }
+}
+
+impl Visit<SwitchStmt> for FnBoundary {
+    fn visit(&mut self, s: &SwitchStmt) {
+        self.break_depth += 1;
+        s.visit_children(self);
+        self.break_depth -= 1;
+    }
+}
+
+macro_rules! impl_visit_loop {
+    ($T:ty) => {
+        impl Visit<$T> for FnBoundary {
+            fn visit(&mut self, s: &$T) {
+                self.loop_depth += 1;
+                self.break_depth += 1;
+                s.visit_children(self);
+                self.loop_depth -= 1;
+                self.break_depth -= 1;
+            }
+        }
+    };
+}
+
+impl_visit_loop!(ForStmt);
+impl_visit_loop!(ForInStmt);
+impl_visit_loop!(ForOfStmt);
+impl_visit_loop!(WhileStmt);
+impl_visit_loop!(DoWhileStmt);
+
+/// Don't recurse into functions.
+impl Visit<Function> for FnBoundary {
+    fn visit(&mut self, _: &Function) {}
+}
+
+/// Don't recurse into functions.
+impl Visit<ArrowExpr> for FnBoundary {
+    fn visit(&mut self, _: &ArrowExpr) {}
+}
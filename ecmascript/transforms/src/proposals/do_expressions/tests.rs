@@ -0,0 +1,231 @@
+use super::do_expressions;
+use crate::{tests::Tester, util::HANDLER};
+use ast::*;
+use swc_common::Fold;
+use swc_ecma_parser::{EsConfig, Syntax};
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        do_expressions: true,
+        jsx: true,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Fold<Module> {
+    do_expressions()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    single_expr,
+    "let x = do { foo() };",
+    "let x = foo();"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    if_else,
+    "let x = do {
+    if (a) {
+        1;
+    } else {
+        2;
+    }
+};",
+    "let x = (() => {
+    if (a) {
+        return 1;
+    } else {
+        return 2;
+    }
+})();"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    declarations,
+    "let x = do {
+    let tmp = f();
+    tmp * tmp;
+};",
+    "let x = (() => {
+    let tmp = f();
+    return tmp * tmp;
+})();"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    try_catch,
+    "let x = do {
+    try {
+        JSON.parse(s);
+    } catch (e) {
+        null;
+    }
+};",
+    "let x = (() => {
+    try {
+        return JSON.parse(s);
+    } catch (e) {
+        return null;
+    }
+})();"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    jsx_child,
+    "<div>{do { if (a) { <A />; } else { <B />; } }}</div>;",
+    "<div>{(() => {
+    if (a) {
+        return <A />;
+    } else {
+        return <B />;
+    }
+})()}</div>;"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    await_inside,
+    "async function f() {
+    return do {
+        const res = await fetch(url);
+        res.json();
+    };
+}",
+    "async function f() {
+    return await (async () => {
+        const res = await fetch(url);
+        return res.json();
+    })();
+}"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    exec_this,
+    r#"
+const obj = {
+    value: 3,
+    get() {
+        return do {
+            if (this.value > 2) {
+                "big";
+            } else {
+                "small";
+            }
+        };
+    },
+};
+
+expect(obj.get()).toBe("big");
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    switch_case,
+    "let x = do {
+    switch (a) {
+        case 1:
+            f();
+            'one';
+            break;
+        case 2:
+            g();
+        default:
+            'other';
+    }
+};",
+    "let x = (() => {
+    switch (a) {
+        case 1:
+            f();
+            return 'one';
+            break;
+        case 2:
+            g();
+        default:
+            return 'other';
+    }
+})();"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    labeled,
+    "let x = do {
+    outer: if (a) {
+        1;
+    } else {
+        2;
+    }
+};",
+    "let x = (() => {
+    outer: if (a) {
+        return 1;
+    } else {
+        return 2;
+    }
+})();"
+);
+
+fn errors(src: &str) -> String {
+    ::testing::run_test(false, |cm, handler| {
+        HANDLER.set(handler, || {
+            Tester {
+                cm,
+                handler,
+                comments: Default::default(),
+            }
+            .apply_transform(tr(), "input.js", syntax(), src)
+        })?;
+        if handler.has_errors() {
+            return Err(());
+        }
+        Ok(())
+    })
+    .err()
+    .map(|err| err.to_string())
+    .unwrap_or_default()
+}
+
+#[test]
+fn jump_out() {
+    for src in &[
+        "for (;;) { x = do { if (a) { break; } 1; }; }",
+        "for (;;) { x = do { if (a) { continue; } 1; }; }",
+        "outer: for (;;) { x = do { for (;;) { break outer; } 1; }; }",
+        "for (;;) { x = do { switch (a) { case 1: continue; } 1; }; }",
+    ] {
+        let errors = errors(src);
+        assert!(
+            errors.contains("`break` and `continue` cannot jump out of do expressions"),
+            "{}:\n{}",
+            src,
+            errors
+        );
+    }
+
+    for src in &[
+        "x = do { for (;;) { if (a) { break; } continue; } 1; };",
+        "x = do { switch (a) { case 1: break; } 1; };",
+        "x = do { inner: { break inner; } 1; };",
+        "x = do { inner: for (;;) { for (;;) { continue inner; } } 1; };",
+    ] {
+        let errors = errors(src);
+        assert!(errors.is_empty(), "{}:\n{}", src, errors);
+    }
+}
@@ -47,6 +47,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                     .src
                                     .clone()
                                     .expect("`export default from` requires source"),
+                                type_only: false,
                             })));
                             extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
                                 NamedExport {
@@ -59,6 +60,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                         },
                                     )],
                                     src: None,
+                                    type_only: false,
                                 },
                             )));
                         }
@@ -75,6 +77,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                     .src
                                     .clone()
                                     .expect("`export default from` requires source"),
+                                type_only: false,
                             })));
                             extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
                                 NamedExport {
@@ -87,6 +90,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                         },
                                     )],
                                     src: None,
+                                    type_only: false,
                                 },
                             )));
                         }
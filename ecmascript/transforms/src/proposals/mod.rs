@@ -1,5 +1,17 @@
-pub use self::{class_properties::class_properties, decorators::decorators, export::export};
+pub use self::{
+    class_properties::class_properties,
+    decorators::{decorators, decorators_2019_11},
+    do_expressions::do_expressions,
+    export::export,
+    partial_application::partial_application,
+    pipeline_operator::pipeline_operator,
+    throw_expressions::throw_expressions,
+};
 
 mod class_properties;
 mod decorators;
+mod do_expressions;
 mod export;
+mod partial_application;
+mod pipeline_operator;
+mod throw_expressions;
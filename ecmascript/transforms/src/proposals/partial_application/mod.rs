@@ -0,0 +1,243 @@
+use crate::{
+    pass::Pass,
+    util::{alias_ident_for, ExprFactory, StmtLike},
+};
+use ast::*;
+use swc_common::{Fold, FoldWith, Visit, VisitWith, DUMMY_SP};
+
+#[cfg(test)]
+mod tests;
+
+/// `@babel/plugin-proposal-partial-application`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// const addOne = add(1, ?);
+///
+/// const log = console.log(?, x);
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var _add;
+/// const addOne = (_add = add, (_arg) => _add(1, _arg));
+///
+/// var _console, _x;
+/// const log = (_console = console, _x = x, (_arg) => _console.log(_arg, _x));
+/// ```
+pub fn partial_application() -> impl Pass {
+    PartialApplication
+}
+
+#[derive(Clone, Copy)]
+struct PartialApplication;
+
+#[derive(Default)]
+struct PartialFolder {
+    vars: Vec<VarDeclarator>,
+}
+
+impl PartialFolder {
+    /// Evaluates `e` into a temporary variable, so that it's evaluated at the
+    /// point of the partial application and not when the result is called.
+    fn alias(&mut self, e: Box<Expr>, exprs: &mut Vec<Box<Expr>>, default: &str) -> Box<Expr> {
+        match *e {
+            Expr::Lit(..) | Expr::This(..) => return e,
+            _ => {}
+        }
+
+        let ident = alias_ident_for(&e, default);
+        self.vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(ident.clone()),
+            init: None,
+            definite: false,
+        });
+        exprs.push(box Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(box Pat::Ident(ident.clone())),
+            right: e,
+        }));
+
+        box Expr::Ident(ident)
+    }
+}
+
+impl Fold<Expr> for PartialFolder {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children(self);
+
+        match e {
+            Expr::Call(CallExpr {
+                span,
+                callee,
+                args,
+                type_args,
+            }) => {
+                if !args.iter().any(is_placeholder) {
+                    return Expr::Call(CallExpr {
+                        span,
+                        callee,
+                        args,
+                        type_args,
+                    });
+                }
+
+                let mut exprs = vec![];
+                let mut bind_this = false;
+
+                let callee = match callee {
+                    // `super.m` is evaluated now, and called with `this`.
+                    ExprOrSuper::Expr(
+                        callee @ box Expr::Member(MemberExpr {
+                            obj: ExprOrSuper::Super(..),
+                            ..
+                        }),
+                    ) => {
+                        bind_this = true;
+                        let callee = self.alias(callee, &mut exprs, "_fn");
+
+                        ExprOrSuper::Expr(box Expr::Member(MemberExpr {
+                            span: DUMMY_SP,
+                            obj: ExprOrSuper::Expr(callee),
+                            prop: box Expr::Ident(quote_ident!("call")),
+                            computed: false,
+                        }))
+                    }
+                    ExprOrSuper::Expr(box Expr::Member(MemberExpr {
+                        span: member_span,
+                        obj: ExprOrSuper::Expr(obj),
+                        prop,
+                        computed,
+                    })) => {
+                        let obj = self.alias(obj, &mut exprs, "_obj");
+                        let prop = if computed {
+                            self.alias(prop, &mut exprs, "_prop")
+                        } else {
+                            prop
+                        };
+
+                        ExprOrSuper::Expr(box Expr::Member(MemberExpr {
+                            span: member_span,
+                            obj: ExprOrSuper::Expr(obj),
+                            prop,
+                            computed,
+                        }))
+                    }
+                    ExprOrSuper::Expr(callee) => {
+                        ExprOrSuper::Expr(self.alias(callee, &mut exprs, "_fn"))
+                    }
+                    ExprOrSuper::Super(..) => callee,
+                };
+
+                let mut params = vec![];
+                let mut call_args = vec![];
+                if bind_this {
+                    call_args.push(ThisExpr { span: DUMMY_SP }.as_arg());
+                }
+                call_args.extend(args.into_iter().map(|arg| {
+                    if is_placeholder(&arg) {
+                        let param = private_ident!("_arg");
+                        params.push(Pat::Ident(param.clone()));
+                        return param.as_arg();
+                    }
+
+                    ExprOrSpread {
+                        spread: arg.spread,
+                        expr: self.alias(arg.expr, &mut exprs, "_ref"),
+                    }
+                }));
+
+                exprs.push(box Expr::Arrow(ArrowExpr {
+                    span,
+                    params,
+                    body: BlockStmtOrExpr::Expr(box Expr::Call(CallExpr {
+                        span,
+                        callee,
+                        args: call_args,
+                        type_args,
+                    })),
+                    is_async: false,
+                    is_generator: false,
+                    type_params: Default::default(),
+                    return_type: Default::default(),
+                }));
+
+                if exprs.len() == 1 {
+                    *exprs.pop().unwrap()
+                } else {
+                    Expr::Seq(SeqExpr { span, exprs })
+                }
+            }
+            _ => e,
+        }
+    }
+}
+
+impl<T: StmtLike + VisitWith<ShouldFold>> Fold<Vec<T>> for PartialApplication
+where
+    Vec<T>: FoldWith<Self>,
+{
+    fn fold(&mut self, stmts: Vec<T>) -> Vec<T> {
+        if !should_fold(&stmts) {
+            return stmts;
+        }
+        let stmts = stmts.fold_children(self);
+
+        let mut buf = vec![];
+
+        for stmt in stmts {
+            match stmt.try_into_stmt() {
+                Err(module_item) => buf.push(module_item),
+                Ok(stmt) => {
+                    let mut folder = PartialFolder::default();
+                    let stmt = stmt.fold_with(&mut folder);
+
+                    if !folder.vars.is_empty() {
+                        buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Var,
+                            decls: folder.vars,
+                            declare: false,
+                        }))));
+                    }
+
+                    buf.push(T::from_stmt(stmt));
+                }
+            }
+        }
+
+        buf
+    }
+}
+
+fn is_placeholder(arg: &ExprOrSpread) -> bool {
+    match *arg.expr {
+        Expr::ArgPlaceholder(..) => arg.spread.is_none(),
+        _ => false,
+    }
+}
+
+fn should_fold<N>(node: &N) -> bool
+where
+    N: VisitWith<ShouldFold>,
+{
+    let mut v = ShouldFold { found: false };
+    node.visit_with(&mut v);
+    v.found
+}
+
+struct ShouldFold {
+    found: bool,
+}
+
+impl Visit<ArgPlaceholder> for ShouldFold {
+    fn visit(&mut self, _: &ArgPlaceholder) {
+        self.found = true;
+    }
+}
@@ -0,0 +1,118 @@
+use super::partial_application;
+use ast::*;
+use swc_common::Fold;
+use swc_ecma_parser::{EsConfig, Syntax};
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        partial_application: true,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Fold<Module> {
+    partial_application()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    simple,
+    "const addOne = add(1, ?);",
+    "
+var _add;
+const addOne = (_add = add, (_arg) => _add(1, _arg));
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    member_callee,
+    "const log = console.log(?, x);",
+    "
+var _console, _x;
+const log = (_console = console, _x = x, (_arg) => _console.log(_arg, _x));
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    this_callee,
+    "const f = this.g(?, ?);",
+    "const f = (_arg, _arg1) => this.g(_arg, _arg1);"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    super_callee,
+    "class A extends B {
+    f() {
+        return super.m(?, x);
+    }
+}",
+    "
+class A extends B {
+    f() {
+        var _m, _x;
+        return (_m = super.m, _x = x, (_arg) => _m.call(this, _arg, _x));
+    }
+}
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_arg,
+    "const f = g(?, ...rest);",
+    "
+var _g, _rest;
+const f = (_g = g, _rest = rest, (_arg) => _g(_arg, ..._rest));
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    no_placeholder,
+    "const f = g(a, b);",
+    "const f = g(a, b);"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    exec_eager_evaluation,
+    r#"
+var calls = 0;
+function arg() { calls++; return 2; }
+function mul(a, b) { return a * b; }
+
+const double = mul(?, arg());
+expect(calls).toBe(1);
+expect(double(3)).toBe(6);
+expect(double(4)).toBe(8);
+expect(calls).toBe(1);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    exec_super_callee,
+    r#"
+class A {
+  m(a, b) { return this.x + a + b; }
+}
+class B extends A {
+  constructor() { super(); this.x = 1; }
+  f() { return super.m(?, 2); }
+}
+
+const f = new B().f();
+expect(f(3)).toBe(6);
+"#
+);
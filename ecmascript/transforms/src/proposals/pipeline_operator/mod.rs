@@ -0,0 +1,169 @@
+use crate::{
+    pass::Pass,
+    util::{ExprFactory, StmtLike},
+};
+use ast::*;
+use swc_common::{Fold, FoldWith, Visit, VisitWith, DUMMY_SP};
+
+#[cfg(test)]
+mod tests;
+
+/// `@babel/plugin-proposal-pipeline-operator`
+///
+/// Handles both the minimal and the F#-style proposal.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// let x = a() |> f |> g;
+///
+/// let y = z |> await;
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var _ref, _ref1;
+/// let x = (_ref1 = (_ref = a(), f(_ref)), g(_ref1));
+///
+/// let y = await z;
+/// ```
+pub fn pipeline_operator() -> impl Pass {
+    PipelineOperator
+}
+
+#[derive(Clone, Copy)]
+struct PipelineOperator;
+
+#[derive(Default)]
+struct PipelineFolder {
+    vars: Vec<VarDeclarator>,
+}
+
+impl Fold<Expr> for PipelineFolder {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children(self);
+
+        match e {
+            Expr::Bin(BinExpr {
+                span,
+                left,
+                op: op!("|>"),
+                right,
+            }) => {
+                // Literals and identifiers can be passed as-is.
+                let (init, arg) = match *left {
+                    Expr::Ident(..) | Expr::Lit(..) | Expr::This(..) => (None, left),
+                    _ => {
+                        let ref_ident = private_ident!("_ref");
+                        self.vars.push(VarDeclarator {
+                            span: DUMMY_SP,
+                            name: Pat::Ident(ref_ident.clone()),
+                            init: None,
+                            definite: false,
+                        });
+
+                        (
+                            Some(box Expr::Assign(AssignExpr {
+                                span: DUMMY_SP,
+                                op: op!("="),
+                                left: PatOrExpr::Pat(box Pat::Ident(ref_ident.clone())),
+                                right: left,
+                            })),
+                            box Expr::Ident(ref_ident),
+                        )
+                    }
+                };
+
+                let body = match *right {
+                    // `x |> await`
+                    Expr::Await(AwaitExpr {
+                        span: await_span,
+                        arg: box Expr::ArgPlaceholder(..),
+                    }) => Expr::Await(AwaitExpr {
+                        span: await_span,
+                        arg,
+                    }),
+                    _ => Expr::Call(CallExpr {
+                        span,
+                        callee: right.as_callee(),
+                        args: vec![arg.as_arg()],
+                        type_args: Default::default(),
+                    }),
+                };
+
+                match init {
+                    Some(init) => Expr::Seq(SeqExpr {
+                        span,
+                        exprs: vec![init, box body],
+                    }),
+                    None => body,
+                }
+            }
+            _ => e,
+        }
+    }
+}
+
+impl<T: StmtLike + VisitWith<ShouldFold>> Fold<Vec<T>> for PipelineOperator
+where
+    Vec<T>: FoldWith<Self>,
+{
+    fn fold(&mut self, stmts: Vec<T>) -> Vec<T> {
+        if !should_fold(&stmts) {
+            return stmts;
+        }
+        let stmts = stmts.fold_children(self);
+
+        let mut buf = vec![];
+
+        for stmt in stmts {
+            match stmt.try_into_stmt() {
+                Err(module_item) => buf.push(module_item),
+                Ok(stmt) => {
+                    let mut folder = PipelineFolder::default();
+                    let stmt = stmt.fold_with(&mut folder);
+
+                    // Add variable declaration
+                    // e.g. var _ref
+                    if !folder.vars.is_empty() {
+                        buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Var,
+                            decls: folder.vars,
+                            declare: false,
+                        }))));
+                    }
+
+                    buf.push(T::from_stmt(stmt));
+                }
+            }
+        }
+
+        buf
+    }
+}
+
+fn should_fold<N>(node: &N) -> bool
+where
+    N: VisitWith<ShouldFold>,
+{
+    let mut v = ShouldFold { found: false };
+    node.visit_with(&mut v);
+    v.found
+}
+
+struct ShouldFold {
+    found: bool,
+}
+
+impl Visit<BinExpr> for ShouldFold {
+    fn visit(&mut self, e: &BinExpr) {
+        if e.op == op!("|>") {
+            self.found = true;
+        }
+        e.visit_children(self);
+    }
+}
@@ -0,0 +1,102 @@
+use super::pipeline_operator;
+use ast::*;
+use swc_common::Fold;
+use swc_ecma_parser::{EsConfig, PipelineProposal, Syntax};
+
+fn syntax(proposal: PipelineProposal) -> Syntax {
+    Syntax::Es(EsConfig {
+        pipeline_operator: true,
+        pipeline_proposal: proposal,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Fold<Module> {
+    pipeline_operator()
+}
+
+test!(
+    syntax(PipelineProposal::Minimal),
+    |_| tr(),
+    minimal_ident,
+    "let result = x |> double;",
+    "let result = double(x);"
+);
+
+test!(
+    syntax(PipelineProposal::Minimal),
+    |_| tr(),
+    minimal_chained,
+    "let result = a() |> f |> g;",
+    "
+var _ref, _ref1;
+let result = (_ref1 = (_ref = a(), f(_ref)), g(_ref1));
+"
+);
+
+test!(
+    syntax(PipelineProposal::Minimal),
+    |_| tr(),
+    minimal_member_callee,
+    "let result = 5 |> obj.add;",
+    "let result = obj.add(5);"
+);
+
+test!(
+    syntax(PipelineProposal::Minimal),
+    |_| tr(),
+    minimal_parenthesized_arrow,
+    "let result = 10 |> (x => x + 1);",
+    "let result = (x => x + 1)(10);"
+);
+
+test!(
+    syntax(PipelineProposal::Minimal),
+    |_| tr(),
+    minimal_in_function,
+    "function foo() {
+    return a.b |> f;
+}",
+    "function foo() {
+    var _ref;
+    return _ref = a.b, f(_ref);
+}"
+);
+
+test!(
+    syntax(PipelineProposal::FSharp),
+    |_| tr(),
+    fsharp_arrow_body,
+    "let result = 10 |> x => x + 1 |> double;",
+    "
+var _ref;
+let result = (_ref = (x => x + 1)(10), double(_ref));
+"
+);
+
+test!(
+    syntax(PipelineProposal::FSharp),
+    |_| tr(),
+    fsharp_await,
+    "async function foo() {
+    return url |> fetch |> await;
+}",
+    "async function foo() {
+    var _ref;
+    return _ref = fetch(url), await _ref;
+}"
+);
+
+test_exec!(
+    syntax(PipelineProposal::Minimal),
+    |_| tr(),
+    minimal_exec_order,
+    r#"
+var log = [];
+function a() { log.push("a"); return 1; }
+function f() { log.push("f"); return function (x) { return x + 1; }; }
+
+expect(a() |> f()).toBe(2);
+expect(log).toEqual(["a", "f"]);
+"#
+);
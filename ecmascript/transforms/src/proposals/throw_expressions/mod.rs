@@ -0,0 +1,47 @@
+use crate::{pass::Pass, util::ExprFactory};
+use ast::*;
+use swc_common::{Fold, FoldWith};
+
+#[cfg(test)]
+mod tests;
+
+/// `@babel/plugin-proposal-throw-expressions`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// const x = y || throw new Error('y is required');
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// function _throw(e) {
+///     throw e;
+/// }
+/// const x = y || _throw(new Error('y is required'));
+/// ```
+pub fn throw_expressions() -> impl Pass {
+    ThrowExpressions
+}
+
+#[derive(Clone, Copy)]
+struct ThrowExpressions;
+
+impl Fold<Expr> for ThrowExpressions {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children(self);
+
+        match e {
+            Expr::Throw(ThrowExpr { span, arg }) => Expr::Call(CallExpr {
+                span,
+                callee: helper!(throw, "throw"),
+                args: vec![arg.as_arg()],
+                type_args: Default::default(),
+            }),
+            _ => e,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use super::throw_expressions;
+use ast::*;
+use swc_common::Fold;
+use swc_ecma_parser::{EsConfig, Syntax};
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        throw_expressions: true,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Fold<Module> {
+    throw_expressions()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    logical,
+    "const x = y || throw new Error('y is required');",
+    "
+const x = y || _throw(new Error('y is required'));
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    default_param,
+    "function f(x = throw new TypeError('x is required')) {}",
+    "
+function f(x = _throw(new TypeError('x is required'))) {}
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    arrow_body,
+    "const fail = (msg) => throw new Error(msg);",
+    "
+const fail = (msg) => _throw(new Error(msg));
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    throw_stmt_untouched,
+    "throw new Error('foo');",
+    "throw new Error('foo');"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    exec,
+    r#"
+function f(x) {
+    return x || throw new Error("missing");
+}
+
+expect(f(1)).toBe(1);
+expect(() => f(0)).toThrow("missing");
+"#
+);
@@ -23,6 +23,7 @@ impl Fold<VarDeclarator> for DisplayName {
                         span: ident.span,
                         value: ident.sym.clone(),
                         has_escape: false,
+                        raw: None,
                     }))),
                 });
 
@@ -44,6 +45,7 @@ impl Fold<ModuleDecl> for DisplayName {
                         span: DUMMY_SP,
                         value: "input".into(),
                         has_escape: false,
+                        raw: None,
                     }))),
                 }))
             }
@@ -76,6 +78,7 @@ impl Fold<AssignExpr> for DisplayName {
                         span: prop.span,
                         value: prop.sym.clone(),
                         has_escape: false,
+                        raw: None,
                     }))),
                 });
                 AssignExpr { right, ..expr }
@@ -88,6 +91,7 @@ impl Fold<AssignExpr> for DisplayName {
                         span: ident.span,
                         value: ident.sym.clone(),
                         has_escape: false,
+                        raw: None,
                     }))),
                 });
 
@@ -110,9 +114,10 @@ impl Fold<Prop> for DisplayName {
                             span: i.span,
                             value: i.sym.clone(),
                             has_escape: false,
+                            raw: None,
                         })),
                         PropName::Str(ref s) => box Expr::Lit(Lit::Str(s.clone())),
-                        PropName::Num(n) => box Expr::Lit(Lit::Num(n)),
+                        PropName::Num(ref n) => box Expr::Lit(Lit::Num(n.clone())),
                         PropName::Computed(ref expr) => expr.clone(),
                     }),
                 });
@@ -301,6 +301,7 @@ impl Jsx {
                         span,
                         value: i.sym,
                         has_escape: false,
+                        raw: None,
                     }))
                 } else {
                     box Expr::Ident(i)
@@ -324,6 +325,7 @@ impl Jsx {
                     span,
                     value: format!("{}:{}", ns.sym, name.sym).into(),
                     has_escape: false,
+                    raw: None,
                 }))
             }
             JSXElementName::JSXMemberExpr(JSXMemberExpr { obj, prop }) => {
@@ -378,6 +380,7 @@ fn to_prop_name(n: JSXAttrName) -> PropName {
                     span,
                     value: i.sym,
                     has_escape: false,
+                    raw: None,
                 })
             } else {
                 PropName::Ident(i)
@@ -387,6 +390,7 @@ fn to_prop_name(n: JSXAttrName) -> PropName {
             span,
             value: format!("{}:{}", ns.sym, name.sym).into(),
             has_escape: false,
+            raw: None,
         }),
     }
 }
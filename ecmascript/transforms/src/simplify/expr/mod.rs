@@ -111,6 +111,7 @@ fn fold_member_expr(e: MemberExpr) -> Expr {
             KnownOp::Len => Expr::Lit(Lit::Num(Number {
                 value: value.chars().count() as f64,
                 span,
+                raw: None,
             })),
 
             // 'foo'[1]
@@ -123,6 +124,7 @@ fn fold_member_expr(e: MemberExpr) -> Expr {
                     .into(),
                 span,
                 has_escape: false,
+                raw: None,
             })),
 
             _ => Expr::Member(MemberExpr {
@@ -152,6 +154,7 @@ fn fold_member_expr(e: MemberExpr) -> Expr {
             return Expr::Lit(Lit::Num(Number {
                 value: elems.len() as _,
                 span,
+                raw: None,
             }));
         }
 
@@ -197,7 +200,11 @@ fn fold_bin(
                 Known(v) => {
                     return preserve_effects(
                         span,
-                        Expr::Lit(Lit::Num(Number { value: v, span })),
+                        Expr::Lit(Lit::Num(Number {
+                            value: v,
+                            span,
+                            raw: None,
+                        })),
                         { iter::once(left).chain(iter::once(right)) },
                     );
                 }
@@ -234,6 +241,7 @@ fn fold_bin(
                                         span,
                                         // TODO
                                         has_escape: false,
+                                        raw: None,
                                     }));
                                 }
                                 _ => {}
@@ -261,7 +269,11 @@ fn fold_bin(
                             Known(v) => {
                                 return preserve_effects(
                                     span,
-                                    Expr::Lit(Lit::Num(Number { value: v, span })),
+                                    Expr::Lit(Lit::Num(Number {
+                                        value: v,
+                                        span,
+                                        raw: None,
+                                    })),
                                     { iter::once(left).chain(iter::once(right)) },
                                 );
                             }
@@ -449,7 +461,11 @@ fn fold_bin(
                                 span,
                                 left: left_lhs,
                                 op: left_op,
-                                right: box Expr::Lit(Lit::Num(Number { value, span })),
+                                right: box Expr::Lit(Lit::Num(Number {
+                                    value,
+                                    span,
+                                    raw: None,
+                                })),
                             });
                         }
                     }
@@ -570,6 +586,7 @@ fn try_fold_typeof(UnaryExpr { span, op, arg }: UnaryExpr) -> Expr {
         span,
         value: val.into(),
         has_escape: false,
+        raw: None,
     }))
 }
 
@@ -588,7 +605,11 @@ fn fold_unary(UnaryExpr { span, op, arg }: UnaryExpr) -> Expr {
             Known(v) => {
                 return preserve_effects(
                     span,
-                    Expr::Lit(Lit::Num(Number { value: v, span })),
+                    Expr::Lit(Lit::Num(Number {
+                        value: v,
+                        span,
+                        raw: None,
+                    })),
                     iter::once(arg),
                 );
             }
@@ -606,7 +627,11 @@ fn fold_unary(UnaryExpr { span, op, arg }: UnaryExpr) -> Expr {
                 ..
             }) => return *arg,
             Expr::Lit(Lit::Num(Number { value: f, .. })) => {
-                return Expr::Lit(Lit::Num(Number { value: -f, span }));
+                return Expr::Lit(Lit::Num(Number {
+                    value: -f,
+                    span,
+                    raw: None,
+                }));
             }
             _ => {
 
@@ -620,6 +645,7 @@ fn fold_unary(UnaryExpr { span, op, arg }: UnaryExpr) -> Expr {
                 arg: box Expr::Lit(Lit::Num(Number {
                     value: 0.0,
                     span: arg.span(),
+                    raw: None,
                 })),
                 span,
             });
@@ -836,7 +862,11 @@ fn perform_abstract_eq_cmp(span: Span, left: &Expr, right: &Expr) -> Value<bool>
             return perform_abstract_eq_cmp(
                 span,
                 left,
-                &Expr::Lit(Lit::Num(Number { value: rv, span })),
+                &Expr::Lit(Lit::Num(Number {
+                    value: rv,
+                    span,
+                    raw: None,
+                })),
             );
         }
 
@@ -844,7 +874,11 @@ fn perform_abstract_eq_cmp(span: Span, left: &Expr, right: &Expr) -> Value<bool>
             let lv = left.as_number()?;
             return perform_abstract_eq_cmp(
                 span,
-                &Expr::Lit(Lit::Num(Number { value: lv, span })),
+                &Expr::Lit(Lit::Num(Number {
+                    value: lv,
+                    span,
+                    raw: None,
+                })),
                 right,
             );
         }
@@ -944,7 +978,7 @@ where
             // TODO
             Expr::MetaProp(_) => v.push(box expr),
 
-            Expr::Call(_) => v.push(box expr),
+            Expr::Call(_) | Expr::Do(_) | Expr::Throw(_) => v.push(box expr),
             Expr::New(NewExpr {
                 callee: box Expr::Ident(Ident { ref sym, .. }),
                 ref args,
@@ -1017,6 +1051,8 @@ where
             | Expr::TsAs(TsAsExpr { expr, .. })
             | Expr::TsConstAssertion(TsConstAssertion { expr, .. }) => add_effects(v, expr),
             Expr::TsOptChain(e) => add_effects(v, e.expr),
+
+            Expr::ArgPlaceholder(..) => unreachable!("simplifying partial application"),
         }
     }
 
@@ -7,20 +7,16 @@ use sourcemap::SourceMapBuilder;
 use std::{
     fmt,
     fs::{create_dir_all, OpenOptions},
-    io::{self, Write},
+    io::Write,
     path::Path,
     process::Command,
-    sync::{Arc, RwLock},
+    sync::Arc,
 };
 use swc_common::{comments::Comments, errors::Handler, FileName, Fold, FoldWith, SourceMap};
 use swc_ecma_codegen::Emitter;
 use swc_ecma_parser::{lexer::Lexer, Parser, Session, SourceFileInput, Syntax};
 use tempfile::tempdir_in;
 
-struct MyHandlers;
-
-impl swc_ecma_codegen::Handlers for MyHandlers {}
-
 pub(crate) struct Tester<'a> {
     pub cm: Arc<SourceMap>,
     pub handler: &'a Handler,
@@ -143,32 +139,30 @@ impl<'a> Tester<'a> {
     }
 
     pub fn print(&mut self, module: &Module) -> String {
-        let handlers = box MyHandlers;
-
-        let mut wr = Buf(Arc::new(RwLock::new(vec![])));
+        let mut wr = String::new();
         {
             let mut src_map_builder = SourceMapBuilder::new(None);
             let mut emitter = Emitter {
                 cfg: Default::default(),
                 cm: self.cm.clone(),
-                wr: box swc_ecma_codegen::text_writer::JsWriter::new(
+                wr: swc_ecma_codegen::text_writer::JsWriter::new(
                     self.cm.clone(),
                     "\n",
                     &mut wr,
                     Some(&mut src_map_builder),
                 ),
                 comments: None,
-                handlers,
+                handlers: None,
                 pos_of_leading_comments: Default::default(),
+                in_ambient: false,
+                unchanged: Default::default(),
             };
 
             // println!("Emitting: {:?}", module);
             emitter.emit_module(&module).unwrap();
         }
 
-        let r = wr.0.read().unwrap();
-        let s = String::from_utf8_lossy(&*r);
-        s.to_string()
+        wr
     }
 }
 
@@ -384,18 +378,6 @@ macro_rules! test_exec {
     };
 }
 
-#[derive(Debug, Clone)]
-struct Buf(Arc<RwLock<Vec<u8>>>);
-impl Write for Buf {
-    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
-        self.0.write().unwrap().write(data)
-    }
-
-    fn flush(&mut self) -> io::Result<()> {
-        self.0.write().unwrap().flush()
-    }
-}
-
 struct Normalizer;
 impl Fold<PatOrExpr> for Normalizer {
     fn fold(&mut self, n: PatOrExpr) -> PatOrExpr {
@@ -405,6 +387,16 @@ impl Fold<PatOrExpr> for Normalizer {
         }
     }
 }
+impl Fold<Str> for Normalizer {
+    fn fold(&mut self, s: Str) -> Str {
+        Str { raw: None, ..s }
+    }
+}
+impl Fold<Number> for Normalizer {
+    fn fold(&mut self, n: Number) -> Number {
+        Number { raw: None, ..n }
+    }
+}
 
 struct HygieneVisualizer;
 impl Fold<Ident> for HygieneVisualizer {
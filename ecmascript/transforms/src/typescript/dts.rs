@@ -0,0 +1,889 @@
+use crate::{pass::Pass, util::HANDLER};
+use ast::*;
+use hashbrown::HashSet;
+use swc_atoms::JsWord;
+use swc_common::{Fold, Span, Spanned, DUMMY_SP};
+
+/// Converts a typescript module into a declaration file (`.d.ts`).
+///
+/// Function bodies, initializers and statements are dropped. Types are not
+/// checked, so the type of an exported declaration should be written or be
+/// obvious from its initializer. An error is reported for other exported
+/// declarations.
+pub fn dts() -> impl Pass {
+    Dts::default()
+}
+
+#[derive(Default)]
+struct Dts {
+    /// Names exported by `export { a }` and `export = a`.
+    exported: HashSet<JsWord>,
+    /// `declare` is not allowed inside of a declared namespace.
+    in_ambient: bool,
+}
+
+impl Fold<Module> for Dts {
+    fn fold(&mut self, module: Module) -> Module {
+        for item in &module.body {
+            match *item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    ref specifiers,
+                    src: None,
+                    ..
+                })) => {
+                    for s in specifiers {
+                        if let ExportSpecifier::Named(ref s) = *s {
+                            self.exported.insert(s.orig.sym.clone());
+                        }
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(TsExportAssignment {
+                    expr: box Expr::Ident(ref i),
+                    ..
+                })) => {
+                    self.exported.insert(i.sym.clone());
+                }
+                _ => {}
+            }
+        }
+
+        Module {
+            body: self.fold_items(module.body),
+            ..module
+        }
+    }
+}
+
+impl Dts {
+    fn fold_items(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        // Implementations of overloaded functions are not emitted.
+        let overloaded: HashSet<_> = items
+            .iter()
+            .filter_map(|item| match *item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
+                    ref ident,
+                    function: Function { body: None, .. },
+                    ..
+                })))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl:
+                        Decl::Fn(FnDecl {
+                            ref ident,
+                            function: Function { body: None, .. },
+                            ..
+                        }),
+                    ..
+                })) => Some(ident.sym.clone()),
+                _ => None,
+            })
+            .collect();
+        let is_impl = |decl: &Decl| match *decl {
+            Decl::Fn(FnDecl {
+                ref ident,
+                function: Function { body: Some(..), .. },
+                ..
+            }) => overloaded.contains(&ident.sym),
+            _ => false,
+        };
+
+        let mut buf = Vec::with_capacity(items.len());
+
+        for item in items {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span, decl })) => {
+                    if is_impl(&decl) {
+                        continue;
+                    }
+                    if let Some(decl) = self.fold_decl(decl, true) {
+                        buf.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                            span,
+                            decl,
+                        })));
+                    }
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    span,
+                    decl,
+                })) => {
+                    let decl = match decl {
+                        DefaultDecl::Class(c) => DefaultDecl::Class(ClassExpr {
+                            class: self.fold_class(c.class, true),
+                            ..c
+                        }),
+                        DefaultDecl::Fn(f) => DefaultDecl::Fn(FnExpr {
+                            function: self.fold_function(f.function, true),
+                            ..f
+                        }),
+                        DefaultDecl::TsInterfaceDecl(..) => decl,
+                    };
+                    buf.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+                        ExportDefaultDecl { span, decl },
+                    )));
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                    span,
+                    expr,
+                })) => match *expr {
+                    Expr::Ident(..) => {
+                        buf.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
+                            ExportDefaultExpr { span, expr },
+                        )))
+                    }
+                    _ => {
+                        // declare const _default: T;
+                        // export default _default;
+                        let ty = infer(&expr, false);
+                        if ty.is_none() {
+                            report(
+                                expr.span(),
+                                "Default export must be an identifier or have a type which can \
+                                 be determined without type checking",
+                            );
+                        }
+                        let id = quote_ident!("_default");
+
+                        buf.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                            span,
+                            kind: VarDeclKind::Const,
+                            declare: !self.in_ambient,
+                            decls: vec![VarDeclarator {
+                                span: DUMMY_SP,
+                                name: Pat::Ident(Ident {
+                                    type_ann: ty.map(type_ann),
+                                    ..id.clone()
+                                }),
+                                init: None,
+                                definite: false,
+                            }],
+                        }))));
+                        buf.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
+                            ExportDefaultExpr {
+                                span,
+                                expr: box Expr::Ident(id),
+                            },
+                        )));
+                    }
+                },
+
+                ModuleItem::ModuleDecl(decl) => buf.push(ModuleItem::ModuleDecl(decl)),
+
+                ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                    if is_impl(&decl) {
+                        continue;
+                    }
+                    let exported = self.is_exported(&decl);
+                    if let Some(decl) = self.fold_decl(decl, exported) {
+                        buf.push(ModuleItem::Stmt(Stmt::Decl(decl)));
+                    }
+                }
+
+                // Statements are not allowed in declaration files.
+                ModuleItem::Stmt(..) => {}
+            }
+        }
+
+        buf
+    }
+
+    fn is_exported(&self, decl: &Decl) -> bool {
+        match *decl {
+            Decl::Class(ClassDecl { ref ident, .. }) | Decl::Fn(FnDecl { ref ident, .. }) => {
+                self.exported.contains(&ident.sym)
+            }
+            Decl::Var(ref var) => var.decls.iter().any(|d| match d.name {
+                Pat::Ident(ref i) => self.exported.contains(&i.sym),
+                _ => false,
+            }),
+            _ => false,
+        }
+    }
+
+    /// Returns `None` if nothing is left to declare.
+    fn fold_decl(&mut self, decl: Decl, exported: bool) -> Option<Decl> {
+        let decl = match decl {
+            Decl::Class(c) => Decl::Class(ClassDecl {
+                declare: !self.in_ambient,
+                class: self.fold_class(c.class, exported),
+                ..c
+            }),
+
+            Decl::Fn(f) => Decl::Fn(FnDecl {
+                declare: !self.in_ambient,
+                function: self.fold_function(f.function, exported),
+                ..f
+            }),
+
+            Decl::Var(var) => {
+                let is_const = var.kind == VarDeclKind::Const;
+                let decls: Vec<_> = var
+                    .decls
+                    .into_iter()
+                    .filter_map(|d| {
+                        let mut i = match d.name {
+                            Pat::Ident(i) => i,
+                            _ => {
+                                if exported {
+                                    report(d.span, "Destructuring declarations can't be exported");
+                                }
+                                return None;
+                            }
+                        };
+
+                        if i.type_ann.is_none() {
+                            i.type_ann = d.init.and_then(|e| infer(&e, is_const)).map(type_ann);
+                        }
+                        if i.type_ann.is_none() && exported {
+                            report(i.span, "Variable must have an explicit type annotation");
+                        }
+
+                        Some(VarDeclarator {
+                            name: Pat::Ident(i),
+                            init: None,
+                            definite: false,
+                            ..d
+                        })
+                    })
+                    .collect();
+                if decls.is_empty() {
+                    return None;
+                }
+
+                Decl::Var(VarDecl {
+                    declare: !self.in_ambient,
+                    decls,
+                    ..var
+                })
+            }
+
+            Decl::TsEnum(e) => Decl::TsEnum(TsEnumDecl { declare: !self.in_ambient, ..e }),
+
+            Decl::TsModule(m) => {
+                if m.declare || m.global {
+                    return Some(Decl::TsModule(m));
+                }
+
+                let declare = !self.in_ambient;
+                let old = self.in_ambient;
+                self.in_ambient = true;
+                let body = m.body.map(|body| self.fold_ns_body(body));
+                self.in_ambient = old;
+
+                Decl::TsModule(TsModuleDecl { declare, body, ..m })
+            }
+
+            Decl::TsInterface(..) | Decl::TsTypeAlias(..) => decl,
+        };
+
+        Some(decl)
+    }
+
+    fn fold_ns_body(&mut self, body: TsNamespaceBody) -> TsNamespaceBody {
+        match body {
+            TsNamespaceBody::TsModuleBlock(b) => TsNamespaceBody::TsModuleBlock(TsModuleBlock {
+                body: self.fold_items(b.body),
+                ..b
+            }),
+            TsNamespaceBody::TsNamespaceDecl(d) => {
+                TsNamespaceBody::TsNamespaceDecl(TsNamespaceDecl {
+                    body: box self.fold_ns_body(*d.body),
+                    ..d
+                })
+            }
+        }
+    }
+
+    fn fold_function(&mut self, f: Function, exported: bool) -> Function {
+        if f.body.is_some() && f.return_type.is_none() && exported {
+            report(f.span, "Function must have an explicit return type annotation");
+        }
+
+        Function {
+            params: f
+                .params
+                .into_iter()
+                .enumerate()
+                .map(|(i, p)| fold_param(p, i, exported))
+                .collect(),
+            decorators: vec![],
+            body: None,
+            ..f
+        }
+    }
+
+    fn fold_class(&mut self, class: Class, exported: bool) -> Class {
+        let overloaded: HashSet<_> = class
+            .body
+            .iter()
+            .filter_map(|m| match *m {
+                ClassMember::Method(ClassMethod {
+                    ref key,
+                    function: Function { body: None, .. },
+                    is_abstract: false,
+                    ..
+                }) => prop_name_sym(key),
+                _ => None,
+            })
+            .collect();
+        let has_ctor_overloads = class.body.iter().any(|m| match *m {
+            ClassMember::Constructor(Constructor { body: None, .. }) => true,
+            _ => false,
+        });
+
+        let mut body = Vec::with_capacity(class.body.len());
+        let mut has_private_name = false;
+
+        for member in class.body {
+            match member {
+                ClassMember::Constructor(c) => {
+                    if c.body.is_some() && has_ctor_overloads {
+                        continue;
+                    }
+
+                    let params = c
+                        .params
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, p)| match p {
+                            PatOrTsParamProp::Pat(p) => {
+                                PatOrTsParamProp::Pat(fold_param(p, i, exported))
+                            }
+                            PatOrTsParamProp::TsParamProp(p) => {
+                                let param = match p.param {
+                                    TsParamPropParam::Ident(i) => TsParamPropParam::Ident(i),
+                                    TsParamPropParam::Assign(a) => {
+                                        match fold_param(Pat::Assign(a), i, exported) {
+                                            Pat::Ident(i) => TsParamPropParam::Ident(i),
+                                            _ => unreachable!("destructuring parameter property"),
+                                        }
+                                    }
+                                };
+
+                                PatOrTsParamProp::TsParamProp(TsParamProp {
+                                    decorators: vec![],
+                                    param,
+                                    ..p
+                                })
+                            }
+                        })
+                        .collect();
+
+                    body.push(ClassMember::Constructor(Constructor {
+                        params,
+                        body: None,
+                        ..c
+                    }))
+                }
+
+                ClassMember::Method(m) => {
+                    if m.function.body.is_some() {
+                        if let Some(sym) = prop_name_sym(&m.key) {
+                            if overloaded.contains(&sym) {
+                                continue;
+                            }
+                        }
+                    }
+
+                    // Signatures of private members are not emitted.
+                    if m.accessibility == Some(Accessibility::Private) {
+                        body.push(ClassMember::ClassProp(private_prop(
+                            m.span,
+                            m.key,
+                            m.is_static,
+                        )));
+                        continue;
+                    }
+
+                    if m.function.body.is_some()
+                        && m.function.return_type.is_none()
+                        && m.kind != MethodKind::Setter
+                        && exported
+                    {
+                        report(m.span, "Method must have an explicit return type annotation");
+                    }
+
+                    body.push(ClassMember::Method(ClassMethod {
+                        function: Function {
+                            params: m
+                                .function
+                                .params
+                                .into_iter()
+                                .enumerate()
+                                .map(|(i, p)| fold_param(p, i, exported))
+                                .collect(),
+                            decorators: vec![],
+                            body: None,
+                            ..m.function
+                        },
+                        ..m
+                    }))
+                }
+
+                ClassMember::ClassProp(p) => {
+                    if p.accessibility == Some(Accessibility::Private) {
+                        let key = if p.computed {
+                            PropName::Computed(p.key)
+                        } else {
+                            expr_to_prop_name(*p.key)
+                        };
+                        body.push(ClassMember::ClassProp(private_prop(p.span, key, p.is_static)));
+                        continue;
+                    }
+
+                    let type_ann = match p.type_ann {
+                        Some(ty) => Some(ty),
+                        None => {
+                            let ty = p.value.as_ref().and_then(|e| infer(e, p.readonly));
+                            if ty.is_none() && exported {
+                                report(p.span, "Property must have an explicit type annotation");
+                            }
+                            ty.map(type_ann)
+                        }
+                    };
+
+                    body.push(ClassMember::ClassProp(ClassProp {
+                        value: None,
+                        type_ann,
+                        decorators: vec![],
+                        definite: false,
+                        ..p
+                    }))
+                }
+
+                // `#private;` hides all private names.
+                ClassMember::PrivateMethod(PrivateMethod { span, .. })
+                | ClassMember::PrivateProp(PrivateProp { span, .. }) => {
+                    if has_private_name {
+                        continue;
+                    }
+                    has_private_name = true;
+
+                    body.push(ClassMember::PrivateProp(PrivateProp {
+                        span,
+                        key: PrivateName {
+                            span: DUMMY_SP,
+                            id: quote_ident!("private"),
+                        },
+                        value: None,
+                        type_ann: None,
+                        is_static: false,
+                        decorators: vec![],
+                        computed: false,
+                        accessibility: None,
+                        is_abstract: false,
+                        is_optional: false,
+                        readonly: false,
+                        definite: false,
+                        is_accessor: false,
+                    }))
+                }
+
+                ClassMember::TsIndexSignature(..) => body.push(member),
+            }
+        }
+
+        Class {
+            decorators: vec![],
+            body,
+            ..class
+        }
+    }
+}
+
+/// Removes default values from a parameter.
+///
+/// `a = 1` becomes `a?: number`. A destructuring pattern can't be optional, so
+/// it is replaced with an identifier named after its position. `{ a }: T = {}`
+/// as the first parameter becomes `_a?: T`.
+fn fold_param(p: Pat, index: usize, exported: bool) -> Pat {
+    match p {
+        Pat::Assign(AssignPat {
+            span,
+            left: box Pat::Ident(mut i),
+            right,
+            type_ann,
+        }) => {
+            i.optional = true;
+            if i.type_ann.is_none() {
+                i.type_ann = type_ann.or_else(|| infer(&right, false).map(self::type_ann));
+            }
+            if i.type_ann.is_none() && exported {
+                report(span, "Parameter must have an explicit type annotation");
+            }
+
+            Pat::Ident(i)
+        }
+        Pat::Assign(AssignPat {
+            span,
+            left,
+            right,
+            type_ann,
+        }) => {
+            let type_ann = type_ann
+                .or_else(|| match *left {
+                    Pat::Object(ObjectPat { type_ann, .. })
+                    | Pat::Array(ArrayPat { type_ann, .. }) => type_ann,
+                    _ => None,
+                })
+                .or_else(|| infer(&right, false).map(self::type_ann));
+            if type_ann.is_none() && exported {
+                report(span, "Parameter must have an explicit type annotation");
+            }
+
+            let name = if index < 26 {
+                format!("_{}", (b'a' + index as u8) as char)
+            } else {
+                format!("_{}", index)
+            };
+            Pat::Ident(Ident {
+                optional: true,
+                type_ann,
+                ..Ident::new(name.into(), span)
+            })
+        }
+        _ => drop_defaults(p),
+    }
+}
+
+/// Removes default values from a binding pattern.
+fn drop_defaults(p: Pat) -> Pat {
+    match p {
+        Pat::Assign(AssignPat { left, .. }) => drop_defaults(*left),
+        Pat::Array(a) => Pat::Array(ArrayPat {
+            elems: a
+                .elems
+                .into_iter()
+                .map(|e| e.map(drop_defaults))
+                .collect(),
+            ..a
+        }),
+        Pat::Object(o) => Pat::Object(ObjectPat {
+            props: o
+                .props
+                .into_iter()
+                .map(|p| match p {
+                    ObjectPatProp::KeyValue(p) => ObjectPatProp::KeyValue(KeyValuePatProp {
+                        value: box drop_defaults(*p.value),
+                        ..p
+                    }),
+                    ObjectPatProp::Assign(p) => {
+                        ObjectPatProp::Assign(AssignPatProp { value: None, ..p })
+                    }
+                    ObjectPatProp::Rest(..) => p,
+                })
+                .collect(),
+            ..o
+        }),
+        _ => p,
+    }
+}
+
+/// Returns the type of `e` if it can be determined syntactically.
+///
+/// If `literal` is true, literal types are returned for literals like `const`
+/// declarations.
+fn infer(e: &Expr, literal: bool) -> Option<Box<TsType>> {
+    let lit = |lit: TsLit| {
+        Some(box TsType::TsLitType(TsLitType {
+            span: DUMMY_SP,
+            lit,
+        }))
+    };
+
+    match *e {
+        Expr::Lit(Lit::Str(ref s)) if literal => lit(TsLit::Str(s.clone())),
+        Expr::Lit(Lit::Num(ref n)) if literal => lit(TsLit::Number(n.clone())),
+        Expr::Lit(Lit::Bool(ref b)) if literal => lit(TsLit::Bool(b.clone())),
+        Expr::Lit(Lit::Null(..)) if literal => keyword(TsKeywordTypeKind::TsNullKeyword),
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg: box Expr::Lit(Lit::Num(ref n)),
+            ..
+        }) if literal => lit(TsLit::Number(Number {
+            span: DUMMY_SP,
+            value: -n.value,
+            raw: None,
+        })),
+
+        Expr::Lit(Lit::Str(..))
+        | Expr::Tpl(..)
+        | Expr::Unary(UnaryExpr {
+            op: op!("typeof"), ..
+        }) => keyword(TsKeywordTypeKind::TsStringKeyword),
+        Expr::Lit(Lit::Num(..))
+        | Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg: box Expr::Lit(Lit::Num(..)),
+            ..
+        }) => keyword(TsKeywordTypeKind::TsNumberKeyword),
+        Expr::Lit(Lit::Bool(..)) | Expr::Unary(UnaryExpr { op: op!("!"), .. }) => {
+            keyword(TsKeywordTypeKind::TsBooleanKeyword)
+        }
+        Expr::Unary(UnaryExpr { op: op!("void"), .. }) => {
+            keyword(TsKeywordTypeKind::TsUndefinedKeyword)
+        }
+        Expr::Lit(Lit::Regex(..)) => Some(box TsType::TsTypeRef(TsTypeRef {
+            span: DUMMY_SP,
+            type_name: TsEntityName::Ident(quote_ident!("RegExp")),
+            type_params: None,
+        })),
+
+        Expr::Paren(ParenExpr { ref expr, .. }) => infer(expr, literal),
+        Expr::TsConstAssertion(TsConstAssertion { ref expr, .. }) => infer(expr, true),
+        Expr::TsAs(TsAsExpr { ref type_ann, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { ref type_ann, .. }) => Some(type_ann.clone()),
+
+        Expr::Arrow(ArrowExpr {
+            ref params,
+            ref type_params,
+            return_type: Some(ref return_type),
+            ..
+        })
+        | Expr::Fn(FnExpr {
+            function:
+                Function {
+                    ref params,
+                    ref type_params,
+                    return_type: Some(ref return_type),
+                    ..
+                },
+            ..
+        }) => {
+            let params = params
+                .iter()
+                .enumerate()
+                .map(|(i, p)| match fold_param(p.clone(), i, false) {
+                    Pat::Ident(i) => Some(TsFnParam::Ident(i)),
+                    Pat::Rest(r) => Some(TsFnParam::Rest(r)),
+                    Pat::Object(o) => Some(TsFnParam::Object(o)),
+                    _ => None,
+                })
+                .collect::<Option<_>>()?;
+
+            Some(box TsType::TsFnOrConstructorType(
+                TsFnOrConstructorType::TsFnType(TsFnType {
+                    span: DUMMY_SP,
+                    params,
+                    type_params: type_params.clone(),
+                    type_ann: return_type.clone(),
+                }),
+            ))
+        }
+
+        _ => None,
+    }
+}
+
+fn keyword(kind: TsKeywordTypeKind) -> Option<Box<TsType>> {
+    Some(box TsType::TsKeywordType(TsKeywordType {
+        span: DUMMY_SP,
+        kind,
+    }))
+}
+
+fn type_ann(ty: Box<TsType>) -> TsTypeAnn {
+    TsTypeAnn {
+        span: DUMMY_SP,
+        type_ann: ty,
+    }
+}
+
+/// `private a;`
+fn private_prop(span: Span, key: PropName, is_static: bool) -> ClassProp {
+    let (key, computed) = match key {
+        PropName::Ident(i) => (box Expr::Ident(i), false),
+        PropName::Str(s) => (box Expr::Lit(Lit::Str(s)), false),
+        PropName::Num(n) => (box Expr::Lit(Lit::Num(n)), false),
+        PropName::Computed(e) => (e, true),
+    };
+
+    ClassProp {
+        span,
+        key,
+        value: None,
+        type_ann: None,
+        is_static,
+        decorators: vec![],
+        computed,
+        accessibility: Some(Accessibility::Private),
+        is_abstract: false,
+        is_optional: false,
+        readonly: false,
+        definite: false,
+        is_accessor: false,
+    }
+}
+
+fn expr_to_prop_name(e: Expr) -> PropName {
+    match e {
+        Expr::Ident(i) => PropName::Ident(i),
+        Expr::Lit(Lit::Str(s)) => PropName::Str(s),
+        Expr::Lit(Lit::Num(n)) => PropName::Num(n),
+        _ => PropName::Computed(box e),
+    }
+}
+
+fn prop_name_sym(key: &PropName) -> Option<JsWord> {
+    match *key {
+        PropName::Ident(ref i) => Some(i.sym.clone()),
+        PropName::Str(ref s) => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+fn report(span: Span, msg: &str) {
+    HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::Tester;
+    use swc_ecma_parser::Syntax;
+
+    macro_rules! to {
+        ($name:ident, $from:expr, $to:expr) => {
+            test!(
+                Syntax::Typescript(Default::default()),
+                |_| dts(),
+                $name,
+                $from,
+                $to,
+                ok_if_code_eq
+            );
+        };
+    }
+
+    fn has_errors(src: &str) -> bool {
+        ::testing::run_test(false, |cm, handler| {
+            HANDLER.set(handler, || {
+                Tester {
+                    cm,
+                    handler,
+                    comments: Default::default(),
+                }
+                .apply_transform(dts(), "input.ts", Syntax::Typescript(Default::default()), src)
+                .map(|_| handler.has_errors())
+            })
+        })
+        .unwrap()
+    }
+
+    to!(
+        fn_decl,
+        "export function foo(a: number, b = 'b', { c = 1 }: { c?: number }): void {
+            console.log(a);
+        }
+        foo(1);",
+        "export declare function foo(a: number, b?: string, { c }: { c?: number }): void;"
+    );
+
+    to!(
+        destructuring_param_default,
+        "export function foo(a = 1, { b }: { b?: number } = {}, [c]: number[] = []): void {}",
+        "export declare function foo(a?: number, _b?: { b?: number }, _c?: number[]): void;"
+    );
+
+    to!(
+        fn_overload,
+        "export function foo(a: string): string;
+        export function foo(a: number): number;
+        export function foo(a: any): any {
+            return a;
+        }",
+        "export declare function foo(a: string): string;
+        export declare function foo(a: number): number;"
+    );
+
+    to!(
+        var_decl,
+        "export const a = 1, b = 'b', c = `c`;
+        export let d = true;
+        export var e: number[] = [];",
+        "export declare const a: 1, b: 'b', c: string;
+        export declare let d: boolean;
+        export declare var e: number[];"
+    );
+
+    to!(
+        class_decl,
+        "export class Foo {
+            private a = 1;
+            #b = 2;
+            #c() {}
+            readonly d = 'd';
+            e: number = 1;
+            constructor(public f: number, readonly g = 1) {}
+            get h(): number {
+                return 1;
+            }
+            set h(v) {}
+            private i(): void {}
+        }",
+        "export declare class Foo {
+            private a;
+            #private;
+            readonly d: 'd';
+            e: number;
+            constructor(public f: number, readonly g?: number);
+            get h(): number;
+            set h(v);
+            private i;
+        }"
+    );
+
+    to!(
+        namespace,
+        "export namespace A.B {
+            export const c = 1;
+            function d() {}
+            d();
+        }",
+        "export declare namespace A.B {
+            export const c: 1;
+            function d();
+        }"
+    );
+
+    to!(
+        default_expr,
+        "export default 1 as number;",
+        "declare const _default: number;
+        export default _default;"
+    );
+
+    to!(
+        keep_declarations,
+        "import { A } from 'a';
+        interface B extends A {}
+        type C = B;
+        declare module 'd' {
+            export const e: number;
+        }
+        export enum F { G }
+        export { B, C };",
+        "import { A } from 'a';
+        interface B extends A {}
+        type C = B;
+        declare module 'd' {
+            export const e: number;
+        }
+        export declare enum F { G }
+        export { B, C };"
+    );
+
+    #[test]
+    fn errors() {
+        assert!(has_errors("export const a = foo();"));
+        assert!(has_errors("export function a() {}"));
+        assert!(has_errors("export class A { b() {} }"));
+        assert!(has_errors("export default foo();"));
+        assert!(has_errors("const a = foo(); export { a };"));
+        assert!(has_errors("export function a({ b } = {}): void {}"));
+
+        assert!(!has_errors("const a = foo();"));
+        assert!(!has_errors("export const a: number = foo();"));
+        assert!(!has_errors("export function a(): void {}"));
+    }
+}
@@ -1,4 +1,4 @@
-pub use self::opt_chaining::optional_chaining;
+pub use self::{dts::dts, opt_chaining::optional_chaining};
 use crate::{
     pass::Pass,
     util::{prepend_stmts, var::VarCollector, ExprFactory},
@@ -10,6 +10,7 @@ use swc_common::{
     util::move_map::MoveMap, Fold, FoldWith, Spanned, SyntaxContext, Visit, VisitWith, DUMMY_SP,
 };
 
+mod dts;
 mod opt_chaining;
 #[cfg(test)]
 mod tests;
@@ -270,6 +271,7 @@ impl Fold<Vec<ModuleItem>> for Strip {
                             },
                         })],
                         src: None,
+                        type_only: false,
                     })
                     .fold_with(self),
                 ))
@@ -652,6 +652,10 @@ pub trait ExprExt {
             | Expr::TsTypeAssertion(TsTypeAssertion { ref expr, .. })
             | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. }) => expr.may_have_side_effects(),
             Expr::TsOptChain(ref e) => e.expr.may_have_side_effects(),
+
+            Expr::Do(..) | Expr::Throw(..) => true,
+
+            Expr::ArgPlaceholder(..) => unreachable!("simplifying partial application"),
         }
     }
 }
@@ -817,6 +821,7 @@ pub(crate) fn prop_name_to_expr_value(p: PropName) -> Expr {
             span: i.span,
             value: i.sym,
             has_escape: false,
+            raw: None,
         })),
         PropName::Str(s) => Expr::Lit(Lit::Str(s)),
         PropName::Num(n) => Expr::Lit(Lit::Num(n)),
@@ -879,7 +884,11 @@ pub(crate) fn undefined(span: Span) -> Box<Expr> {
     box Expr::Unary(UnaryExpr {
         span,
         op: op!("void"),
-        arg: box Expr::Lit(Lit::Num(Number { value: 0.0, span })),
+        arg: box Expr::Lit(Lit::Num(Number {
+            value: 0.0,
+            span,
+            raw: None,
+        })),
     })
 }
 
@@ -13,9 +13,8 @@ extern crate testing;
 use std::{
     env,
     fs::{read_dir, File},
-    io::{self, Read, Write},
+    io::{self, Read},
     path::Path,
-    sync::{Arc, RwLock},
 };
 use swc_common::{Fold, FoldWith};
 use swc_ecma_ast::*;
@@ -112,10 +111,6 @@ fn add_test<F: FnOnce() + Send + 'static>(
     });
 }
 
-struct MyHandlers;
-
-impl swc_ecma_codegen::Handlers for MyHandlers {}
-
 fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
     let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
         .parent()
@@ -159,8 +154,8 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                     "\n\n========== Running fixer test {}\nSource:\n{}\n",
                     file_name, input
                 );
-                let mut wr = Buf(Arc::new(RwLock::new(vec![])));
-                let mut wr2 = Buf(Arc::new(RwLock::new(vec![])));
+                let mut wr = String::new();
+                let mut wr2 = String::new();
 
                 ::testing::run_test(false, |cm, handler| {
                     let src = cm.load_file(&entry.path()).expect("failed to load file");
@@ -169,8 +164,6 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                         .expect("failed to load reference file");
 
                     {
-                        let handlers = box MyHandlers;
-                        let handlers2 = box MyHandlers;
                         let mut parser: Parser<Lexer<SourceFileInput>> = Parser::new(
                             Session { handler: &handler },
                             Syntax::default(),
@@ -179,30 +172,40 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                         );
 
                         let mut emitter = Emitter {
-                            cfg: swc_ecma_codegen::Config { minify: false },
+                            cfg: swc_ecma_codegen::Config {
+                                minify: false,
+                                ..Default::default()
+                            },
                             cm: cm.clone(),
-                            wr: box swc_ecma_codegen::text_writer::JsWriter::new(
+                            wr: swc_ecma_codegen::text_writer::JsWriter::new(
                                 cm.clone(),
                                 "\n",
                                 &mut wr,
                                 None,
                             ),
                             comments: None,
-                            handlers,
+                            handlers: None,
                             pos_of_leading_comments: Default::default(),
+                            in_ambient: false,
+                            unchanged: Default::default(),
                         };
                         let mut expected_emitter = Emitter {
-                            cfg: swc_ecma_codegen::Config { minify: false },
+                            cfg: swc_ecma_codegen::Config {
+                                minify: false,
+                                ..Default::default()
+                            },
                             cm: cm.clone(),
-                            wr: box swc_ecma_codegen::text_writer::JsWriter::new(
+                            wr: swc_ecma_codegen::text_writer::JsWriter::new(
                                 cm.clone(),
                                 "\n",
                                 &mut wr2,
                                 None,
                             ),
                             comments: None,
-                            handlers: handlers2,
+                            handlers: None,
                             pos_of_leading_comments: Default::default(),
+                            in_ambient: false,
+                            unchanged: Default::default(),
                         };
 
                         // Parse source
@@ -262,12 +265,10 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                         }
                     }
 
-                    let output = String::from_utf8_lossy(&*wr.0.read().unwrap()).to_string();
-                    let expected = String::from_utf8_lossy(&*wr2.0.read().unwrap()).to_string();
-                    if output == expected {
+                    if wr == wr2 {
                         return Ok(());
                     }
-                    eprintln!("Wrong output:\n{}\n-----\n{}", output, expected);
+                    eprintln!("Wrong output:\n{}\n-----\n{}", wr, wr2);
 
                     Err(())
                 })
@@ -287,18 +288,6 @@ fn identity() {
     test_main(&args, tests, Some(Options::new()));
 }
 
-#[derive(Debug, Clone)]
-struct Buf(Arc<RwLock<Vec<u8>>>);
-impl Write for Buf {
-    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
-        self.0.write().unwrap().write(data)
-    }
-
-    fn flush(&mut self) -> io::Result<()> {
-        self.0.write().unwrap().flush()
-    }
-}
-
 struct Normalizer;
 impl Fold<Stmt> for Normalizer {
     fn fold(&mut self, stmt: Stmt) -> Stmt {
@@ -320,6 +309,7 @@ impl Fold<PropName> for Normalizer {
                 value: i.sym,
                 span: i.span,
                 has_escape: false,
+                raw: None,
             }),
             PropName::Num(n) => {
                 let s = if n.value.is_infinite() {
@@ -335,6 +325,7 @@ impl Fold<PropName> for Normalizer {
                     value: s.into(),
                     span: n.span,
                     has_escape: false,
+                    raw: None,
                 })
             }
             _ => name,
@@ -342,6 +333,18 @@ impl Fold<PropName> for Normalizer {
     }
 }
 
+impl Fold<Str> for Normalizer {
+    fn fold(&mut self, s: Str) -> Str {
+        Str { raw: None, ..s }
+    }
+}
+
+impl Fold<Number> for Normalizer {
+    fn fold(&mut self, n: Number) -> Number {
+        Number { raw: None, ..n }
+    }
+}
+
 impl Fold<NewExpr> for Normalizer {
     fn fold(&mut self, expr: NewExpr) -> NewExpr {
         let mut expr = expr.fold_children(self);
@@ -4,7 +4,7 @@ use common::{errors::Handler, SourceMap};
 use ecmascript::{
     ast::Module,
     transforms::{
-        chain_at, compat, const_modules, fixer, helpers, hygiene, modules,
+        chain_at, compat, const_modules, fixer, flow, helpers, hygiene, modules,
         pass::{JoinedPass, Optional, Pass},
         typescript,
     },
@@ -18,6 +18,7 @@ pub struct PassBuilder<'a, 'b, P: Pass> {
     handler: &'b Handler,
     pass: P,
     target: JscTarget,
+    verify: bool,
 }
 
 impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
@@ -27,6 +28,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             handler,
             pass,
             target: JscTarget::Es5,
+            verify: false,
         }
     }
 
@@ -37,6 +39,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             handler: self.handler,
             pass,
             target: self.target,
+            verify: self.verify,
         }
     }
 
@@ -56,11 +59,21 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
         self.then(typescript::strip())
     }
 
+    pub fn strip_flow(self) -> PassBuilder<'a, 'b, impl Pass> {
+        self.then(flow::strip())
+    }
+
     pub fn target(mut self, target: JscTarget) -> Self {
         self.target = target;
         self
     }
 
+    /// Reports syntax which compat passes failed to lower for the target.
+    pub fn verify(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
     /// # Arguments
     /// ## module
     ///  - Use `None` if you want swc to emit import statements.
@@ -73,6 +86,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
     ///  - helper injector
     ///  - identifier hygiene handler
     ///  - fixer
+    ///  - verifier for the target (if enabled)
     pub fn finalize(self, module: Option<ModuleConfig>) -> impl Pass {
         let need_interop_analysis = match module {
             Some(ModuleConfig::CommonJs(ref c)) => !c.no_interop,
@@ -85,6 +99,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             Module,
             self.pass,
             // compat
+            Optional::new(compat::es2021(), self.target <= JscTarget::Es2020),
             Optional::new(compat::es2018(), self.target <= JscTarget::Es2018),
             Optional::new(compat::es2017(), self.target <= JscTarget::Es2017),
             Optional::new(compat::es2016(), self.target <= JscTarget::Es2016),
@@ -101,6 +116,8 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             hygiene(),
             // fixer
             fixer(),
+            // verifier
+            Optional::new(compat::verify(self.target.into()), self.verify),
         )
     }
 }
@@ -1,15 +1,23 @@
 use crate::{builder::PassBuilder, error::Error};
 use atoms::JsWord;
 use chashmap::CHashMap;
-use common::{errors::Handler, FileName, SourceMap};
+use common::{
+    comments::{Comment, CommentKind},
+    errors::Handler,
+    FileName, SourceMap,
+};
 use ecmascript::{
     ast::{Expr, Module, ModuleItem, Stmt},
-    parser::{Parser, Session as ParseSess, SourceFileInput, Syntax},
+    codegen,
+    parser::{DecoratorsVersion, EsVersion, Parser, Session as ParseSess, SourceFileInput, Syntax},
     transforms::{
         chain_at, const_modules, modules,
         pass::{noop, Optional, Pass},
-        proposals::{class_properties, decorators, export},
-        react, resolver, simplifier, typescript, InlineGlobals,
+        proposals::{
+            class_properties, decorators, decorators_2019_11, do_expressions, export,
+            partial_application, pipeline_operator, throw_expressions,
+        },
+        flow, react, resolver, simplifier, typescript, InlineGlobals,
     },
 };
 use hashbrown::{HashMap, HashSet};
@@ -123,6 +131,8 @@ impl Options {
             syntax,
             external_helpers,
             target,
+            minify: minify_config,
+            verify,
         } = config.jsc;
 
         let syntax = syntax.unwrap_or_default();
@@ -152,24 +162,42 @@ impl Options {
             // handle jsx
             Optional::new(react::react(cm.clone(), transform.react), syntax.jsx()),
             Optional::new(typescript::strip(), syntax.typescript()),
+            Optional::new(flow::strip(), syntax.flow()),
             resolver(),
             const_modules,
             pass,
-            Optional::new(decorators(), syntax.decorators()),
+            Optional::new(
+                decorators(),
+                syntax.decorators() && syntax.decorators_version() == DecoratorsVersion::Legacy
+            ),
+            Optional::new(
+                decorators_2019_11(),
+                syntax.decorators() && syntax.decorators_version() == DecoratorsVersion::V201911
+            ),
             Optional::new(class_properties(), syntax.class_props()),
             Optional::new(
                 export(),
                 syntax.export_default_from() || syntax.export_namespace_from()
             ),
+            Optional::new(pipeline_operator(), syntax.pipeline_operator()),
+            Optional::new(partial_application(), syntax.partial_application()),
+            Optional::new(do_expressions(), syntax.do_expressions()),
+            Optional::new(throw_expressions(), syntax.throw_expressions()),
             Optional::new(simplifier(), enable_optimizer),
         );
 
         let pass = PassBuilder::new(&cm, &handler, pass)
             .target(target)
+            .verify(verify)
             .finalize(config.module);
 
+        let format = config.format.unwrap_or_default();
+        let minify = config.minify.unwrap_or(format.minify);
+
         BuiltConfig {
-            minify: config.minify.unwrap_or(false),
+            minify,
+            minify_comments: minify_config.unwrap_or_default().comments,
+            format: codegen::Config { minify, ..format },
             pass,
             external_helpers,
             syntax,
@@ -297,6 +325,10 @@ pub struct Config {
 
     #[serde(default)]
     pub minify: Option<bool>,
+
+    /// Formatting options of the generated code.
+    #[serde(default)]
+    pub format: Option<codegen::Config>,
 }
 
 impl Config {
@@ -326,6 +358,9 @@ pub struct BuiltConfig<P: Pass> {
     pub pass: P,
     pub syntax: Syntax,
     pub minify: bool,
+    /// Comments to keep if `minify` is true.
+    pub minify_comments: MinifyComments,
+    pub format: codegen::Config,
     pub external_helpers: bool,
     pub source_maps: bool,
 }
@@ -344,6 +379,51 @@ pub struct JscConfig {
 
     #[serde(default)]
     pub target: JscTarget,
+
+    #[serde(default)]
+    pub minify: Option<JsMinifyConfig>,
+
+    /// Reports syntax which is not lowered for `target`.
+    #[serde(default)]
+    pub verify: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct JsMinifyConfig {
+    /// Comments to keep in minified output.
+    #[serde(default)]
+    pub comments: MinifyComments,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MinifyComments {
+    None,
+    /// Keeps license comments, which start with `/*!` or contain `@license` or
+    /// `@preserve`.
+    Some,
+    All,
+}
+
+impl Default for MinifyComments {
+    fn default() -> Self {
+        MinifyComments::None
+    }
+}
+
+impl MinifyComments {
+    pub fn keeps(self, cmt: &Comment) -> bool {
+        match self {
+            MinifyComments::None => false,
+            MinifyComments::Some => {
+                (cmt.kind == CommentKind::Block && cmt.text.starts_with('!'))
+                    || cmt.text.contains("@license")
+                    || cmt.text.contains("@preserve")
+            }
+            MinifyComments::All => true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialOrd, Ord, PartialEq, Eq)]
@@ -362,6 +442,10 @@ pub enum JscTarget {
     Es2018,
     #[serde(rename = "es2019")]
     Es2019,
+    #[serde(rename = "es2020")]
+    Es2020,
+    #[serde(rename = "es2021")]
+    Es2021,
 }
 
 impl Default for JscTarget {
@@ -370,6 +454,22 @@ impl Default for JscTarget {
     }
 }
 
+impl From<JscTarget> for EsVersion {
+    fn from(target: JscTarget) -> Self {
+        match target {
+            JscTarget::Es3 => EsVersion::Es3,
+            JscTarget::Es5 => EsVersion::Es5,
+            JscTarget::Es2015 => EsVersion::Es2015,
+            JscTarget::Es2016 => EsVersion::Es2016,
+            JscTarget::Es2017 => EsVersion::Es2017,
+            JscTarget::Es2018 => EsVersion::Es2018,
+            JscTarget::Es2019 => EsVersion::Es2019,
+            JscTarget::Es2020 => EsVersion::Es2020,
+            JscTarget::Es2021 => EsVersion::Es2021,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 #[serde(tag = "type")]
@@ -534,7 +634,8 @@ impl Merge for Config {
     fn merge(&mut self, from: &Self) {
         self.jsc.merge(&from.jsc);
         self.module.merge(&from.module);
-        self.minify.merge(&from.minify)
+        self.minify.merge(&from.minify);
+        self.format.merge(&from.format)
     }
 }
 
@@ -544,6 +645,8 @@ impl Merge for JscConfig {
         self.transform.merge(&from.transform);
         self.target.merge(&from.target);
         self.external_helpers.merge(&from.external_helpers);
+        self.minify.merge(&from.minify);
+        self.verify.merge(&from.verify);
     }
 }
 
@@ -596,6 +699,18 @@ impl Merge for GlobalPassOption {
     }
 }
 
+impl Merge for JsMinifyConfig {
+    fn merge(&mut self, from: &Self) {
+        *self = *from;
+    }
+}
+
+impl Merge for codegen::Config {
+    fn merge(&mut self, from: &Self) {
+        *self = *from;
+    }
+}
+
 impl Merge for react::Options {
     fn merge(&mut self, from: &Self) {
         *self = from.clone();
@@ -1,5 +1,12 @@
-use super::Rc;
+use super::{MinifyComments, Options, Rc};
+use crate::Compiler;
+use common::{
+    comments::{Comment, CommentKind},
+    errors::{ColorConfig, Handler},
+    FileName, SourceMap, DUMMY_SP,
+};
 use serde_json;
+use std::sync::Arc;
 
 #[test]
 fn object() {
@@ -10,3 +17,69 @@ fn object() {
 fn array() {
     let _: Rc = serde_json::from_str(include_str!("array.json")).expect("failed to parse");
 }
+
+#[test]
+fn minify_comments() {
+    let rc: Rc = serde_json::from_str(r#"{ "jsc": { "minify": { "comments": "some" } } }"#)
+        .expect("failed to parse");
+    let keep = match rc {
+        Rc::Single(c) => c.jsc.minify.unwrap().comments,
+        _ => unreachable!(),
+    };
+    assert_eq!(keep, MinifyComments::Some);
+
+    let cmt = |kind, text: &str| Comment {
+        kind,
+        span: DUMMY_SP,
+        text: text.into(),
+    };
+    assert!(keep.keeps(&cmt(CommentKind::Block, "! foo")));
+    assert!(keep.keeps(&cmt(CommentKind::Line, " @license MIT")));
+    assert!(keep.keeps(&cmt(CommentKind::Block, "* @preserve ")));
+    assert!(!keep.keeps(&cmt(CommentKind::Line, "! foo")));
+    assert!(!keep.keeps(&cmt(CommentKind::Block, " foo ")));
+}
+
+#[test]
+fn verify() {
+    let src = "function a() { return new.target; }\nvar b = /c/u;\nvar d = 0b11;";
+
+    for &(opts, verify) in &[(r#"{}"#, false), (r#"{ "jsc": { "verify": true } }"#, true)] {
+        let opts: Options = serde_json::from_str(opts).expect("failed to parse");
+
+        let cm = Arc::<SourceMap>::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, true, false, Some(cm.clone()));
+        let c = Compiler::new(cm.clone(), handler);
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        let config = c.run(|| opts.build(&cm, &c.handler, None));
+        let out = c.process_js(fm, config).expect("failed to process");
+
+        // `new.target` and the `u` flag are not lowered for es5.
+        assert_eq!(c.handler.err_count(), if verify { 2 } else { 0 });
+        assert!(out.code.contains("new.target"));
+        assert!(out.code.contains("/c/u"));
+        assert!(out.code.contains("var d = 3;"));
+    }
+}
+
+#[test]
+fn minify_keeps_license_comments() {
+    let src = "/*! a */\nvar a = 1; // @license b\n// c\nvar b = 2;";
+    let opts: Options =
+        serde_json::from_str(r#"{ "minify": true, "jsc": { "minify": { "comments": "some" } } }"#)
+            .expect("failed to parse");
+
+    let cm = Arc::<SourceMap>::default();
+    let handler = Handler::with_tty_emitter(ColorConfig::Never, true, false, Some(cm.clone()));
+    let c = Compiler::new(cm.clone(), handler);
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+
+    let config = c.run(|| opts.build(&cm, &c.handler, None));
+    let out = c.process_js(fm, config).expect("failed to process");
+
+    assert!(out.code.contains("/*! a */"));
+    assert!(out.code.contains("// @license b\n"));
+    assert!(!out.code.contains("// c"));
+    assert!(out.code.contains("b=2"));
+}
@@ -11,7 +11,7 @@ pub mod error;
 
 pub use crate::builder::PassBuilder;
 use crate::{
-    config::{BuiltConfig, ConfigFile, Merge, Options, Rc, RootMode},
+    config::{BuiltConfig, ConfigFile, Merge, MinifyComments, Options, Rc, RootMode},
     error::Error,
 };
 use common::{
@@ -85,15 +85,32 @@ impl Compiler {
         })
     }
 
+    /// If `cfg.minify` is true, only comments kept by `minify_comments` are
+    /// emitted.
     pub fn print(
         &self,
         module: &Module,
         fm: Arc<SourceFile>,
         comments: &Comments,
         source_map: bool,
-        minify: bool,
+        cfg: codegen::Config,
+        minify_comments: MinifyComments,
     ) -> Result<TransformOutput, Error> {
         self.run(|| {
+            let kept;
+            let comments = if !cfg.minify {
+                Some(comments)
+            } else {
+                match minify_comments {
+                    MinifyComments::None => None,
+                    MinifyComments::Some => {
+                        kept = comments.filter(|cmt| minify_comments.keeps(cmt));
+                        Some(&kept)
+                    }
+                    MinifyComments::All => Some(comments),
+                }
+            };
+
             let mut src_map_builder = SourceMapBuilder::new(None);
 
             match fm.name {
@@ -105,14 +122,14 @@ impl Compiler {
             }
 
             let src = {
-                let mut buf = vec![];
+                // Output is usually about as large as the input.
+                let mut buf = String::with_capacity(fm.src.len());
                 {
-                    let handlers = box MyHandlers;
                     let mut emitter = Emitter {
-                        cfg: codegen::Config { minify },
-                        comments: if minify { None } else { Some(&comments) },
+                        cfg,
+                        comments,
                         cm: self.cm.clone(),
-                        wr: box codegen::text_writer::JsWriter::new(
+                        wr: codegen::text_writer::JsWriter::new(
                             self.cm.clone(),
                             "\n",
                             &mut buf,
@@ -121,17 +138,19 @@ impl Compiler {
                             } else {
                                 None
                             },
-                        ),
-                        handlers,
+                        )
+                        .with_config(&cfg),
+                        handlers: None,
                         pos_of_leading_comments: Default::default(),
+                        in_ambient: false,
+                        unchanged: Default::default(),
                     };
 
                     emitter
                         .emit_module(&module)
                         .map_err(|err| Error::FailedToEmitModule { err })?;
                 }
-                // Invalid utf8 is valid in javascript world.
-                unsafe { String::from_utf8_unchecked(buf) }
+                buf
             };
             Ok(TransformOutput {
                 code: src,
@@ -267,7 +286,11 @@ impl Compiler {
             let module = self.parse_js(
                 fm.clone(),
                 config.syntax,
-                if config.minify { None } else { Some(&comments) },
+                if config.minify && config.minify_comments == MinifyComments::None {
+                    None
+                } else {
+                    Some(&comments)
+                },
             )?;
             let mut pass = config.pass;
             let module = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
@@ -277,11 +300,14 @@ impl Compiler {
                 })
             });
 
-            self.print(&module, fm, &comments, config.source_maps, config.minify)
+            self.print(
+                &module,
+                fm,
+                &comments,
+                config.source_maps,
+                config.format,
+                config.minify_comments,
+            )
         })
     }
 }
-
-struct MyHandlers;
-
-impl ecmascript::codegen::Handlers for MyHandlers {}